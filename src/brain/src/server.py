@@ -16,6 +16,9 @@ from boyd.engine import BoydStrategist
 console = Console()
 logger = logging.getLogger(__name__)
 
+# ContextResponse schema served by this Brain (the Reflex Firewall rejects other versions)
+CONTEXT_SCHEMA_VERSION = 1
+
 
 class BrainService(brain_pb2_grpc.BrainServiceServicer):
     def __init__(self):
@@ -175,6 +178,19 @@ class BrainService(brain_pb2_grpc.BrainServiceServicer):
         console.print(f"[bold red]🚨 REGIME CHANGE:[/bold red] {request.regime}")
         return brain_pb2.Empty()
 
+    async def RequestBlessing(self, request, context):
+        # Ignition Gate 4: no live trading into a decoherent market or a slow venue
+        if request.price <= 0.0:
+            verdict = (False, "No price")
+        elif request.regime == brain_pb2.REGIME_DECOHERENT:
+            verdict = (False, "Decoherent regime")
+        elif request.penny_latency_ms > 1000.0:
+            verdict = (False, f"Venue round trip {request.penny_latency_ms:.0f}ms")
+        else:
+            verdict = (True, "Laminar enough")
+        console.print(f"[bold cyan]🙏 BLESSING {request.symbol}:[/bold cyan] {verdict}")
+        return brain_pb2.BlessingVerdict(blessed=verdict[0], reason=verdict[1])
+
     async def Forecast(self, request, context):
         # Allow direct forecast requests (bypass Boyd)
         return brain_pb2.ForecastResult(p50=0.0)
@@ -271,4 +287,6 @@ class BrainService(brain_pb2_grpc.BrainServiceServicer):
             computation_time_ns=duration_ns,
             reasoning=reasoning,  # Use the potentially modified reasoning
             referenced_price=ctx_data.get("referenced_price", 0.0),
+            schema_version=CONTEXT_SCHEMA_VERSION,
+            velocity_direction=ctx_data.get("velocity_direction", ""),
         )
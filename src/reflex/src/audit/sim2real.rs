@@ -46,6 +46,8 @@ impl Sim2RealAuditor {
                 quantile_score: 8,
                 decision: "BUY".to_string(),
                 operator_hash: String::new(), // Will be filled
+                sizing: Vec::new(),
+                omega: None,
             };
 
             // Seal it
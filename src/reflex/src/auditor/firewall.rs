@@ -1,106 +1,352 @@
 use crate::auditor::truth_envelope::TruthEnvelope;
+use crate::governor::regime_detector::MarketRegime;
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Brain response schema this build understands (`ContextResponse.schema_version`).
+/// Bump together with the Brain when a response field changes meaning.
+pub const BRAIN_SCHEMA_VERSION: u32 = 1;
 
 /// Standardized LLM Response Schema (must match what we expect from Python/Brain)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmInferenceResponse {
+    #[serde(default)]
+    pub schema_version: u32, // 0 = pre-versioning Brain
     pub reasoning: String,
     pub decision: String, // "BUY", "SELL", "HOLD"
     pub confidence: f64,
     // Optional fields the model *might* halllucinate, or return if asked
     pub referenced_price: Option<f64>,
     pub regime_classification: Option<String>,
+    #[serde(default)]
+    pub sentiment_score: Option<f64>,
+    #[serde(default)]
+    pub velocity_direction: Option<String>, // "UP", "DOWN", "FLAT"
 }
 
 #[derive(Debug, Clone)]
 pub enum FirewallError {
     NumericHallucination { claimed: f64, truth: f64, delta: f64 },
-    RegimeMismatch { claimed: String, truth_id: u8 },
+    RegimeMismatch { claimed: String, truth: MarketRegime },
     SchemaViolation(String),
+    IncompatibleSchema { version: u32, min: u32, max: u32 },
+    ConfidenceOutOfBounds(f64),
+    DisallowedDecision(String),
+    SentimentOutOfBounds(f64),
+    ReasoningLength { len: usize, min: usize, max: usize },
+    VelocityContradiction { claimed: String, truth: f64 },
+}
+
+/// Schema gate: responses outside [min_version, max_version] come from an incompatible Brain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchemaRule {
+    pub enabled: bool,
+    pub min_version: u32,
+    pub max_version: u32,
+}
+
+impl Default for SchemaRule {
+    fn default() -> Self {
+        Self { enabled: true, min_version: BRAIN_SCHEMA_VERSION, max_version: BRAIN_SCHEMA_VERSION }
+    }
+}
+
+/// Directive-87: Numeric Anchor Tolerance.
+/// If model quotes a price, it must be within +/- tolerance of live mid_price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumericAnchorRule {
+    pub enabled: bool,
+    pub tolerance: f64, // Fraction of mid_price
+}
+
+impl Default for NumericAnchorRule {
+    fn default() -> Self {
+        Self { enabled: true, tolerance: 0.005 } // 0.5%
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegimeRule {
+    pub enabled: bool,
+}
+
+impl Default for RegimeRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Closed interval check shared by the confidence and sentiment rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoundsRule {
+    pub enabled: bool,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl BoundsRule {
+    fn new(min: f64, max: f64) -> Self {
+        Self { enabled: true, min, max }
+    }
+
+    fn admits(&self, value: f64) -> bool {
+        !self.enabled || (self.min..=self.max).contains(&value)
+    }
+}
+
+impl Default for BoundsRule {
+    fn default() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecisionRule {
+    pub enabled: bool,
+    pub allowed: Vec<String>, // Case-insensitive
+}
+
+impl Default for DecisionRule {
+    fn default() -> Self {
+        let allowed = ["BUY", "SELL", "HOLD", "CONTEXT"].iter().map(|d| d.to_string()).collect();
+        Self { enabled: true, allowed }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReasoningRule {
+    pub enabled: bool,
+    pub min_len: usize, // Characters
+    pub max_len: usize,
+}
+
+impl Default for ReasoningRule {
+    fn default() -> Self {
+        Self { enabled: true, min_len: 0, max_len: 4_096 }
+    }
+}
+
+/// A claimed velocity direction must agree with the kernel's sign.
+/// |velocity| <= dead_band counts as FLAT, and UP/DOWN claims are tolerated there.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VelocityRule {
+    pub enabled: bool,
+    pub dead_band: f64,
+}
+
+impl Default for VelocityRule {
+    fn default() -> Self {
+        Self { enabled: true, dead_band: 1e-6 }
+    }
+}
+
+/// D-87 rule set, loaded from JSON (FIREWALL_PATH); missing rules keep their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FirewallConfig {
+    pub schema: SchemaRule,
+    pub numeric_anchor: NumericAnchorRule,
+    pub regime: RegimeRule,
+    pub confidence: BoundsRule,
+    pub decision: DecisionRule,
+    pub sentiment: BoundsRule,
+    pub reasoning: ReasoningRule,
+    pub velocity: VelocityRule,
+}
+
+impl Default for FirewallConfig {
+    fn default() -> Self {
+        Self {
+            schema: SchemaRule::default(),
+            numeric_anchor: NumericAnchorRule::default(),
+            regime: RegimeRule::default(),
+            confidence: BoundsRule::new(0.0, 1.0),
+            decision: DecisionRule::default(),
+            sentiment: BoundsRule::new(-1.0, 1.0), // D-38
+            reasoning: ReasoningRule::default(),
+            velocity: VelocityRule::default(),
+        }
+    }
+}
+
+impl FirewallConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.schema.min_version > self.schema.max_version {
+            return Err("schema.min_version must not exceed max_version".into());
+        }
+        if self.numeric_anchor.tolerance <= 0.0 {
+            return Err("numeric_anchor.tolerance must be positive".into());
+        }
+        if self.confidence.min > self.confidence.max || self.sentiment.min > self.sentiment.max {
+            return Err("Bounds must satisfy min <= max".into());
+        }
+        if self.decision.enabled && self.decision.allowed.is_empty() {
+            return Err("decision.allowed must not be empty".into());
+        }
+        if self.reasoning.min_len > self.reasoning.max_len {
+            return Err("reasoning.min_len must not exceed max_len".into());
+        }
+        if self.velocity.dead_band < 0.0 {
+            return Err("velocity.dead_band must not be negative".into());
+        }
+        Ok(())
+    }
 }
 
 pub struct Firewall {
-    // Directive-87: Numeric Anchor Tolerance (0.5%)
-    // If model quotes a price, it must be within +/- 0.5% of live mid_price
-    tolerance: f64, 
+    config: FirewallConfig,
 }
 
 impl Firewall {
     pub fn new() -> Self {
-        Self {
-            tolerance: 0.005, // 0.5%
-        }
+        Self::with_config(FirewallConfig::default())
+    }
+
+    pub fn with_config(config: FirewallConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &FirewallConfig {
+        &self.config
     }
 
     /// Directive-87: The Validation Gate
     /// Validates an LLM response against the Hard Telemetry Truth Envelope.
+    /// Rules run in order and the first violation is returned.
     pub fn validate(
-        &self, 
-        response: &LlmInferenceResponse, 
+        &self,
+        response: &LlmInferenceResponse,
         truth: &TruthEnvelope
     ) -> Result<(), FirewallError> {
-        
+        let rules = &self.config;
+
+        // 0. Schema Version: an incompatible Brain is rejected before its fields are trusted
+        let schema = &rules.schema;
+        if schema.enabled && !(schema.min_version..=schema.max_version).contains(&response.schema_version) {
+            return Err(FirewallError::IncompatibleSchema {
+                version: response.schema_version,
+                min: schema.min_version,
+                max: schema.max_version,
+            });
+        }
+
         // 1. Numeric Anchor Check (NAC)
-        if let Some(price) = response.referenced_price {
+        if let Some(price) = response.referenced_price.filter(|_| rules.numeric_anchor.enabled) {
             // Avoid div by zero
             if truth.mid_price > f64::EPSILON {
                 let delta_pct = (price - truth.mid_price).abs() / truth.mid_price;
-                if delta_pct > self.tolerance {
-                    return Err(FirewallError::NumericHallucination { 
-                        claimed: price, 
-                        truth: truth.mid_price, 
-                        delta: delta_pct 
+                if delta_pct > rules.numeric_anchor.tolerance {
+                    return Err(FirewallError::NumericHallucination {
+                        claimed: price,
+                        truth: truth.mid_price,
+                        delta: delta_pct
                     });
                 }
             }
         }
 
         // 2. Regime Continuity Guard
-        if let Some(regime_str) = &response.regime_classification {
-            // Map Truth ID to expected strings
-            // Regime 0: Undefined, 1: Laminar, 2: Turbulent, 3: Violent/Decoherent
-            let valid = match (truth.regime_id, regime_str.to_uppercase().as_str()) {
-                (1, "LAMINAR") => true,
-                (2, "TURBULENT") => true,
-                (3, "VIOLENT") | (3, "DECOHERENT") => true,
-                // Soft matching allows model some variance if it implies correct state? 
-                // Strict requirement says: "If summary describes Laminar while Kernel is Regime 4... rejected"
-                // We'll enforce strict matching for now.
-                _ => false,
-            };
+        if let Some(regime_str) = response.regime_classification.as_ref().filter(|_| rules.regime.enabled) {
+            // Claimed name must parse to the kernel's canonical regime (Unknown never validates)
+            // Strict requirement says: "If summary describes Laminar while Kernel is Regime 4... rejected"
+            // We'll enforce strict matching for now.
+            let valid = truth.regime != MarketRegime::Unknown
+                && regime_str.parse::<MarketRegime>() == Ok(truth.regime);
 
             if !valid {
-                return Err(FirewallError::RegimeMismatch { 
-                    claimed: regime_str.clone(), 
-                    truth_id: truth.regime_id 
+                return Err(FirewallError::RegimeMismatch {
+                    claimed: regime_str.clone(),
+                    truth: truth.regime
                 });
             }
         }
-        
-        // 3. Schema Strictness is handled by Serde deserialize before this fn is called.
-        // If we parsed LlmInferenceResponse successfully, strict JSON schema is validated.
 
+        // 3. Confidence Bounds (NaN never passes)
+        if !rules.confidence.admits(response.confidence) {
+            return Err(FirewallError::ConfidenceOutOfBounds(response.confidence));
+        }
+
+        // 4. Decision Vocabulary
+        let decision = &rules.decision;
+        if decision.enabled && !decision.allowed.iter().any(|d| d.eq_ignore_ascii_case(response.decision.trim())) {
+            return Err(FirewallError::DisallowedDecision(response.decision.clone()));
+        }
+
+        // 5. Sentiment Bounds
+        if let Some(sentiment) = response.sentiment_score.filter(|s| !rules.sentiment.admits(*s)) {
+            return Err(FirewallError::SentimentOutOfBounds(sentiment));
+        }
+
+        // 6. Reasoning Length (empty or runaway traces)
+        let reasoning = &rules.reasoning;
+        let len = response.reasoning.chars().count();
+        if reasoning.enabled && !(reasoning.min_len..=reasoning.max_len).contains(&len) {
+            return Err(FirewallError::ReasoningLength { len, min: reasoning.min_len, max: reasoning.max_len });
+        }
+
+        // 7. Velocity Direction vs Simons kinematics
+        if let Some(claim) = response.velocity_direction.as_ref().filter(|_| rules.velocity.enabled) {
+            let v = truth.velocity;
+            let band = rules.velocity.dead_band;
+            let consistent = match claim.trim().to_ascii_uppercase().as_str() {
+                "UP" => v > -band,
+                "DOWN" => v < band,
+                "FLAT" => v.abs() <= band,
+                _ => return Err(FirewallError::SchemaViolation(format!("Unknown velocity direction '{}'", claim))),
+            };
+            if !consistent {
+                return Err(FirewallError::VelocityContradiction { claimed: claim.clone(), truth: v });
+            }
+        }
+
+        // Field types are handled by Serde/Prost before this fn is called.
         Ok(())
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
+    fn response() -> LlmInferenceResponse {
+        LlmInferenceResponse {
+            schema_version: BRAIN_SCHEMA_VERSION,
+            reasoning: "ok".into(),
+            decision: "HOLD".into(),
+            confidence: 1.0,
+            referenced_price: None,
+            regime_classification: None,
+            sentiment_score: None,
+            velocity_direction: None,
+        }
+    }
+
     #[test]
     fn test_numeric_anchor_pass() {
         let firewall = Firewall::new();
         let mut truth = TruthEnvelope::default();
         truth.mid_price = 100.0;
-        
+
         let resp = LlmInferenceResponse {
-            reasoning: "ok".into(),
-            decision: "HOLD".into(),
-            confidence: 1.0,
             referenced_price: Some(100.4), // +0.4% (Pass)
-            regime_classification: None,
+            ..response()
         };
-        
+
         assert!(firewall.validate(&resp, &truth).is_ok());
     }
 
@@ -109,15 +355,13 @@ mod tests {
         let firewall = Firewall::new();
         let mut truth = TruthEnvelope::default();
         truth.mid_price = 100.0;
-        
+
         let resp = LlmInferenceResponse {
             reasoning: "bad".into(),
-            decision: "HOLD".into(),
-            confidence: 1.0,
             referenced_price: Some(100.6), // +0.6% (Fail > 0.5%)
-            regime_classification: None,
+            ..response()
         };
-        
+
         match firewall.validate(&resp, &truth) {
             Err(FirewallError::NumericHallucination { delta, .. }) => {
                 assert!(delta > 0.005);
@@ -125,4 +369,78 @@ mod tests {
             _ => panic!("Should fail NAC"),
         }
     }
+
+    #[test]
+    fn test_schema_version_gate() {
+        let firewall = Firewall::new();
+        let truth = TruthEnvelope::default();
+
+        let legacy = LlmInferenceResponse { schema_version: 0, ..response() };
+        assert!(matches!(
+            firewall.validate(&legacy, &truth),
+            Err(FirewallError::IncompatibleSchema { version: 0, .. })
+        ));
+
+        // A config that still admits pre-versioning Brains
+        let mut config = FirewallConfig::default();
+        config.schema.min_version = 0;
+        assert!(Firewall::with_config(config).validate(&legacy, &truth).is_ok());
+    }
+
+    #[test]
+    fn test_content_rules_and_toggles() {
+        let firewall = Firewall::new();
+        let mut truth = TruthEnvelope::default();
+        truth.velocity = 2.5;
+
+        let cases = [
+            LlmInferenceResponse { confidence: 1.2, ..response() },
+            LlmInferenceResponse { confidence: f64::NAN, ..response() },
+            LlmInferenceResponse { decision: "YOLO".into(), ..response() },
+            LlmInferenceResponse { sentiment_score: Some(-1.5), ..response() },
+            LlmInferenceResponse { reasoning: "x".repeat(5_000), ..response() },
+            LlmInferenceResponse { velocity_direction: Some("DOWN".into()), ..response() },
+            LlmInferenceResponse { velocity_direction: Some("SIDEWAYS".into()), ..response() },
+        ];
+        for resp in &cases {
+            assert!(firewall.validate(resp, &truth).is_err(), "{:?}", resp);
+        }
+
+        let ok = LlmInferenceResponse {
+            decision: "buy".into(),
+            sentiment_score: Some(0.4),
+            velocity_direction: Some("up".into()),
+            ..response()
+        };
+        assert!(firewall.validate(&ok, &truth).is_ok());
+
+        // Disabled rules let the same packets through
+        let mut config = FirewallConfig::default();
+        config.confidence.enabled = false;
+        config.decision.enabled = false;
+        config.sentiment.enabled = false;
+        config.reasoning.enabled = false;
+        config.velocity.enabled = false;
+        let permissive = Firewall::with_config(config);
+        for resp in &cases {
+            assert!(permissive.validate(resp, &truth).is_ok(), "{:?}", resp);
+        }
+    }
+
+    #[test]
+    fn test_config_load_partial_and_invalid() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("firewall_{}.json", std::process::id()));
+
+        std::fs::write(&path, r#"{"numeric_anchor": {"tolerance": 0.01}, "velocity": {"enabled": false}}"#).unwrap();
+        let config = FirewallConfig::load(&path).unwrap();
+        assert_eq!(config.numeric_anchor.tolerance, 0.01);
+        assert!(config.numeric_anchor.enabled);
+        assert!(!config.velocity.enabled);
+        assert_eq!(config.schema, SchemaRule::default());
+
+        std::fs::write(&path, r#"{"schema": {"min_version": 3, "max_version": 2}}"#).unwrap();
+        assert!(FirewallConfig::load(&path).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
 }
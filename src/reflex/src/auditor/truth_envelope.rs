@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::governor::regime_detector::MarketRegime;
 
 /// Directive-87: The Truth Envelope
 /// Captures "Hard Telemetry" to ground LLM reasoning.
@@ -20,7 +21,7 @@ pub struct TruthEnvelope {
     pub bid_ask_spread: f64,
     
     // Governance / State
-    pub regime_id: u8, // Deterministic Regime ID
+    pub regime: MarketRegime, // Deterministic Regime (canonical)
     pub sequence_id: u64,
 }
 
@@ -34,7 +35,7 @@ impl Default for TruthEnvelope {
             sentiment_score: 0.0,
             mid_price: 0.0,
             bid_ask_spread: 0.0,
-            regime_id: 0,
+            regime: MarketRegime::Unknown,
             sequence_id: 0,
         }
     }
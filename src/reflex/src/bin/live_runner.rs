@@ -120,17 +120,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let brain_url = std::env::var("BRAIN_SERVICE_URL").unwrap_or("http://[::1]:50052".to_string());
     info!("🔌 Connecting to Brain Service at {}...", brain_url);
     
-    let mut brain_client = match client::BrainClient::connect(brain_url).await {
+    let mut brain_client = match client::BrainClient::lazy(brain_url) {
         Ok(c) => {
-            info!("✅ Connected to Brain Service");
+            c.spawn_heartbeat(client::HEARTBEAT_INTERVAL);
+            info!("✅ Brain Service link armed (circuit breaker + heartbeat)");
             Some(c)
         },
         Err(e) => {
-            warn!("⚠️ Failed to connect to Brain: {}. Running AUTONOMOUS.", e);
+            warn!("⚠️ Invalid Brain endpoint: {}. Running AUTONOMOUS.", e);
             None
         }
     };
 
+    // Brain Forecast RPC, refreshed off-loop (feeds Omega through OODACore)
+    let mut forecasts = reflex::brain::forecast::ForecastService::new(
+        reflex::brain::forecast::ForecastConfig::default(),
+        brain_client.clone(),
+    );
+
     // --- Live Feed Connection ---
     let (tick_tx, mut tick_rx) = mpsc::channel::<market::Tick>(10_000);
     
@@ -142,6 +149,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // --- Physics Engine ---
     let mut feynman = feynman::PhysicsEngine::new(2000);
+    let mut regime_detector = governor::regime_detector::RegimeDetector::new(5);
 
     // --- Metrics ---
     let meter = opentelemetry::global::meter("reflex_live");
@@ -190,8 +198,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let physics = feynman.update(tick.price, tick.timestamp, spread, tick.quantity, 0);
 >>>>>>> feb49d06 (pushing local changes.)
 
+        forecasts.observe_volume(&live_symbol, tick.quantity);
+        forecasts.refresh(&live_symbol, &feynman);
+        ooda.brain_forecast = forecasts.get(&live_symbol).map(|f| f.quantiles);
+
         // OODA Orient
-        let ooda_state = ooda.orient(physics.clone(), 0, brain_client.as_mut(), "NEUTRAL".to_string()).await;
+        let regime = regime_detector.observe(&physics);
+        let ooda_state = ooda.orient(physics.clone(), regime, brain_client.as_mut(), "NEUTRAL".to_string()).await;
 
         // OODA Decide
         let default_legislation = reflex::governor::legislator::LegislativeState::default();
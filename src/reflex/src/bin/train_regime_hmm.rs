@@ -0,0 +1,48 @@
+use reflex::governor::regime_hmm::{self, GaussianHmm};
+use reflex::historian::TickReader;
+use tracing::{info, error};
+use dotenvy::dotenv;
+
+/// Offline regime training: fits a Gaussian HMM to recorded physics (QuestDB `physics` table)
+/// and writes the JSON model that Reflex loads from REGIME_MODEL_PATH.
+///
+/// Usage: train_regime_hmm <start_ms> <end_ms> <out.json> [states=3] [iterations=50]
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 4 {
+        eprintln!("Usage: {} <start_ms> <end_ms> <out.json> [states=3] [iterations=50]", args[0]);
+        std::process::exit(2);
+    }
+    let start_ms: f64 = args[1].parse()?;
+    let end_ms: f64 = args[2].parse()?;
+    let out_path = &args[3];
+    let n_states: usize = args.get(4).map(|s| s.parse()).transpose()?.unwrap_or(3);
+    let iterations: usize = args.get(5).map(|s| s.parse()).transpose()?.unwrap_or(50);
+
+    info!("📚 Fetching physics history [{} - {}]...", start_ms, end_ms);
+    let history = TickReader::new().fetch_physics(start_ms, end_ms).await?;
+    let observations: Vec<_> = history
+        .iter()
+        .map(regime_hmm::features)
+        .filter(|x| x.iter().all(|v| v.is_finite()))
+        .collect();
+    info!("🧮 Training {}-state HMM on {} observations ({} iterations)...", n_states, observations.len(), iterations);
+
+    let hmm = match GaussianHmm::fit(&observations, n_states, iterations) {
+        Ok(hmm) => hmm,
+        Err(e) => {
+            error!("❌ Training failed: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for (i, label) in hmm.labels.iter().enumerate() {
+        info!("   State {} -> {} (mean {:?})", i, label, hmm.means[i]);
+    }
+    hmm.save(out_path)?;
+    info!("✅ Model written to {}. Set REGIME_MODEL_PATH={} to use it.", out_path, out_path);
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use crate::client::brain::HistoryWindow;
+use crate::client::BrainClient;
+use crate::clock::{self, SharedClock};
+use crate::feynman::PhysicsEngine;
+use crate::governor::ooda_loop::{ForecastQuantiles, ForecastSource};
+
+const QUEUE_DEPTH: usize = 16; // Pending refreshes; a full queue drops the request (retried next cycle)
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastConfig {
+    pub window: usize,     // Prices sent in the HistoryWindow
+    pub refresh: Duration, // Per-symbol request cadence
+    pub ttl: Duration,     // Matches the RiskGuardian quantile TTL
+    pub timeout: Duration, // Per RPC; off-loop, so it does not eat the OODA budget
+}
+
+impl Default for ForecastConfig {
+    fn default() -> Self {
+        Self {
+            window: 64,
+            refresh: Duration::from_secs(1),
+            ttl: Duration::from_secs(60),
+            timeout: Duration::from_millis(250),
+        }
+    }
+}
+
+/// A Brain forecast and when it arrived (Unix millis, the RiskGuardian `forecast_ts`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimedForecast {
+    pub quantiles: ForecastQuantiles,
+    pub horizon: f64,
+    pub fetched_at_ms: i64,
+}
+
+struct Job {
+    symbol: String,
+    window: HistoryWindow,
+}
+
+type Cache = Arc<RwLock<HashMap<String, TimedForecast>>>;
+
+/// Forecast Service
+/// Builds the HistoryWindow from PhysicsEngine history, asks the Brain for quantiles on a
+/// worker task (one request per symbol per `refresh`) and caches the answers with a TTL.
+/// The owner loop reads the cache and hands it to RiskGuardian, RiskShroud and OODACore (Omega).
+pub struct ForecastService {
+    pub config: ForecastConfig,
+    cache: Cache,
+    jobs: Option<mpsc::Sender<Job>>, // None = no Brain (consumers fall back to realized quantiles)
+    volumes: HashMap<String, VecDeque<f64>>,
+    last_request: HashMap<String, Instant>,
+    clock: SharedClock,
+}
+
+impl ForecastService {
+    pub fn new(config: ForecastConfig, client: Option<BrainClient>) -> Self {
+        Self::with_clock(config, client, clock::system())
+    }
+
+    /// Spawns the worker when a client is given (needs a Tokio runtime).
+    pub fn with_clock(config: ForecastConfig, client: Option<BrainClient>, clock: SharedClock) -> Self {
+        let cache: Cache = Arc::new(RwLock::new(HashMap::new()));
+        let jobs = client.map(|client| {
+            let (tx, rx) = mpsc::channel(QUEUE_DEPTH);
+            tokio::spawn(run_worker(client, rx, cache.clone(), config.timeout, clock.clone()));
+            tx
+        });
+        Self { config, cache, jobs, volumes: HashMap::new(), last_request: HashMap::new(), clock }
+    }
+
+    /// Record the traded volume of this cycle (one call per PhysicsEngine update keeps them aligned).
+    pub fn observe_volume(&mut self, symbol: &str, volume: f64) {
+        let buf = self.volumes.entry(symbol.to_string()).or_default();
+        if buf.len() >= self.config.window {
+            buf.pop_front();
+        }
+        buf.push_back(volume);
+    }
+
+    /// HistoryWindow for a symbol, or None until half a window of prices exists.
+    pub fn window(&self, symbol: &str, engine: &PhysicsEngine) -> Option<HistoryWindow> {
+        build_window(engine.recent_prices(self.config.window), self.volumes.get(symbol), self.config.window)
+    }
+
+    /// Queue a refresh if one is due. Never waits on the Brain. Returns true if queued.
+    pub fn refresh(&mut self, symbol: &str, engine: &PhysicsEngine) -> bool {
+        let Some(jobs) = &self.jobs else { return false };
+        let due = self
+            .last_request
+            .get(symbol)
+            .is_none_or(|t| self.clock.elapsed_since(*t) >= self.config.refresh);
+        if !due {
+            return false;
+        }
+        let Some(window) = self.window(symbol, engine) else { return false };
+        self.last_request.insert(symbol.to_string(), self.clock.now());
+        jobs.try_send(Job { symbol: symbol.to_string(), window }).is_ok()
+    }
+
+    /// The cached forecast if it is younger than the TTL.
+    pub fn get(&self, symbol: &str) -> Option<TimedForecast> {
+        let forecast = *self.cache.read().ok()?.get(symbol)?;
+        let age_ms = self.clock.unix_millis() - forecast.fetched_at_ms;
+        (age_ms < self.config.ttl.as_millis() as i64).then_some(forecast)
+    }
+}
+
+fn build_window(prices: Vec<f64>, volumes: Option<&VecDeque<f64>>, window: usize) -> Option<HistoryWindow> {
+    if prices.is_empty() || prices.len() < window / 2 {
+        return None;
+    }
+    // Align volumes to the newest prices; cycles without a recorded volume count as zero
+    let recorded: Vec<f64> = volumes.map(|v| v.iter().copied().collect()).unwrap_or_default();
+    let take = recorded.len().min(prices.len());
+    let mut volumes = vec![0.0; prices.len() - take];
+    volumes.extend_from_slice(&recorded[recorded.len() - take..]);
+    let window_size = prices.len() as i32;
+    Some(HistoryWindow { prices, volumes, window_size })
+}
+
+async fn run_worker(mut client: BrainClient, mut jobs: mpsc::Receiver<Job>, cache: Cache, timeout: Duration, clock: SharedClock) {
+    while let Some(Job { symbol, window }) = jobs.recv().await {
+        if !client.available() {
+            continue; // Circuit open: consumers use realized quantiles until it closes
+        }
+        match tokio::time::timeout(timeout, client.forecast(window.prices, window.volumes)).await {
+            Ok(Ok(r)) => {
+                client.record_success();
+                match ForecastQuantiles::new(r.p10, r.p50, r.p90, ForecastSource::Brain) {
+                    Some(quantiles) => {
+                        let forecast = TimedForecast { quantiles, horizon: r.horizon, fetched_at_ms: clock.unix_millis() };
+                        if let Ok(mut c) = cache.write() {
+                            c.insert(symbol, forecast);
+                        }
+                    }
+                    None => tracing::warn!("⚠️ Brain Forecast rejected for {}: p10={} p50={} p90={}", symbol, r.p10, r.p50, r.p90),
+                }
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("Brain Forecast Error ({}): {}", symbol, e);
+                client.record_error(&e);
+            }
+            Err(_) => {
+                tracing::warn!("Brain Forecast Timeout ({})", symbol);
+                client.record_timeout();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, ManualClock};
+
+    #[test]
+    fn test_window_aligns_volumes_to_prices() {
+        let prices: Vec<f64> = (0..40).map(|i| 100.0 + i as f64).collect();
+        let volumes: VecDeque<f64> = (0..10).map(|i| i as f64).collect();
+
+        let window = build_window(prices.clone(), Some(&volumes), 64).unwrap();
+        assert_eq!(window.window_size, 40);
+        assert_eq!(window.prices, prices);
+        assert_eq!(window.volumes.len(), 40);
+        assert!(window.volumes[..30].iter().all(|v| *v == 0.0));
+        assert_eq!(&window.volumes[30..], &(0..10).map(|i| i as f64).collect::<Vec<_>>()[..]);
+
+        // Less than half a window: nothing to send yet
+        assert!(build_window(prices[..31].to_vec(), None, 64).is_none());
+    }
+
+    #[test]
+    fn test_cache_ttl_and_no_brain() {
+        let clock = Arc::new(ManualClock::new());
+        let mut service = ForecastService::with_clock(ForecastConfig::default(), None, clock.clone());
+        assert!(!service.refresh("XBT/USD", &PhysicsEngine::new(100))); // No Brain, nothing queued
+
+        let quantiles = ForecastQuantiles::new(99.0, 100.0, 101.0, ForecastSource::Brain).unwrap();
+        let forecast = TimedForecast { quantiles, horizon: 60.0, fetched_at_ms: clock.unix_millis() };
+        service.cache.write().unwrap().insert("XBT/USD".to_string(), forecast);
+
+        assert_eq!(service.get("XBT/USD"), Some(forecast));
+        assert_eq!(service.get("ETH/USD"), None);
+        clock.advance(Duration::from_secs(59));
+        assert!(service.get("XBT/USD").is_some());
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(service.get("XBT/USD"), None); // Stale: consumers fall back
+    }
+}
@@ -1 +1,2 @@
 pub mod veto_gate;
+pub mod forecast; // Brain Forecast RPC, refreshed off-loop per symbol
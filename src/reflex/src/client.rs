@@ -1,4 +1,8 @@
-use tonic::transport::Channel;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tonic::transport::{Channel, Endpoint};
 
 // Import generated types
 pub mod brain {
@@ -8,15 +12,298 @@ pub mod brain {
 use brain::brain_service_client::BrainServiceClient;
 use brain::StateVector;
 use crate::auditor::truth_envelope::TruthEnvelope; // D-87
+use crate::clock::{self, SharedClock};
+
+const FAILURE_THRESHOLD: u32 = 3; // Consecutive timeouts / transport errors before the circuit opens
+const BASE_COOLDOWN: Duration = Duration::from_secs(1); // First open period; doubles per failed probe
+const MAX_COOLDOWN: Duration = Duration::from_secs(30);
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(250);
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CircuitState {
+    Closed,   // Calls flow
+    Open,     // Calls refused until the cooldown passes (orient runs blind)
+    HalfOpen, // One probe call allowed
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BrainStatus {
+    Healthy,
+    Degraded, // Reachable, but recent calls failed or a probe is pending
+    Down,
+}
+
+/// Brain link health (pushed into SharedState by the owner loop).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BrainHealth {
+    pub status: BrainStatus,
+    pub circuit: CircuitState,
+    pub consecutive_failures: u32,
+    pub trips: u64, // Times the circuit has opened
+    pub last_pulse_ms: Option<i64>, // Unix ms of the last answered Heartbeat
+    pub pulse_rtt_ms: Option<f64>,
+    pub last_error: Option<String>,
+    pub schema_mismatch: Option<u32>, // Context schema version the firewall refuses (set by the owner loop)
+}
+
+impl Default for BrainHealth {
+    fn default() -> Self {
+        Self {
+            status: BrainStatus::Down,
+            circuit: CircuitState::Closed,
+            consecutive_failures: 0,
+            trips: 0,
+            last_pulse_ms: None,
+            pulse_rtt_ms: None,
+            last_error: None,
+            schema_mismatch: None,
+        }
+    }
+}
+
+/// Circuit Breaker over Brain calls.
+/// Opens after FAILURE_THRESHOLD consecutive failures; after the cooldown a single probe
+/// goes out. A failed probe reopens with twice the cooldown (the reconnect backoff), a
+/// success closes it.
+pub struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    cooldown: Duration,
+    opened_at: Instant,
+    probe_in_flight: bool,
+    trips: u64,
+    clock: SharedClock,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            cooldown: BASE_COOLDOWN,
+            opened_at: clock.now(),
+            probe_in_flight: false,
+            trips: 0,
+            clock,
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// May a call go out now? Claims the probe when half-open.
+    pub fn allow(&mut self) -> bool {
+        if self.state == CircuitState::Open && self.clock.elapsed_since(self.opened_at) >= self.cooldown {
+            self.state = CircuitState::HalfOpen;
+            self.probe_in_flight = false;
+        }
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen if self.probe_in_flight => false,
+            CircuitState::HalfOpen => {
+                self.probe_in_flight = true;
+                true
+            }
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        if self.state != CircuitState::Closed {
+            tracing::info!("🧠 BRAIN CIRCUIT CLOSED (probe answered)");
+        }
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+        self.cooldown = BASE_COOLDOWN;
+        self.probe_in_flight = false;
+    }
+
+    /// Returns true if this failure opened the circuit.
+    pub fn record_failure(&mut self) -> bool {
+        self.consecutive_failures += 1;
+        self.probe_in_flight = false;
+        let open = match self.state {
+            CircuitState::HalfOpen => {
+                self.cooldown = (self.cooldown * 2).min(MAX_COOLDOWN);
+                true
+            }
+            CircuitState::Closed => self.consecutive_failures >= FAILURE_THRESHOLD,
+            CircuitState::Open => false,
+        };
+        if open {
+            self.state = CircuitState::Open;
+            self.opened_at = self.clock.now();
+            self.trips += 1;
+            tracing::warn!("🧠 BRAIN CIRCUIT OPEN after {} failures. Blind physics for {:?}.", self.consecutive_failures, self.cooldown);
+        }
+        open
+    }
+
+    /// Liveness proven out of band (Heartbeat answered): an open circuit may probe now.
+    pub fn nudge(&mut self) {
+        if self.state == CircuitState::Open {
+            self.state = CircuitState::HalfOpen;
+            self.probe_in_flight = false;
+        }
+    }
+}
+
+/// Breaker and pulse record, shared between the owner loop and the heartbeat task.
+struct BrainLink {
+    breaker: CircuitBreaker,
+    last_pulse_ms: Option<i64>,
+    pulse_rtt_ms: Option<f64>,
+    last_error: Option<String>,
+}
+
+impl BrainLink {
+    fn failed(&mut self, error: String) {
+        self.breaker.record_failure();
+        self.last_error = Some(error);
+    }
+
+    fn health(&self) -> BrainHealth {
+        let circuit = self.breaker.state();
+        let failures = self.breaker.consecutive_failures;
+        let status = match circuit {
+            CircuitState::Open => BrainStatus::Down,
+            CircuitState::Closed if failures == 0 => BrainStatus::Healthy,
+            _ => BrainStatus::Degraded,
+        };
+        BrainHealth {
+            status,
+            circuit,
+            consecutive_failures: failures,
+            trips: self.breaker.trips,
+            last_pulse_ms: self.last_pulse_ms,
+            pulse_rtt_ms: self.pulse_rtt_ms,
+            last_error: self.last_error.clone(),
+            schema_mismatch: None,
+        }
+    }
+}
+
+/// Timeouts and transport errors count against the link; application errors mean it is up.
+fn is_link_failure(status: &tonic::Status) -> bool {
+    matches!(
+        status.code(),
+        tonic::Code::Unavailable | tonic::Code::DeadlineExceeded | tonic::Code::Cancelled | tonic::Code::Unknown
+    )
+}
 
+/// Clones share the channel and the circuit breaker.
+#[derive(Clone)]
 pub struct BrainClient {
     client: BrainServiceClient<Channel>,
+    link: Arc<Mutex<BrainLink>>,
 }
 
 impl BrainClient {
     pub async fn connect(dst: String) -> Result<Self, tonic::transport::Error> {
         let client = BrainServiceClient::connect(dst).await?;
-        Ok(Self { client })
+        Ok(Self::from_client(client, clock::system()))
+    }
+
+    /// Client over a lazily dialled channel: starts without the Brain and redials on the
+    /// calls the circuit breaker lets through, so a Brain that comes (back) up is picked up.
+    pub fn lazy(dst: String) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(dst)?.connect_timeout(CONNECT_TIMEOUT).connect_lazy();
+        Ok(Self::from_client(BrainServiceClient::new(channel), clock::system()))
+    }
+
+    fn from_client(client: BrainServiceClient<Channel>, clock: SharedClock) -> Self {
+        let link = BrainLink {
+            breaker: CircuitBreaker::with_clock(clock),
+            last_pulse_ms: None,
+            pulse_rtt_ms: None,
+            last_error: None,
+        };
+        Self { client, link: Arc::new(Mutex::new(link)) }
+    }
+
+    /// False while the circuit is open: callers skip the Brain (blind physics) instead of
+    /// spending their timeout on it.
+    pub fn available(&self) -> bool {
+        self.link.lock().map(|mut l| l.breaker.allow()).unwrap_or(false)
+    }
+
+    pub fn record_success(&self) {
+        if let Ok(mut l) = self.link.lock() {
+            l.breaker.record_success();
+        }
+    }
+
+    pub fn record_timeout(&self) {
+        if let Ok(mut l) = self.link.lock() {
+            l.failed("timeout".to_string());
+        }
+    }
+
+    pub fn record_error(&self, status: &tonic::Status) {
+        if let Ok(mut l) = self.link.lock() {
+            if is_link_failure(status) {
+                l.failed(status.message().to_string());
+            } else {
+                l.breaker.record_success();
+            }
+        }
+    }
+
+    pub fn health(&self) -> BrainHealth {
+        self.link.lock().map(|l| l.health()).unwrap_or_default()
+    }
+
+    /// Heartbeat pulses on their own task. A missed pulse counts as a failure and the next
+    /// one backs off (doubling up to MAX_COOLDOWN); an answered pulse lets an open circuit probe.
+    pub fn spawn_heartbeat(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let mut client = self.client.clone();
+        let link = self.link.clone();
+        tokio::spawn(async move {
+            let mut delay = interval;
+            loop {
+                tokio::time::sleep(delay).await;
+                let pulse = brain::Pulse {
+                    timestamp: chrono::Utc::now().timestamp_millis(),
+                    organ: "REFLEX".to_string(),
+                };
+                let sent = Instant::now();
+                let result = tokio::time::timeout(HEARTBEAT_TIMEOUT, client.heartbeat(tonic::Request::new(pulse))).await;
+                let Ok(mut l) = link.lock() else { return };
+                match result {
+                    Ok(Ok(ack)) if ack.get_ref().alive => {
+                        l.last_pulse_ms = Some(chrono::Utc::now().timestamp_millis());
+                        l.pulse_rtt_ms = Some(sent.elapsed().as_secs_f64() * 1000.0);
+                        l.breaker.nudge();
+                        delay = interval;
+                    }
+                    failure => {
+                        l.failed(match failure {
+                            Ok(Ok(_)) => "Heartbeat: Brain reports not alive".to_string(),
+                            Ok(Err(status)) => format!("Heartbeat: {}", status.message()),
+                            Err(_) => "Heartbeat: timeout".to_string(),
+                        });
+                        delay = (delay * 2).min(MAX_COOLDOWN);
+                    }
+                }
+            }
+        })
     }
 
     pub async fn reason(
@@ -64,9 +351,96 @@ impl BrainClient {
         Ok(response.into_inner())
     }
 
+    /// Forecast quantiles (absolute prices) for the window's horizon.
+    pub async fn forecast(
+        &mut self,
+        prices: Vec<f64>,
+        volumes: Vec<f64>,
+    ) -> Result<brain::ForecastResult, tonic::Status> {
+        let window_size = prices.len() as i32;
+        let request = tonic::Request::new(brain::HistoryWindow { prices, volumes, window_size });
+        let response = self.client.forecast(request).await?;
+        Ok(response.into_inner())
+    }
+
+    /// Ignition Gate 4: go/no-go for live trading. Caller applies the timeout.
+    pub async fn request_blessing(
+        &mut self,
+        request: brain::BlessingRequest,
+    ) -> Result<brain::BlessingVerdict, tonic::Status> {
+        let response = self.client.request_blessing(tonic::Request::new(request)).await?;
+        Ok(response.into_inner())
+    }
+
     pub async fn reset_state(&mut self) -> Result<brain::Ack, tonic::Status> {
         let request = tonic::Request::new(brain::Empty {});
         let response = self.client.reset_state(request).await?;
         Ok(response.into_inner())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+
+    #[test]
+    fn test_circuit_opens_after_repeated_failures_and_backs_off() {
+        let clock = Arc::new(ManualClock::new());
+        let mut breaker = CircuitBreaker::with_clock(clock.clone());
+
+        assert!(breaker.allow());
+        assert!(!breaker.record_failure());
+        assert!(!breaker.record_failure());
+        assert!(breaker.record_failure());
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow());
+
+        // One probe after the cooldown; its failure doubles the cooldown
+        clock.advance(BASE_COOLDOWN);
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+        assert!(breaker.record_failure());
+        assert_eq!(breaker.cooldown(), BASE_COOLDOWN * 2);
+        clock.advance(BASE_COOLDOWN);
+        assert!(!breaker.allow());
+        clock.advance(BASE_COOLDOWN);
+        assert!(breaker.allow());
+
+        // A successful probe closes and resets the backoff
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.cooldown(), BASE_COOLDOWN);
+        assert!(breaker.allow());
+    }
+
+    #[test]
+    fn test_heartbeat_nudge_and_health() {
+        let clock = Arc::new(ManualClock::new());
+        let mut link = BrainLink {
+            breaker: CircuitBreaker::with_clock(clock),
+            last_pulse_ms: None,
+            pulse_rtt_ms: None,
+            last_error: None,
+        };
+        assert_eq!(link.health().status, BrainStatus::Healthy);
+
+        link.failed("timeout".to_string());
+        assert_eq!(link.health().status, BrainStatus::Degraded);
+        link.failed("timeout".to_string());
+        link.failed("timeout".to_string());
+        let health = link.health();
+        assert_eq!((health.status, health.circuit, health.trips), (BrainStatus::Down, CircuitState::Open, 1));
+        assert_eq!(health.last_error.as_deref(), Some("timeout"));
+
+        // An answered pulse lets the open circuit probe before the cooldown ends
+        assert!(!link.breaker.allow());
+        link.breaker.nudge();
+        assert!(link.breaker.allow());
+        assert_eq!(link.health().status, BrainStatus::Degraded);
+
+        // Application errors mean the link is up
+        assert!(!is_link_failure(&tonic::Status::invalid_argument("bad window")));
+        assert!(is_link_failure(&tonic::Status::unavailable("connection refused")));
+    }
+}
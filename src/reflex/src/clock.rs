@@ -0,0 +1,171 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Time source for every time-dependent governor.
+/// Live runs on `SystemClock`; sim replays drive a `SimulatedClock` from tick
+/// timestamps; tests step a `ManualClock` instead of sleeping.
+pub trait Clock: Send + Sync {
+    /// Monotonic time, for elapsed/cooldown checks.
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, for anything compared against exchange/Brain timestamps.
+    fn system_time(&self) -> SystemTime;
+
+    fn unix_millis(&self) -> i64 {
+        self.system_time().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as i64
+    }
+
+    fn unix_nanos(&self) -> u128 {
+        self.system_time().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+    }
+
+    fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The real clock.
+pub fn system() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Virtual time: a fixed origin plus an offset that only moves when told to.
+#[derive(Debug)]
+struct VirtualTime {
+    origin: Instant,
+    epoch: SystemTime, // Wall-clock time at `origin`
+    offset_nanos: AtomicU64,
+}
+
+impl VirtualTime {
+    fn new(epoch: SystemTime) -> Self {
+        Self { origin: Instant::now(), epoch, offset_nanos: AtomicU64::new(0) }
+    }
+
+    fn offset(&self) -> Duration {
+        Duration::from_nanos(self.offset_nanos.load(Ordering::Acquire))
+    }
+
+    fn advance(&self, by: Duration) {
+        self.offset_nanos.fetch_add(by.as_nanos() as u64, Ordering::AcqRel);
+    }
+
+    /// Moves forward to `target` wall-clock time. Never goes backwards.
+    fn advance_to(&self, target: SystemTime) {
+        if let Ok(since_epoch) = target.duration_since(self.epoch) {
+            self.offset_nanos.fetch_max(since_epoch.as_nanos() as u64, Ordering::AcqRel);
+        }
+    }
+}
+
+/// Test clock: starts at the current wall time and moves only via `advance`.
+#[derive(Debug)]
+pub struct ManualClock {
+    time: VirtualTime,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self { time: VirtualTime::new(SystemTime::now()) }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.time.advance(by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.time.origin + self.time.offset()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.time.epoch + self.time.offset()
+    }
+}
+
+/// Replay clock: follows the timestamps of the ticks being replayed.
+/// Out-of-order ticks do not move it backwards.
+#[derive(Debug)]
+pub struct SimulatedClock {
+    time: VirtualTime,
+}
+
+impl SimulatedClock {
+    /// Starts at `start_ms` (Unix millis of the first replayed tick).
+    pub fn new(start_ms: i64) -> Self {
+        Self { time: VirtualTime::new(UNIX_EPOCH + Duration::from_millis(start_ms.max(0) as u64)) }
+    }
+
+    /// Advance to a tick timestamp (Unix millis, fractional allowed).
+    pub fn on_tick(&self, timestamp_ms: f64) {
+        if timestamp_ms.is_finite() && timestamp_ms >= 0.0 {
+            self.time.advance_to(UNIX_EPOCH + Duration::from_nanos((timestamp_ms * 1e6).round() as u64));
+        }
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> Instant {
+        self.time.origin + self.time.offset()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.time.epoch + self.time.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_only_moves_when_told() {
+        let clock = ManualClock::new();
+        let t0 = clock.now();
+        let wall0 = clock.unix_millis();
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.now(), t0);
+
+        clock.advance(Duration::from_secs(300));
+        assert_eq!(clock.elapsed_since(t0), Duration::from_secs(300));
+        assert_eq!(clock.unix_millis() - wall0, 300_000);
+    }
+
+    #[test]
+    fn test_simulated_clock_follows_ticks() {
+        let clock = SimulatedClock::new(1_700_000_000_000);
+        let t0 = clock.now();
+        assert_eq!(clock.unix_millis(), 1_700_000_000_000);
+
+        clock.on_tick(1_700_000_001_500.0);
+        assert_eq!(clock.unix_millis(), 1_700_000_001_500);
+        assert_eq!(clock.elapsed_since(t0), Duration::from_millis(1500));
+
+        // Late tick: no time travel
+        clock.on_tick(1_700_000_001_000.0);
+        assert_eq!(clock.unix_millis(), 1_700_000_001_500);
+    }
+}
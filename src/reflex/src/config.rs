@@ -1,5 +1,8 @@
 use std::env;
 use std::fmt;
+use std::time::Duration;
+use crate::governor::sentinel::PhaseBudgets;
+use crate::taleb::tail::{self, TailMethod};
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -9,6 +12,18 @@ pub struct Config {
     pub database_url: String,
     pub questdb_host: String,
     pub questdb_ilp_port: String,
+    pub risk_timezone: String,    // IANA name for start-of-day rollover
+    pub risk_rollover_hour: u32,  // Local hour the trading day starts
+    pub regime_model_path: Option<String>, // Trained Gaussian HMM (JSON); thresholds if unset
+    pub adapter_map_path: Option<String>, // D-95 EnsembleConfig (JSON); built-in map if unset
+    pub sovereign_keys_path: Option<String>, // D-86 GateConfig (JSON); no operators (all commands refused) if unset
+    pub schedule_path: Option<String>, // ScheduleConfig (JSON); open around the clock if unset
+    pub allocator_path: Option<String>, // AllocatorConfig (JSON); whole account to one pipeline if unset
+    pub firewall_path: Option<String>, // D-87 FirewallConfig (JSON); built-in rules if unset
+    pub drawdown_path: Option<String>, // DrawdownConfig (JSON); built-in breaker tiers if unset
+    pub phase_budgets: PhaseBudgets, // OODA_<PHASE>_BUDGET_US overrides per phase
+    pub tail_method: TailMethod, // Realized tail estimator: historical, ewma or cornish-fisher
+    pub tail_horizon: Duration, // Realized return sampling interval (the forecast horizon)
 }
 
 #[derive(Debug)]
@@ -43,6 +58,48 @@ impl Config {
         let questdb_ilp_port = env::var("QUESTDB_ILP_PORT")
             .unwrap_or_else(|_| "9009".to_string());
 
+        let risk_timezone = env::var("RISK_TIMEZONE")
+            .unwrap_or_else(|_| "UTC".to_string());
+
+        let risk_rollover_hour = env::var("RISK_ROLLOVER_HOUR")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+
+        let regime_model_path = env::var("REGIME_MODEL_PATH").ok().filter(|p| !p.is_empty());
+        let adapter_map_path = env::var("ADAPTER_MAP_PATH").ok().filter(|p| !p.is_empty());
+        let sovereign_keys_path = env::var("SOVEREIGN_KEYS_PATH").ok().filter(|p| !p.is_empty());
+        let schedule_path = env::var("SCHEDULE_PATH").ok().filter(|p| !p.is_empty());
+        let allocator_path = env::var("ALLOCATOR_PATH").ok().filter(|p| !p.is_empty());
+        let firewall_path = env::var("FIREWALL_PATH").ok().filter(|p| !p.is_empty());
+        let drawdown_path = env::var("DRAWDOWN_PATH").ok().filter(|p| !p.is_empty());
+
+        let budget_us = |var: &str, default: Duration| {
+            env::var(var)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_micros)
+                .unwrap_or(default)
+        };
+        let defaults = PhaseBudgets::default();
+        let phase_budgets = PhaseBudgets {
+            observe: budget_us("OODA_OBSERVE_BUDGET_US", defaults.observe),
+            orient: budget_us("OODA_ORIENT_BUDGET_US", defaults.orient),
+            decide: budget_us("OODA_DECIDE_BUDGET_US", defaults.decide),
+            act: budget_us("OODA_ACT_BUDGET_US", defaults.act),
+        };
+
+        let tail_method = env::var("TAIL_METHOD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(TailMethod::Historical);
+
+        let tail_horizon = env::var("TAIL_HORIZON_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(tail::DEFAULT_HORIZON);
+
         Ok(Self {
             kraken_api_key,
             kraken_secret,
@@ -50,6 +107,18 @@ impl Config {
             database_url,
             questdb_host,
             questdb_ilp_port,
+            risk_timezone,
+            risk_rollover_hour,
+            regime_model_path,
+            adapter_map_path,
+            sovereign_keys_path,
+            schedule_path,
+            allocator_path,
+            firewall_path,
+            drawdown_path,
+            phase_budgets,
+            tail_method,
+            tail_horizon,
         })
     }
 }
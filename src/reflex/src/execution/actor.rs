@@ -41,14 +41,31 @@ impl ExecutionAdapter {
         );
     }
 
+    /// Ignition Gate 3: minimum-size buy then sell straight back.
+    /// Returns the filled quantity of each leg (buy, sell) for reconciliation.
+    pub async fn execute_round_trip(&self, price: f64, qty: f64) -> Result<(f64, f64), String> {
+        if !self.limiter.try_consume(2.0) {
+            warn!("⚠️ PENNY TRADE BLOCKED: Rate Limit Exceeded.");
+            return Err("rate limited".to_string());
+        }
+
+        info!("🪙 PENNY TRADE: BUY {} @ ${:.2} -> SELL {} @ ${:.2}", qty, price, qty, price);
+
+        // Simulate both legs coming back "Filled" (same as the Sniper path)
+        let (bought, sold) = (qty, qty);
+        info!("✅ PENNY FILLED: Bought {} / Sold {}", bought, sold);
+        Ok((bought, sold))
+    }
+
     /// The Nuclear Path: For Risk Shroud Exits.
     /// Uses an Immediate-Or-Cancel (IOC) Market Order to dump risk at any cost.
     /// This bypasses standard niceties but respects rate limits (to avoid bans).
-    pub async fn execute_nuclear(&self, proposal: &TradeProposal, reason: &str) {
+    /// Err = not confirmed; the caller keeps the position protected and retries.
+    pub async fn execute_nuclear(&self, proposal: &TradeProposal, reason: &str) -> Result<(), String> {
         if !self.limiter.try_consume(1.0) {
-            error!("🚨 RADIOLOGICAL ALARM: Rate Limit Blocked Nuclear Exit! Retrying immediately...");
+            error!("🚨 RADIOLOGICAL ALARM: Rate Limit Blocked Nuclear Exit! Retrying next cycle...");
             // Real logic: We might have a backup API key or emergency circuit here
-            // For simulation: Force through or log critical failure
+            return Err("rate limited".to_string());
         }
 
         let start = Instant::now();
@@ -63,23 +80,25 @@ impl ExecutionAdapter {
             "✅ NUCLEAR CONFIRMED: {} {} Sold. (Latency: {:?})", 
             proposal.side, proposal.qty, latency
         );
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::taleb::Side;
 
     #[tokio::test]
     async fn test_nuclear_dispatch() {
         let adapter = ExecutionAdapter::new();
         let proposal = TradeProposal {
-            side: "SELL".to_string(),
+            side: Side::Sell,
             price: 50000.0,
             qty: 0.5,
         };
 
         // Should not panic and should log
-        adapter.execute_nuclear(&proposal, "Test Panic").await;
+        assert!(adapter.execute_nuclear(&proposal, "Test Panic").await.is_ok());
     }
 }
@@ -84,6 +84,12 @@ impl PhysicsEngine {
         }
     }
 
+    /// The last `n` prices seen (oldest first), e.g. for the Brain HistoryWindow.
+    pub fn recent_prices(&self, n: usize) -> Vec<f64> {
+        let skip = self.history.len().saturating_sub(n);
+        self.history.iter().skip(skip).map(|&(_, price)| price).collect()
+    }
+
 <<<<<<< HEAD
     pub fn update(&mut self, price: f64, timestamp: f64, sequence_id: u64, spread: f64) -> PhysicsState {
 =======
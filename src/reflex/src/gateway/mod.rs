@@ -2,3 +2,4 @@ pub mod order_manager;
 pub mod venue_sentry;
 pub mod binary_packer; // D-94
 pub mod vault; // D-98
+pub mod pre_trade;
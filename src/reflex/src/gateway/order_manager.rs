@@ -1,7 +1,12 @@
  // Or Sha512 depending on exchange
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
+use crate::gateway::pre_trade::RestingOrder;
 use crate::governor::wave_legislator::WaveVerdict;
+use crate::ledger;
+
+/// Id the primed order rests under in the pre-trade guard.
+pub const PRIMED_ORDER_ID: &str = "GATEWAY-PRIMED";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Side {
@@ -45,17 +50,19 @@ impl OrderGateway {
     }
 
     /// Primary Logic: converts a "Tunneling" verdict into a "Primed Order".
-    /// This is the "Pre-Ignition" phase.
-    pub fn prime_order(&mut self, verdict: &WaveVerdict, symbol: &str) {
+    /// This is the "Pre-Ignition" phase. The side follows the projected landing
+    /// zone relative to `price` (through resistance -> Buy, through support -> Sell).
+    pub fn prime_order(&mut self, verdict: &WaveVerdict, symbol: &str, price: f64) {
         match verdict {
             WaveVerdict::Tunneling { probability: _, target_price } => {
                 let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
                 
                 // 1. Construct the payload (Simulation for now)
                 // In real impl, this would be JSON or FIX bytes
+                let side = if *target_price >= price { Side::Buy } else { Side::Sell };
                 let payload_str = format!(
-                    r#"{{"event":"addOrder","pair":"{}","type":"limit","price":{},"ordertype":"limit"}}"#, 
-                    symbol, target_price
+                    r#"{{"event":"addOrder","pair":"{}","type":"{}","price":{},"ordertype":"limit"}}"#, 
+                    symbol, if matches!(side, Side::Buy) { "buy" } else { "sell" }, target_price
                 );
                 
                 // 2. Sign (Simulated HMAC)
@@ -70,7 +77,7 @@ impl OrderGateway {
                 // 3. Buffer it
                 self.hot_buffer = Some(PrimedOrder {
                     symbol: symbol.to_string(),
-                    side: Side::Buy, // Simplified for this context
+                    side,
                     qty: 1.0,        // Default unit
                     price: Some(*target_price),
                     payload: final_payload,
@@ -86,6 +93,30 @@ impl OrderGateway {
         }
     }
 
+    pub fn primed(&self) -> Option<&PrimedOrder> {
+        self.hot_buffer.as_ref()
+    }
+
+    /// The primed limit order as self-trade prevention sees it.
+    pub fn resting(&self) -> Option<RestingOrder> {
+        let order = self.hot_buffer.as_ref()?;
+        Some(RestingOrder {
+            id: PRIMED_ORDER_ID.to_string(),
+            side: match order.side {
+                Side::Buy => ledger::Side::Buy,
+                Side::Sell => ledger::Side::Sell,
+            },
+            price: order.price?,
+            qty: order.qty,
+        })
+    }
+
+    /// D-86 CANCEL_ALL: drop the primed order without touching positions.
+    /// Returns TRUE if one was primed.
+    pub fn disarm(&mut self) -> bool {
+        self.hot_buffer.take().is_some()
+    }
+
     /// Phase B: The Trigger
     /// D-61 confirms the move. We send bytes immediately.
     /// Returns the timestamp of "Wire Send"
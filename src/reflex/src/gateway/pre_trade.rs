@@ -0,0 +1,388 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use crate::clock::{self, SharedClock};
+use crate::ledger::Side;
+
+const REJECTION_LOG_SIZE: usize = 256;
+const REFERENCE_WINDOW: usize = 21; // Marks behind the median band reference
+
+/// Static sanity limits applied to every order before it reaches the wire.
+#[derive(Debug, Clone)]
+pub struct PreTradeLimits {
+    pub price_band_pct: f64,      // Max deviation from reference price (0.02 = 2%)
+    pub max_notional: f64,        // USD
+    pub max_qty: f64,             // Base units
+    pub max_orders_per_sec: usize,
+    pub max_orders_per_min: usize,
+    pub duplicate_window: Duration,
+    pub duplicate_price_tolerance_pct: f64,
+}
+
+impl Default for PreTradeLimits {
+    fn default() -> Self {
+        Self {
+            price_band_pct: 0.02,
+            max_notional: 50_000.0,
+            max_qty: 5.0,
+            max_orders_per_sec: 5,
+            max_orders_per_min: 60,
+            duplicate_window: Duration::from_secs(1),
+            duplicate_price_tolerance_pct: 0.0001, // 1 bp
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreTradeReject {
+    InvalidOrder(String),
+    NoReferencePrice,
+    PriceBand { price: f64, reference: f64, deviation_pct: f64 },
+    MaxNotional { notional: f64, limit: f64 },
+    MaxQty { qty: f64, limit: f64 },
+    RateLimitSecond { count: usize, limit: usize },
+    RateLimitMinute { count: usize, limit: usize },
+    Duplicate,
+    SelfTrade { resting_id: String, resting_price: f64 },
+}
+
+impl fmt::Display for PreTradeReject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreTradeReject::InvalidOrder(r) => write!(f, "Invalid Order: {}", r),
+            PreTradeReject::NoReferencePrice => write!(f, "No Reference Price"),
+            PreTradeReject::PriceBand { price, reference, deviation_pct } => write!(
+                f,
+                "Price Band: {:.2} vs Ref {:.2} ({:.2}%)",
+                price, reference, deviation_pct * 100.0
+            ),
+            PreTradeReject::MaxNotional { notional, limit } => {
+                write!(f, "Max Notional: {:.2} > {:.2}", notional, limit)
+            }
+            PreTradeReject::MaxQty { qty, limit } => write!(f, "Max Qty: {:.6} > {:.6}", qty, limit),
+            PreTradeReject::RateLimitSecond { count, limit } => {
+                write!(f, "Rate Limit: {} orders/sec (limit {})", count, limit)
+            }
+            PreTradeReject::RateLimitMinute { count, limit } => {
+                write!(f, "Rate Limit: {} orders/min (limit {})", count, limit)
+            }
+            PreTradeReject::Duplicate => write!(f, "Duplicate Order"),
+            PreTradeReject::SelfTrade { resting_id, resting_price } => write!(
+                f,
+                "Self-Trade: would cross own resting order {} @ {:.2}",
+                resting_id, resting_price
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RejectionRecord {
+    pub at: Instant,
+    pub side: Side,
+    pub price: f64,
+    pub qty: f64,
+    pub reason: PreTradeReject,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestingOrder {
+    pub id: String,
+    pub side: Side,
+    pub price: f64,
+    pub qty: f64,
+}
+
+#[derive(Debug, Clone)]
+struct SentOrder {
+    at: Instant,
+    side: Side,
+    price: f64,
+    qty: f64,
+}
+
+/// Pre-Trade Guard: the last line between a `Decision` and `BinaryPacker`.
+/// Catches fat-finger prices/sizes, runaway loops and crossing our own book.
+pub struct PreTradeGuard {
+    pub limits: PreTradeLimits,
+    reference_price: Option<f64>,
+    marks: VecDeque<f64>,
+    sent: VecDeque<SentOrder>,
+    resting: Vec<RestingOrder>,
+    rejections: VecDeque<RejectionRecord>,
+    clock: SharedClock,
+}
+
+impl Default for PreTradeGuard {
+    fn default() -> Self {
+        Self::new(PreTradeLimits::default())
+    }
+}
+
+impl PreTradeGuard {
+    pub fn new(limits: PreTradeLimits) -> Self {
+        Self::with_clock(limits, clock::system())
+    }
+
+    pub fn with_clock(limits: PreTradeLimits, clock: SharedClock) -> Self {
+        Self {
+            limits,
+            reference_price: None,
+            marks: VecDeque::with_capacity(REFERENCE_WINDOW),
+            sent: VecDeque::new(),
+            resting: Vec::new(),
+            rejections: VecDeque::with_capacity(REJECTION_LOG_SIZE),
+            clock,
+        }
+    }
+
+    /// Reference price for the price band (last trusted market observation).
+    pub fn set_reference_price(&mut self, price: f64) {
+        if price.is_finite() && price > 0.0 {
+            self.reference_price = Some(price);
+        }
+    }
+
+    /// Feeds a market mark into the trailing median that serves as the band reference,
+    /// so a single print cannot drag the reference along with the price it is checked against.
+    pub fn observe_mark(&mut self, price: f64) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        if self.marks.len() >= REFERENCE_WINDOW {
+            self.marks.pop_front();
+        }
+        self.marks.push_back(price);
+        let mut sorted: Vec<f64> = self.marks.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        self.reference_price = Some(sorted[sorted.len() / 2]);
+    }
+
+    pub fn add_resting(&mut self, order: RestingOrder) {
+        self.resting.push(order);
+    }
+
+    pub fn remove_resting(&mut self, id: &str) {
+        self.resting.retain(|o| o.id != id);
+    }
+
+    /// Keeps only the resting orders `keep` still vouches for.
+    pub fn retain_resting(&mut self, keep: impl FnMut(&RestingOrder) -> bool) {
+        self.resting.retain(keep);
+    }
+
+    pub fn clear_resting(&mut self) {
+        self.resting.clear();
+    }
+
+    /// Most recent rejections, oldest first.
+    pub fn rejections(&self) -> impl Iterator<Item = &RejectionRecord> {
+        self.rejections.iter()
+    }
+
+    pub fn check(&mut self, side: Side, price: f64, qty: f64) -> Result<(), PreTradeReject> {
+        let now = self.clock.now();
+        self.check_at(side, price, qty, now)
+    }
+
+    /// Validates an order and, if accepted, records it against the rate and duplicate windows.
+    pub fn check_at(&mut self, side: Side, price: f64, qty: f64, now: Instant) -> Result<(), PreTradeReject> {
+        match self.evaluate(side, price, qty, now) {
+            Ok(()) => {
+                self.sent.push_back(SentOrder { at: now, side, price, qty });
+                Ok(())
+            }
+            Err(reason) => Err(self.reject(side, price, qty, now, reason)),
+        }
+    }
+
+    /// Risk-off orders (trims) shrink exposure, so size, rate and duplicate limits must not
+    /// strand them; only sanity and the price band apply. They do not count against the windows.
+    pub fn check_risk_off(&mut self, side: Side, price: f64, qty: f64) -> Result<(), PreTradeReject> {
+        let now = self.clock.now();
+        self.evaluate_price(price, qty).map_err(|reason| self.reject(side, price, qty, now, reason))
+    }
+
+    fn reject(&mut self, side: Side, price: f64, qty: f64, now: Instant, reason: PreTradeReject) -> PreTradeReject {
+        warn!("🛑 PRE-TRADE REJECT: {} {:.6} @ {:.2} -> {}", side, qty, price, reason);
+        if self.rejections.len() >= REJECTION_LOG_SIZE {
+            self.rejections.pop_front();
+        }
+        self.rejections.push_back(RejectionRecord { at: now, side, price, qty, reason: reason.clone() });
+        reason
+    }
+
+    fn evaluate_price(&self, price: f64, qty: f64) -> Result<(), PreTradeReject> {
+        // 1. Basic Sanity
+        if !price.is_finite() || price <= 0.0 {
+            return Err(PreTradeReject::InvalidOrder(format!("price {}", price)));
+        }
+        if !qty.is_finite() || qty <= 0.0 {
+            return Err(PreTradeReject::InvalidOrder(format!("qty {}", qty)));
+        }
+
+        // 2. Price Band
+        let reference = self.reference_price.ok_or(PreTradeReject::NoReferencePrice)?;
+        let deviation_pct = (price - reference).abs() / reference;
+        if deviation_pct > self.limits.price_band_pct {
+            return Err(PreTradeReject::PriceBand { price, reference, deviation_pct });
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self, side: Side, price: f64, qty: f64, now: Instant) -> Result<(), PreTradeReject> {
+        self.evaluate_price(price, qty)?;
+
+        // 3. Size Limits
+        if qty > self.limits.max_qty {
+            return Err(PreTradeReject::MaxQty { qty, limit: self.limits.max_qty });
+        }
+        let notional = price * qty;
+        if notional > self.limits.max_notional {
+            return Err(PreTradeReject::MaxNotional { notional, limit: self.limits.max_notional });
+        }
+
+        // 4. Rate Limits (sliding windows over accepted orders)
+        while let Some(front) = self.sent.front() {
+            if now.saturating_duration_since(front.at) > Duration::from_secs(60) {
+                self.sent.pop_front();
+            } else {
+                break;
+            }
+        }
+        let per_min = self.sent.len();
+        if per_min >= self.limits.max_orders_per_min {
+            return Err(PreTradeReject::RateLimitMinute { count: per_min, limit: self.limits.max_orders_per_min });
+        }
+        let per_sec = self
+            .sent
+            .iter()
+            .filter(|o| now.saturating_duration_since(o.at) < Duration::from_secs(1))
+            .count();
+        if per_sec >= self.limits.max_orders_per_sec {
+            return Err(PreTradeReject::RateLimitSecond { count: per_sec, limit: self.limits.max_orders_per_sec });
+        }
+
+        // 5. Duplicate Detection
+        let tolerance = self.limits.duplicate_price_tolerance_pct;
+        let duplicate = self.sent.iter().any(|o| {
+            now.saturating_duration_since(o.at) < self.limits.duplicate_window
+                && o.side == side
+                && (o.qty - qty).abs() <= f64::EPSILON * qty.max(1.0)
+                && (o.price - price).abs() / price <= tolerance
+        });
+        if duplicate {
+            return Err(PreTradeReject::Duplicate);
+        }
+
+        // 6. Self-Trade Prevention
+        let crossing = self.resting.iter().find(|o| {
+            o.side != side
+                && match side {
+                    Side::Buy => price >= o.price,
+                    Side::Sell => price <= o.price,
+                }
+        });
+        if let Some(o) = crossing {
+            return Err(PreTradeReject::SelfTrade { resting_id: o.id.clone(), resting_price: o.price });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard() -> PreTradeGuard {
+        let mut g = PreTradeGuard::default();
+        g.set_reference_price(100.0);
+        g
+    }
+
+    #[test]
+    fn test_price_band_and_size() {
+        let mut g = guard();
+        assert!(g.check(Side::Buy, 100.5, 1.0).is_ok());
+        assert!(matches!(g.check(Side::Buy, 110.0, 1.0), Err(PreTradeReject::PriceBand { .. })));
+        assert!(matches!(g.check(Side::Sell, 100.0, 10.0), Err(PreTradeReject::MaxQty { .. })));
+
+        g.limits.max_notional = 150.0;
+        assert!(matches!(g.check(Side::Sell, 100.0, 2.0), Err(PreTradeReject::MaxNotional { .. })));
+        assert!(matches!(g.check(Side::Sell, f64::NAN, 1.0), Err(PreTradeReject::InvalidOrder(_))));
+
+        let mut fresh = PreTradeGuard::default();
+        assert_eq!(fresh.check(Side::Buy, 100.0, 1.0), Err(PreTradeReject::NoReferencePrice));
+    }
+
+    #[test]
+    fn test_rate_limits_and_duplicates() {
+        let mut g = guard();
+        let t0 = Instant::now();
+
+        assert!(g.check_at(Side::Buy, 100.0, 0.1, t0).is_ok());
+        assert_eq!(g.check_at(Side::Buy, 100.0, 0.1, t0), Err(PreTradeReject::Duplicate));
+
+        // Same order after the duplicate window is fine
+        let t1 = t0 + Duration::from_millis(1500);
+        assert!(g.check_at(Side::Buy, 100.0, 0.1, t1).is_ok());
+
+        // Per-second limit: 5 accepted within one second
+        for i in 0..4 {
+            assert!(g.check_at(Side::Buy, 100.0, 0.2 + i as f64 * 0.01, t1).is_ok());
+        }
+        assert!(matches!(g.check_at(Side::Buy, 100.0, 0.9, t1), Err(PreTradeReject::RateLimitSecond { .. })));
+
+        // Per-minute limit
+        g.limits.max_orders_per_min = 6;
+        let t2 = t1 + Duration::from_secs(2);
+        assert!(matches!(g.check_at(Side::Buy, 100.0, 0.9, t2), Err(PreTradeReject::RateLimitMinute { .. })));
+        let t3 = t0 + Duration::from_secs(62);
+        assert!(g.check_at(Side::Buy, 100.0, 0.9, t3).is_ok());
+
+        assert_eq!(g.rejections().count(), 3);
+    }
+
+    #[test]
+    fn test_self_trade_prevention() {
+        let mut g = guard();
+        g.add_resting(RestingOrder { id: "ASK-1".to_string(), side: Side::Sell, price: 100.5, qty: 1.0 });
+
+        assert!(matches!(g.check(Side::Buy, 100.6, 1.0), Err(PreTradeReject::SelfTrade { .. })));
+        assert!(g.check(Side::Buy, 100.4, 1.0).is_ok());
+        // Same-side orders never self-trade
+        assert!(g.check(Side::Sell, 100.7, 1.0).is_ok());
+
+        g.remove_resting("ASK-1");
+        assert!(g.check(Side::Buy, 100.6, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_risk_off_skips_size_rate_and_duplicate_limits() {
+        let mut g = guard();
+        g.limits.max_orders_per_sec = 1;
+        assert!(g.check(Side::Sell, 100.0, 1.0).is_ok());
+        assert!(g.check(Side::Sell, 100.0, 1.0).is_err());
+
+        // Oversized, repeated and over the rate: a trim still goes out
+        assert!(g.check_risk_off(Side::Sell, 100.0, 10.0).is_ok());
+        assert!(g.check_risk_off(Side::Sell, 100.0, 10.0).is_ok());
+        // The band still applies
+        assert!(matches!(g.check_risk_off(Side::Sell, 110.0, 1.0), Err(PreTradeReject::PriceBand { .. })));
+    }
+
+    #[test]
+    fn test_median_reference_ignores_the_checked_print() {
+        let mut g = PreTradeGuard::default();
+        for _ in 0..10 {
+            g.observe_mark(100.0);
+        }
+        // A fat print enters the window but cannot move the median it is checked against
+        g.observe_mark(110.0);
+        assert!(matches!(g.check(Side::Buy, 110.0, 1.0), Err(PreTradeReject::PriceBand { .. })));
+        assert!(g.check(Side::Buy, 100.5, 1.0).is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 use std::time::{Duration, Instant};
 use std::collections::VecDeque;
+use crate::clock::{self, SharedClock};
 
 const RTT_HISTORY_SIZE: usize = 20;
 const MAX_RTT_THRESHOLD_MS: u64 = 150; // D-56 Limit
@@ -16,15 +17,21 @@ pub struct VenueSentry {
     last_heartbeat: Instant,
     is_connected: bool,
     baseline_liquidity: f64,
+    clock: SharedClock,
 }
 
 impl VenueSentry {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             rtt_history: VecDeque::with_capacity(RTT_HISTORY_SIZE),
-            last_heartbeat: Instant::now(),
+            last_heartbeat: clock.now(),
             is_connected: true,
             baseline_liquidity: 0.0,
+            clock,
         }
     }
 
@@ -34,7 +41,7 @@ impl VenueSentry {
             self.rtt_history.pop_front();
         }
         self.rtt_history.push_back(rtt_ms);
-        self.last_heartbeat = Instant::now();
+        self.last_heartbeat = self.clock.now();
         self.is_connected = true;
     }
 
@@ -70,7 +77,7 @@ impl VenueSentry {
     /// Returns TRUE if we should VETO execution.
     pub fn should_veto(&self) -> bool {
         // 1. Connection Check
-        if self.last_heartbeat.elapsed() > Duration::from_secs(5) {
+        if self.clock.elapsed_since(self.last_heartbeat) > Duration::from_secs(5) {
             return true; // Broken Pipe
         }
 
@@ -114,14 +121,18 @@ mod tests {
 
     #[test]
     fn test_broken_pipe() {
-        let mut sentry = VenueSentry::new();
+        let clock = std::sync::Arc::new(crate::clock::ManualClock::new());
+        let mut sentry = VenueSentry::with_clock(clock.clone());
         sentry.record_heartbeat(20);
-        
-        // Simulate time passing (cannot mock Instant::now easily without trait, 
-        // so we just rely on logic correctness or manual test. 
-        // For unit test here, we can't easily sleep 5s. 
-        // We trust the `elapsed()` logic standard lib.)
-        // Skipping direct time wait test to avoid slow tests.
+        assert!(!sentry.should_veto());
+
+        // Heartbeat silence > 5s = Broken Pipe
+        clock.advance(Duration::from_secs(6));
+        assert!(sentry.should_veto());
+
+        // Fresh heartbeat restores the pipe
+        sentry.record_heartbeat(20);
+        assert!(!sentry.should_veto());
     }
 
     #[test]
@@ -1,15 +1,35 @@
+use crate::clock::{self, SharedClock};
+use crate::db::state::RedisStateStore;
 use crate::governor::wave_legislator::WaveLegislator;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+pub const AUDIT_STATE_KEY: &str = "governor:audit_changes";
 
 const HISTORY_SIZE: usize = 10;
-const SLIPPAGE_TOLERANCE_BPS: f64 = 2.0; // Basis points
-const DRIFT_SENSITIVITY: f64 = 0.05; // How much each bad trade impacts drift
+const CHANGE_LOG_LEN: usize = 100; // Threshold changes kept for review
 
 #[derive(Debug, Clone)]
 pub struct TradeResult {
     pub predicted_price: f64,
     pub filled_price: f64,
-    pub side: TradeSide, 
+    pub side: TradeSide,
+}
+
+impl TradeResult {
+    /// Adverse slippage in basis points of the predicted price (negative = price improvement).
+    pub fn slippage_bps(&self) -> f64 {
+        if self.predicted_price <= 0.0 {
+            return 0.0;
+        }
+        let slippage = match self.side {
+            TradeSide::Buy => self.filled_price - self.predicted_price,
+            TradeSide::Sell => self.predicted_price - self.filled_price,
+        };
+        slippage / self.predicted_price * 10_000.0
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,83 +38,271 @@ pub enum TradeSide {
     Sell,
 }
 
+/// Bounds and pacing for tunneling-threshold recalibration.
+#[derive(Debug, Clone)]
+pub struct RecalibrationConfig {
+    pub tolerance_bps: f64, // Slippage within tolerance adds no drift
+    pub full_scale_bps: f64, // Excess slippage that counts as a full miss (1.0)
+    pub min_trades: usize, // Evidence required before any adjustment
+    pub tighten_above: f64, // Drift that raises the threshold
+    pub relax_below: f64, // Drift that lowers it again (below tighten_above: hysteresis)
+    pub step: f64,
+    pub min_threshold: f64,
+    pub max_threshold: f64,
+    pub min_interval: Duration, // Rate limit between adjustments
+}
+
+impl Default for RecalibrationConfig {
+    fn default() -> Self {
+        Self {
+            tolerance_bps: 2.0,
+            full_scale_bps: 50.0,
+            min_trades: HISTORY_SIZE / 2,
+            tighten_above: 0.3,
+            relax_below: 0.1,
+            step: 0.02,
+            min_threshold: 0.80,
+            max_threshold: 0.999,
+            min_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ChangeKind {
+    Tighten,
+    Relax,
+    Rollback { of: u64 },
+}
+
+/// What the audit saw when it made a change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftEvidence {
+    pub drift_score: f64,
+    pub trades: usize,
+    pub mean_slippage_bps: f64,
+    pub worst_slippage_bps: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdChange {
+    pub id: u64,
+    pub at_ms: i64,
+    pub kind: ChangeKind,
+    pub old_value: f64,
+    pub new_value: f64,
+    pub evidence: DriftEvidence,
+}
+
+/// API view of the audit (pushed into SharedState by the owner loop).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditSnapshot {
+    pub tunneling_threshold: f64,
+    pub drift_score: f64,
+    pub changes: Vec<ThresholdChange>, // Oldest first
+}
+
 pub struct AuditLoop {
     history: VecDeque<TradeResult>,
     pub drift_score: f64, // 0.0 (Perfect) -> 1.0 (Broken)
+    pub config: RecalibrationConfig,
+    changes: VecDeque<ThresholdChange>,
+    next_id: u64,
+    last_change: Option<Instant>,
+    dirty: bool, // Change log not yet persisted
+    clock: SharedClock,
 }
 
 impl AuditLoop {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             history: VecDeque::with_capacity(HISTORY_SIZE),
             drift_score: 0.0,
+            config: RecalibrationConfig::default(),
+            changes: VecDeque::with_capacity(CHANGE_LOG_LEN),
+            next_id: 1,
+            last_change: None,
+            dirty: false,
+            clock,
+        }
+    }
+
+    /// Restore the change log from Redis and re-apply the last threshold it set
+    /// (no-op if absent or unreachable).
+    pub async fn load(&mut self, store: &RedisStateStore, legislator: &mut WaveLegislator) {
+        match store.get_state::<Vec<ThresholdChange>>(AUDIT_STATE_KEY).await {
+            Ok(changes) => {
+                self.restore(changes, legislator);
+                info!("📐 AUDIT: Restored {} threshold changes (tunneling threshold {:.3}).", self.changes.len(), legislator.tunneling_threshold());
+            }
+            Err(e) => warn!("⚠️ AUDIT: No persisted threshold changes ({}). Starting fresh.", e),
         }
     }
 
+    /// Persist the change log if it grew since the last save.
+    pub async fn persist(&mut self, store: &RedisStateStore) {
+        if !self.dirty {
+            return;
+        }
+        let changes: Vec<&ThresholdChange> = self.changes.iter().collect();
+        match store.set_state(AUDIT_STATE_KEY, &changes).await {
+            Ok(()) => self.dirty = false,
+            Err(e) => error!("❌ AUDIT: Failed to persist threshold changes: {}", e),
+        }
+    }
+
+    fn restore(&mut self, changes: Vec<ThresholdChange>, legislator: &mut WaveLegislator) {
+        if let Some(last) = changes.last() {
+            legislator.set_tunneling_threshold(last.new_value);
+            self.next_id = last.id + 1;
+        }
+        let skip = changes.len().saturating_sub(CHANGE_LOG_LEN);
+        self.changes = changes.into_iter().skip(skip).collect();
+    }
+
     /// Ingest a new trade result and update model drift metrics.
     pub fn register_trade(&mut self, result: TradeResult) {
         if self.history.len() >= HISTORY_SIZE {
             self.history.pop_front();
         }
         self.history.push_back(result);
-        
+
         self.recalculate_drift();
     }
 
+    /// Mean slippage impact: adverse slippage beyond tolerance, scaled by
+    /// full_scale_bps and capped at 1.0 per trade.
     fn recalculate_drift(&mut self) {
         if self.history.is_empty() {
             self.drift_score = 0.0;
             return;
         }
 
-        let mut total_drift_impact = 0.0;
-
-        for trade in &self.history {
-            let slippage = match trade.side {
-                TradeSide::Buy => trade.filled_price - trade.predicted_price,
-                TradeSide::Sell => trade.predicted_price - trade.filled_price,
-            };
-            
-            // Convert to basis points approx (assuming price around 100 for simplicity or just raw diff if generic)
-            // For this logic, let's treat the inputs as raw price diffs. 
-            // If slippage is positive (bad fill), it adds to drift.
-            
-            if slippage > 0.0 {
-                // Penalize strict slippage
-                total_drift_impact += DRIFT_SENSITIVITY;
-            }
-        }
+        let tolerance = self.config.tolerance_bps;
+        let full_scale = self.config.full_scale_bps.max(f64::EPSILON);
+        let total_drift_impact: f64 = self
+            .history
+            .iter()
+            .map(|t| ((t.slippage_bps() - tolerance).max(0.0) / full_scale).min(1.0))
+            .sum();
 
         // Normalize drift score 0.0 to 1.0
-        self.drift_score = total_drift_impact.min(1.0);
+        self.drift_score = total_drift_impact / self.history.len() as f64;
     }
 
-    /// Automatically adjusts valid wave thresholds if drift is too high.
-    pub fn recalibrate_legislator(&self, legislator: &mut WaveLegislator) -> bool {
-        if self.drift_score > 0.3 {
+    fn evidence(&self) -> DriftEvidence {
+        let slippages: Vec<f64> = self.history.iter().map(TradeResult::slippage_bps).collect();
+        DriftEvidence {
+            drift_score: self.drift_score,
+            trades: slippages.len(),
+            mean_slippage_bps: if slippages.is_empty() { 0.0 } else { slippages.iter().sum::<f64>() / slippages.len() as f64 },
+            worst_slippage_bps: slippages.iter().cloned().fold(0.0, f64::max),
+        }
+    }
+
+    /// Adjusts the tunneling threshold from drift: tighten when fills drift,
+    /// relax once they recover. Bounded, rate limited and logged.
+    pub fn recalibrate_legislator(&mut self, legislator: &mut WaveLegislator) -> bool {
+        if self.history.len() < self.config.min_trades {
+            return false;
+        }
+        if self.last_change.is_some_and(|at| self.clock.elapsed_since(at) < self.config.min_interval) {
+            return false;
+        }
+
+        let current = legislator.tunneling_threshold();
+        let (kind, target) = if self.drift_score > self.config.tighten_above {
             // Significant drift detected. Tighten the requirements.
-            let current = legislator.tunneling_threshold();
-            // Increase threshold by 5% roughly, maxing at 0.99
-            let new_threshold = (current + 0.02).min(0.999);
-            
-            if (new_threshold - current).abs() > 0.001 {
-                legislator.set_tunneling_threshold(new_threshold);
-                return true; // Action taken
-            }
+            (ChangeKind::Tighten, (current + self.config.step).min(self.config.max_threshold))
+        } else if self.drift_score < self.config.relax_below {
+            // Fills are clean again. Give back some room.
+            (ChangeKind::Relax, (current - self.config.step).max(self.config.min_threshold))
+        } else {
+            return false;
+        };
+
+        if (target - current).abs() <= 0.001 {
+            return false;
+        }
+        legislator.set_tunneling_threshold(target);
+        self.log_change(kind, current, legislator.tunneling_threshold());
+        true
+    }
+
+    /// Restores the value a logged change replaced. The rollback is itself logged,
+    /// and restarts the rate limit so the audit does not immediately undo it.
+    pub fn rollback(&mut self, id: u64, legislator: &mut WaveLegislator) -> Result<ThresholdChange, String> {
+        let change = self
+            .changes
+            .iter()
+            .find(|c| c.id == id)
+            .cloned()
+            .ok_or_else(|| format!("No threshold change #{} in the log", id))?;
+        let current = legislator.tunneling_threshold();
+        legislator.set_tunneling_threshold(change.old_value);
+        Ok(self.log_change(ChangeKind::Rollback { of: id }, current, change.old_value))
+    }
+
+    pub fn changes(&self) -> impl Iterator<Item = &ThresholdChange> {
+        self.changes.iter()
+    }
+
+    pub fn snapshot(&self, legislator: &WaveLegislator) -> AuditSnapshot {
+        AuditSnapshot {
+            tunneling_threshold: legislator.tunneling_threshold(),
+            drift_score: self.drift_score,
+            changes: self.changes.iter().cloned().collect(),
+        }
+    }
+
+    fn log_change(&mut self, kind: ChangeKind, old_value: f64, new_value: f64) -> ThresholdChange {
+        let change = ThresholdChange {
+            id: self.next_id,
+            at_ms: self.clock.unix_millis(),
+            kind,
+            old_value,
+            new_value,
+            evidence: self.evidence(),
+        };
+        tracing::warn!(
+            "📐 AUDIT RECALIBRATION #{} {:?}: tunneling threshold {:.3} -> {:.3} (drift {:.2})",
+            change.id, change.kind, old_value, new_value, change.evidence.drift_score
+        );
+        self.next_id += 1;
+        self.last_change = Some(self.clock.now());
+        self.dirty = true;
+        if self.changes.len() >= CHANGE_LOG_LEN {
+            self.changes.pop_front();
         }
-        // If drift is low, we could relax, but safety first: only tighten for now.
-        false
+        self.changes.push_back(change.clone());
+        change
+    }
+}
+
+impl Default for AuditLoop {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::ManualClock;
+    use std::sync::Arc;
+
+    fn buy(filled_price: f64) -> TradeResult {
+        TradeResult { predicted_price: 100.0, filled_price, side: TradeSide::Buy }
+    }
 
     #[test]
     fn test_drift_calculation() {
         let mut audit = AuditLoop::new();
-        
+
         // Good trades (No slippage or positive slippage)
         audit.register_trade(TradeResult {
             predicted_price: 100.0,
@@ -112,6 +320,26 @@ mod tests {
         assert!(audit.drift_score > 0.0);
     }
 
+    #[test]
+    fn test_drift_weighs_slippage_magnitude() {
+        let drift_of = |filled: f64| {
+            let mut audit = AuditLoop::new();
+            audit.register_trade(buy(filled));
+            audit.drift_score
+        };
+        assert_eq!(drift_of(100.01), 0.0, "1 bp is within tolerance");
+        let small = drift_of(100.12); // 12 bps
+        let large = drift_of(100.32); // 32 bps
+        assert!(small > 0.0 && large > small);
+        assert!((small - 0.2).abs() < 1e-9); // (12 - 2) / 50
+        assert_eq!(drift_of(105.0), 1.0, "Capped at a full miss");
+
+        // A sell filled below prediction is adverse too
+        let mut audit = AuditLoop::new();
+        audit.register_trade(TradeResult { predicted_price: 100.0, filled_price: 99.0, side: TradeSide::Sell });
+        assert_eq!(audit.drift_score, 1.0);
+    }
+
     #[test]
     fn test_recalibration() {
         let mut audit = AuditLoop::new();
@@ -127,11 +355,73 @@ mod tests {
         }
 
         assert!(audit.drift_score > 0.3);
-        
+
         let initial_threshold = legislator.tunneling_threshold();
         let recalibrated = audit.recalibrate_legislator(&mut legislator);
-        
+
         assert!(recalibrated);
         assert!(legislator.tunneling_threshold() > initial_threshold);
+
+        let change = audit.changes().last().unwrap();
+        assert_eq!(change.kind, ChangeKind::Tighten);
+        assert_eq!((change.old_value, change.new_value), (0.90, legislator.tunneling_threshold()));
+        assert_eq!(change.evidence.trades, 8);
+        assert_eq!(change.evidence.worst_slippage_bps, 500.0);
+    }
+
+    #[test]
+    fn test_rate_limit_bounds_and_relaxation() {
+        let clock = Arc::new(ManualClock::new());
+        let mut audit = AuditLoop::with_clock(clock.clone());
+        let mut legislator = WaveLegislator::new(0.98);
+        for _ in 0..HISTORY_SIZE {
+            audit.register_trade(buy(105.0));
+        }
+
+        assert!(audit.recalibrate_legislator(&mut legislator));
+        assert!(!audit.recalibrate_legislator(&mut legislator), "Rate limited");
+        clock.advance(Duration::from_secs(61));
+        assert!(!audit.recalibrate_legislator(&mut legislator), "Already at the ceiling");
+        assert_eq!(legislator.tunneling_threshold(), 0.999);
+
+        // Drift recovers -> relax one step at a time, never below the floor
+        for _ in 0..HISTORY_SIZE {
+            audit.register_trade(buy(100.0));
+        }
+        let mut steps = 0;
+        while audit.recalibrate_legislator(&mut legislator) {
+            steps += 1;
+            clock.advance(Duration::from_secs(61));
+        }
+        assert_eq!(legislator.tunneling_threshold(), 0.80);
+        assert_eq!(steps, 10);
+        assert!(audit.changes().skip(1).all(|c| c.kind == ChangeKind::Relax && c.new_value < c.old_value));
+    }
+
+    #[test]
+    fn test_rollback_restores_and_logs() {
+        let mut audit = AuditLoop::new();
+        let mut legislator = WaveLegislator::new(0.90);
+        for _ in 0..HISTORY_SIZE {
+            audit.register_trade(buy(105.0));
+        }
+        audit.recalibrate_legislator(&mut legislator);
+        let tighten = audit.changes().last().unwrap().clone();
+
+        let rollback = audit.rollback(tighten.id, &mut legislator).unwrap();
+        assert_eq!(legislator.tunneling_threshold(), 0.90);
+        assert_eq!(rollback.kind, ChangeKind::Rollback { of: tighten.id });
+        assert_eq!((rollback.old_value, rollback.new_value), (tighten.new_value, 0.90));
+        assert!(!audit.recalibrate_legislator(&mut legislator), "Rollback restarts the rate limit");
+        assert!(audit.rollback(99, &mut legislator).is_err());
+
+        // A restart picks the log and the threshold it left back up
+        let mut restarted = AuditLoop::new();
+        let mut fresh = WaveLegislator::new(0.95);
+        restarted.restore(audit.changes().cloned().collect(), &mut fresh);
+        assert_eq!(fresh.tunneling_threshold(), 0.90);
+        assert_eq!(restarted.snapshot(&fresh).changes.len(), 2);
+        assert!(restarted.rollback(tighten.id, &mut fresh).is_ok());
+        assert_eq!(restarted.changes().last().unwrap().id, rollback.id + 1);
     }
 }
@@ -3,10 +3,14 @@
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
+use std::collections::HashSet;
 use std::time::Instant;
 
+use crate::governor::legislator::LegislativeChange;
+use crate::governor::risk_ladder::RiskTier;
+
 /// Sovereign commands that bypass the autonomous OODA loop
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SovereignCommand {
     /// Emergency stop - halt all trading immediately
     Kill,
@@ -28,28 +32,113 @@ pub enum SovereignCommand {
     
     /// Clear sentiment override, return to autonomous
     ClearSentimentOverride,
+
+    /// Stop trading one symbol (others carry on)
+    PauseSymbol(String),
+
+    /// Resume trading a paused symbol
+    ResumeSymbol(String),
+
+    /// Hold the risk tier at or below `tier` for `duration_secs` (never raises it)
+    CapTier { tier: RiskTier, duration_secs: u64 },
+
+    /// Only orders that shrink the position (true) / back to normal (false)
+    ReduceOnly(bool),
+
+    /// Cancel working orders, keep positions
+    CancelAll,
+
+    /// Release a latched Halt (the OODA loop stays halted until this arrives)
+    ResumeFromHalt,
+
+    /// Drop the risk ladder to `tier` (never raises it)
+    DemoteLadder(RiskTier),
+
+    /// D-107: Replace the Pilot legislation
+    UpdateLegislation(LegislativeChange),
+
+    /// D-66: Undo a logged tunneling-threshold change (by id)
+    RollbackThreshold(u64),
+}
+
+impl SovereignCommand {
+    /// Wire name, as in the proto `CommandType` enum.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SovereignCommand::Kill => "KILL",
+            SovereignCommand::Veto => "VETO",
+            SovereignCommand::Pause => "PAUSE",
+            SovereignCommand::Resume => "RESUME",
+            SovereignCommand::CloseAll => "CLOSE_ALL",
+            SovereignCommand::SetSentimentOverride(_) => "SET_SENTIMENT",
+            SovereignCommand::ClearSentimentOverride => "CLEAR_SENTIMENT",
+            SovereignCommand::PauseSymbol(_) => "PAUSE_SYMBOL",
+            SovereignCommand::ResumeSymbol(_) => "RESUME_SYMBOL",
+            SovereignCommand::CapTier { .. } => "CAP_TIER",
+            SovereignCommand::ReduceOnly(_) => "REDUCE_ONLY",
+            SovereignCommand::CancelAll => "CANCEL_ALL",
+            SovereignCommand::ResumeFromHalt => "RESUME_FROM_HALT",
+            SovereignCommand::DemoteLadder(_) => "DEMOTE_LADDER",
+            SovereignCommand::UpdateLegislation(_) => "UPDATE_LEGISLATION",
+            SovereignCommand::RollbackThreshold(_) => "ROLLBACK_THRESHOLD",
+        }
+    }
+}
+
+/// A command that has passed the `CommandGate`: signature verified and,
+/// for destructive commands, approved by a second operator. Only the gate
+/// constructs these, so nothing unsigned reaches the bridge.
+#[derive(Debug, Clone)]
+pub struct AuthorizedCommand {
+    command: SovereignCommand,
+    operators: Vec<String>, // Proposer first, then the approver (two-person rule)
+    reason: String,
+}
+
+impl AuthorizedCommand {
+    pub(super) fn new(command: SovereignCommand, operators: Vec<String>, reason: String) -> Self {
+        Self { command, operators, reason }
+    }
+
+    pub fn command(&self) -> &SovereignCommand {
+        &self.command
+    }
+
+    pub fn operators(&self) -> &[String] {
+        &self.operators
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
 }
 
 /// Authority Bridge - manages sovereign command channel
 pub struct AuthorityBridge {
-    command_rx: mpsc::UnboundedReceiver<SovereignCommand>,
+    command_rx: mpsc::UnboundedReceiver<AuthorizedCommand>,
     tactical_pause: bool,
+    paused_symbols: HashSet<String>, // Upper-cased
+    reduce_only: bool,
     sentiment_override: Option<f64>,
     last_command_latency_us: u64,
     total_commands_processed: u64,
+    last_authorized: Option<AuthorizedCommand>, // Who signed off the last command, and why
 }
 
 impl AuthorityBridge {
     /// Create new authority bridge and return sender for external use
-    pub fn new() -> (Self, mpsc::UnboundedSender<SovereignCommand>) {
+    pub fn new() -> (Self, mpsc::UnboundedSender<AuthorizedCommand>) {
         let (tx, rx) = mpsc::unbounded_channel();
         
         let bridge = Self {
             command_rx: rx,
             tactical_pause: false,
+            paused_symbols: HashSet::new(),
+            reduce_only: false,
             sentiment_override: None,
             last_command_latency_us: 0,
             total_commands_processed: 0,
+            last_authorized: None,
         };
         
         tracing::info!("🎛️ Authority Bridge initialized");
@@ -65,10 +154,18 @@ impl AuthorityBridge {
         
         // Non-blocking check (required for <10μs latency)
         match self.command_rx.try_recv() {
-            Ok(cmd) => {
+            Ok(authorized) => {
                 let latency_us = start.elapsed().as_micros() as u64;
                 self.last_command_latency_us = latency_us;
                 self.total_commands_processed += 1;
+
+                tracing::info!(
+                    "🎛️ {} authorized by {} ({})",
+                    authorized.command.name(),
+                    authorized.operators.join(" + "),
+                    authorized.reason
+                );
+                let cmd = authorized.command.clone();
                 
                 // Update internal state based on command
                 match &cmd {
@@ -100,7 +197,38 @@ impl AuthorityBridge {
                     SovereignCommand::CloseAll => {
                         tracing::warn!("📛 CLOSE ALL POSITIONS");
                     }
+                    SovereignCommand::PauseSymbol(symbol) => {
+                        self.paused_symbols.insert(symbol.to_ascii_uppercase());
+                        tracing::warn!("⏸️ SYMBOL PAUSED: {}", symbol);
+                    }
+                    SovereignCommand::ResumeSymbol(symbol) => {
+                        self.paused_symbols.remove(&symbol.to_ascii_uppercase());
+                        tracing::info!("▶️ SYMBOL RESUMED: {}", symbol);
+                    }
+                    SovereignCommand::CapTier { tier, duration_secs } => {
+                        tracing::warn!("🪜 TIER CAP: {} for {}s", tier, duration_secs);
+                    }
+                    SovereignCommand::ReduceOnly(enabled) => {
+                        self.reduce_only = *enabled;
+                        tracing::warn!("📉 REDUCE-ONLY {}", if *enabled { "ENABLED" } else { "DISABLED" });
+                    }
+                    SovereignCommand::CancelAll => {
+                        tracing::warn!("🧹 CANCEL ALL WORKING ORDERS");
+                    }
+                    SovereignCommand::ResumeFromHalt => {
+                        tracing::warn!("▶️ RESUME FROM HALT");
+                    }
+                    SovereignCommand::DemoteLadder(tier) => {
+                        tracing::warn!("🪜 LADDER DEMOTION: -> {}", tier);
+                    }
+                    SovereignCommand::UpdateLegislation(change) => {
+                        tracing::info!("⚖️ LEGISLATION: {:?}", change);
+                    }
+                    SovereignCommand::RollbackThreshold(id) => {
+                        tracing::warn!("📐 THRESHOLD ROLLBACK: change #{}", id);
+                    }
                 }
+                self.last_authorized = Some(authorized);
                 
                 // Log latency warning if threshold exceeded
                 if latency_us > 10 {
@@ -116,11 +244,26 @@ impl AuthorityBridge {
         }
     }
     
+    /// The command most recently returned by `check_intervention`, with its operators and reason.
+    pub fn last_authorized(&self) -> Option<&AuthorizedCommand> {
+        self.last_authorized.as_ref()
+    }
+
     /// Check if system is in tactical pause
     pub fn is_paused(&self) -> bool {
         self.tactical_pause
     }
     
+    /// Check if one symbol is paused (case-insensitive)
+    pub fn is_symbol_paused(&self, symbol: &str) -> bool {
+        self.paused_symbols.contains(&symbol.to_ascii_uppercase())
+    }
+
+    /// Check if only position-reducing orders are allowed
+    pub fn is_reduce_only(&self) -> bool {
+        self.reduce_only
+    }
+
     /// Get current sentiment override value if set
     pub fn sentiment_override(&self) -> Option<f64> {
         self.sentiment_override
@@ -148,6 +291,10 @@ impl Default for AuthorityBridge {
 mod tests {
     use super::*;
 
+    fn authorized(command: SovereignCommand) -> AuthorizedCommand {
+        AuthorizedCommand::new(command, vec!["alice".into()], "test".into())
+    }
+
     #[test]
     fn test_authority_bridge_creation() {
         let (bridge, _tx) = AuthorityBridge::new();
@@ -161,7 +308,7 @@ mod tests {
         let (mut bridge, tx) = AuthorityBridge::new();
         
         // Send pause command
-        tx.send(SovereignCommand::Pause).unwrap();
+        tx.send(authorized(SovereignCommand::Pause)).unwrap();
         
         // Check intervention
         let cmd = bridge.check_intervention();
@@ -169,7 +316,7 @@ mod tests {
         assert!(bridge.is_paused());
         
         // Send resume command
-        tx.send(SovereignCommand::Resume).unwrap();
+        tx.send(authorized(SovereignCommand::Resume)).unwrap();
         let cmd = bridge.check_intervention();
         assert!(matches!(cmd, Some(SovereignCommand::Resume)));
         assert!(!bridge.is_paused());
@@ -180,13 +327,13 @@ mod tests {
         let (mut bridge, tx) = AuthorityBridge::new();
         
         // Set override
-        tx.send(SovereignCommand::SetSentimentOverride(0.3)).unwrap();
+        tx.send(authorized(SovereignCommand::SetSentimentOverride(0.3))).unwrap();
         bridge.check_intervention();
         
         assert_eq!(bridge.sentiment_override(), Some(0.3));
         
         // Clear override
-        tx.send(SovereignCommand::ClearSentimentOverride).unwrap();
+        tx.send(authorized(SovereignCommand::ClearSentimentOverride)).unwrap();
         bridge.check_intervention();
         
         assert_eq!(bridge.sentiment_override(), None);
@@ -196,7 +343,7 @@ mod tests {
     fn test_command_latency_tracking() {
         let (mut bridge, tx) = AuthorityBridge::new();
         
-        tx.send(SovereignCommand::Veto).unwrap();
+        tx.send(authorized(SovereignCommand::Veto)).unwrap();
         bridge.check_intervention();
         
         // Latency should be tracked (likely < 10μs in test)
@@ -209,8 +356,8 @@ mod tests {
         let (mut bridge, tx) = AuthorityBridge::new();
         
         // Send multiple commands
-        tx.send(SovereignCommand::Pause).unwrap();
-        tx.send(SovereignCommand::SetSentimentOverride(0.5)).unwrap();
+        tx.send(authorized(SovereignCommand::Pause)).unwrap();
+        tx.send(authorized(SovereignCommand::SetSentimentOverride(0.5))).unwrap();
         
         // First command
         bridge.check_intervention();
@@ -222,4 +369,25 @@ mod tests {
         
         assert_eq!(bridge.total_commands(), 2);
     }
+
+    #[test]
+    fn test_symbol_pause_and_reduce_only() {
+        let (mut bridge, tx) = AuthorityBridge::new();
+
+        tx.send(authorized(SovereignCommand::PauseSymbol("xbt/usd".into()))).unwrap();
+        tx.send(authorized(SovereignCommand::ReduceOnly(true))).unwrap();
+        bridge.check_intervention();
+        bridge.check_intervention();
+        assert!(bridge.is_symbol_paused("XBT/USD"));
+        assert!(!bridge.is_symbol_paused("ETH/USD"));
+        assert!(!bridge.is_paused(), "Global pause untouched");
+        assert!(bridge.is_reduce_only());
+
+        tx.send(authorized(SovereignCommand::ResumeSymbol("XBT/USD".into()))).unwrap();
+        tx.send(authorized(SovereignCommand::ReduceOnly(false))).unwrap();
+        bridge.check_intervention();
+        bridge.check_intervention();
+        assert!(!bridge.is_symbol_paused("xbt/usd"));
+        assert!(!bridge.is_reduce_only());
+    }
 }
@@ -0,0 +1,576 @@
+// Directive-86: Sovereign Interface Law (The Gate)
+// Every sovereign command is signed by a known operator, recorded, and, if
+// destructive, held until a second operator approves it.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use hmac::{Hmac, Mac};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::clock::{self, SharedClock};
+use crate::gateway::vault::ZeroizingSecret;
+use crate::governor::authority::{AuthorizedCommand, SovereignCommand};
+use crate::governor::legislator::LegislativeChange;
+
+const LOG_LEN: usize = 500; // Command records kept for the API
+const COMMAND_NAMES: [&str; 16] = [
+    "KILL", "VETO", "PAUSE", "RESUME", "CLOSE_ALL", "SET_SENTIMENT", "CLEAR_SENTIMENT",
+    "PAUSE_SYMBOL", "RESUME_SYMBOL", "CAP_TIER", "REDUCE_ONLY", "CANCEL_ALL", "RESUME_FROM_HALT",
+    "DEMOTE_LADDER", "UPDATE_LEGISLATION", "ROLLBACK_THRESHOLD",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyScheme {
+    HmacSha256, // Shared secret
+    Ed25519, // Public key; the operator holds the private half
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperatorKeyConfig {
+    pub id: String,
+    pub scheme: KeyScheme,
+    pub key: String, // Hex: HMAC secret or 32-byte Ed25519 public key
+}
+
+/// Operator keyring and approval policy.
+/// Loaded from JSON (SOVEREIGN_KEYS_PATH); missing fields keep their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GateConfig {
+    pub operators: Vec<OperatorKeyConfig>,
+    pub two_person: Vec<String>, // Command names (proto CommandType) that need a second operator
+    pub approval_window_secs: u64, // How long a destructive command waits for its approver
+    pub max_skew_secs: u64, // Accepted distance between the signed timestamp and our clock
+}
+
+impl Default for GateConfig {
+    fn default() -> Self {
+        Self {
+            operators: Vec::new(),
+            two_person: vec![
+                "KILL".to_string(),
+                "CLOSE_ALL".to_string(),
+                "RESUME_FROM_HALT".to_string(),
+                "UPDATE_LEGISLATION".to_string(),
+            ],
+            approval_window_secs: 120,
+            max_skew_secs: 30,
+        }
+    }
+}
+
+impl GateConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        let mut ids = std::collections::HashSet::new();
+        for op in &config.operators {
+            if op.id.is_empty() || op.id.contains('|') {
+                return Err(format!("Invalid operator id '{}'", op.id).into());
+            }
+            if !ids.insert(op.id.as_str()) {
+                return Err(format!("Duplicate operator id '{}'", op.id).into());
+            }
+            OperatorKey::decode(op).map_err(|e| format!("Operator '{}': {}", op.id, e))?;
+        }
+        if let Some(name) = config.two_person.iter().find(|n| !COMMAND_NAMES.contains(&n.as_str())) {
+            return Err(format!("Unknown command '{}' in two_person", name).into());
+        }
+        if config.approval_window_secs == 0 || config.max_skew_secs == 0 {
+            return Err("approval_window_secs and max_skew_secs must be positive".into());
+        }
+        Ok(config)
+    }
+}
+
+enum OperatorKey {
+    HmacSha256(ZeroizingSecret),
+    Ed25519(Vec<u8>),
+}
+
+impl OperatorKey {
+    fn decode(config: &OperatorKeyConfig) -> Result<Self, String> {
+        let bytes = hex::decode(config.key.trim()).map_err(|e| format!("key is not hex ({})", e))?;
+        match config.scheme {
+            KeyScheme::HmacSha256 if bytes.len() < 16 => Err("HMAC secret must be at least 16 bytes".into()),
+            KeyScheme::HmacSha256 => Ok(Self::HmacSha256(ZeroizingSecret { content: bytes })),
+            KeyScheme::Ed25519 if bytes.len() != 32 => Err("Ed25519 public key must be 32 bytes".into()),
+            KeyScheme::Ed25519 => Ok(Self::Ed25519(bytes)),
+        }
+    }
+
+    fn verify(&self, payload: &[u8], signature: &[u8]) -> bool {
+        match self {
+            Self::HmacSha256(secret) => Hmac::<Sha256>::new_from_slice(&secret.content)
+                .map(|mut mac| {
+                    mac.update(payload);
+                    mac.verify_slice(signature).is_ok()
+                })
+                .unwrap_or(false),
+            Self::Ed25519(public_key) => UnparsedPublicKey::new(&ED25519, public_key).verify(payload, signature).is_ok(),
+        }
+    }
+}
+
+/// A command as submitted over the API, before verification.
+#[derive(Debug, Clone)]
+pub struct SignedCommand {
+    pub command: SovereignCommand,
+    pub operator: String,
+    pub reason: String,
+    pub timestamp_ms: i64, // Unix ms at signing
+    pub nonce: String, // Unique per operator within the skew window
+    pub approval_id: u64, // 0 = new command; otherwise the pending command being approved
+    pub signature: Vec<u8>,
+}
+
+impl SignedCommand {
+    /// The bytes the operator signs:
+    /// `COMMAND|value|operator|reason|timestamp_ms|nonce|approval_id`, where value is
+    /// the sentiment override to 4 dp, the symbol, `TIER:secs` for a tier cap,
+    /// `true`/`false` for reduce-only, the target tier for a demotion,
+    /// `BIAS:aggression:maker_only:hibernation:hurdle_rate:snap_to_breakeven`
+    /// (numbers to 4 dp) for legislation, and empty otherwise.
+    pub fn payload(&self) -> String {
+        let value = match &self.command {
+            SovereignCommand::SetSentimentOverride(v) => format!("{:.4}", v),
+            SovereignCommand::PauseSymbol(symbol) | SovereignCommand::ResumeSymbol(symbol) => symbol.clone(),
+            SovereignCommand::CapTier { tier, duration_secs } => format!("{}:{}", tier, duration_secs),
+            SovereignCommand::ReduceOnly(enabled) => enabled.to_string(),
+            SovereignCommand::DemoteLadder(tier) => tier.to_string(),
+            SovereignCommand::RollbackThreshold(id) => id.to_string(),
+            SovereignCommand::UpdateLegislation(l) => format!(
+                "{}:{:.4}:{}:{}:{:.4}:{}",
+                l.bias, l.aggression, l.maker_only, l.hibernation, l.hurdle_rate, l.snap_to_breakeven
+            ),
+            _ => String::new(),
+        };
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.command.name(),
+            value,
+            self.operator,
+            self.reason,
+            self.timestamp_ms,
+            self.nonce,
+            self.approval_id
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GateError {
+    UnknownOperator(String),
+    MissingReason,
+    MalformedField(&'static str),
+    BadSignature,
+    Stale { skew_ms: i64 },
+    Replay,
+    NoSuchApproval(u64),
+    ApprovalMismatch { pending: &'static str },
+    SelfApproval,
+}
+
+impl fmt::Display for GateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GateError::UnknownOperator(op) => write!(f, "Unknown operator '{}'", op),
+            GateError::MissingReason => write!(f, "A reason is required"),
+            GateError::MalformedField(field) => write!(f, "Malformed {}", field),
+            GateError::BadSignature => write!(f, "Signature verification failed"),
+            GateError::Stale { skew_ms } => write!(f, "Timestamp outside the accepted window ({} ms)", skew_ms),
+            GateError::Replay => write!(f, "Nonce already used"),
+            GateError::NoSuchApproval(id) => write!(f, "No pending command {} (unknown or expired)", id),
+            GateError::ApprovalMismatch { pending } => write!(f, "Approval does not match pending {}", pending),
+            GateError::SelfApproval => write!(f, "A second, different operator must approve"),
+        }
+    }
+}
+
+impl std::error::Error for GateError {}
+
+#[derive(Debug, Clone)]
+pub enum Authorization {
+    Execute(AuthorizedCommand),
+    AwaitingApproval { approval_id: u64, expires_at_ms: i64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum CommandOutcome {
+    Executed,
+    AwaitingApproval { approval_id: u64 },
+    Rejected(String),
+}
+
+/// Audit trail entry; every submission is recorded, rejected ones included.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandRecord {
+    pub at_ms: i64,
+    pub command: String,
+    pub operator: String, // As claimed; only verified when the outcome is not Rejected
+    pub reason: String,
+    pub approval_id: u64,
+    pub outcome: CommandOutcome,
+}
+
+#[derive(Debug, Clone)]
+struct PendingCommand {
+    command: SovereignCommand,
+    operator: String,
+    reason: String,
+    expires_at_ms: i64,
+}
+
+pub struct CommandGate {
+    config: GateConfig,
+    keys: HashMap<String, OperatorKey>,
+    seen_nonces: HashMap<(String, String), i64>, // (operator, nonce) -> signed timestamp
+    pending: HashMap<u64, PendingCommand>,
+    next_approval_id: u64,
+    log: VecDeque<CommandRecord>,
+    clock: SharedClock,
+}
+
+impl fmt::Debug for CommandGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Keys stay out of logs
+        f.debug_struct("CommandGate")
+            .field("operators", &self.keys.keys().collect::<Vec<_>>())
+            .field("pending", &self.pending.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CommandGate {
+    pub fn new(config: GateConfig) -> Self {
+        Self::with_clock(config, clock::system())
+    }
+
+    pub fn with_clock(config: GateConfig, clock: SharedClock) -> Self {
+        let keys: HashMap<String, OperatorKey> = config
+            .operators
+            .iter()
+            .filter_map(|op| match OperatorKey::decode(op) {
+                Ok(key) => Some((op.id.clone(), key)),
+                Err(e) => {
+                    tracing::error!("🔐 COMMAND GATE: Dropping key for '{}': {}", op.id, e);
+                    None
+                }
+            })
+            .collect();
+        if keys.is_empty() {
+            tracing::warn!("🔐 COMMAND GATE: No operator keys. All sovereign commands will be rejected.");
+        }
+        Self {
+            config,
+            keys,
+            seen_nonces: HashMap::new(),
+            pending: HashMap::new(),
+            next_approval_id: 1,
+            log: VecDeque::with_capacity(LOG_LEN),
+            clock,
+        }
+    }
+
+    /// Verify and record a signed command. Destructive commands (per
+    /// `two_person`) come back as `AwaitingApproval` until a different
+    /// operator signs the same command with the returned `approval_id`.
+    pub fn authorize(&mut self, signed: SignedCommand) -> Result<Authorization, GateError> {
+        let now = self.clock.unix_millis();
+        let result = self.evaluate(&signed, now);
+        let outcome = match &result {
+            Ok(Authorization::Execute(_)) => CommandOutcome::Executed,
+            Ok(Authorization::AwaitingApproval { approval_id, .. }) => CommandOutcome::AwaitingApproval { approval_id: *approval_id },
+            Err(e) => CommandOutcome::Rejected(e.to_string()),
+        };
+        match &outcome {
+            CommandOutcome::Rejected(e) => tracing::warn!("🔐 {} from '{}' REJECTED: {}", signed.command.name(), signed.operator, e),
+            CommandOutcome::AwaitingApproval { approval_id } => tracing::warn!(
+                "🔐 {} proposed by {} ({}). Awaiting second operator (approval {}).",
+                signed.command.name(), signed.operator, signed.reason, approval_id
+            ),
+            CommandOutcome::Executed => {}
+        }
+        if self.log.len() >= LOG_LEN {
+            self.log.pop_front();
+        }
+        self.log.push_back(CommandRecord {
+            at_ms: now,
+            command: signed.command.name().to_string(),
+            operator: signed.operator,
+            reason: signed.reason,
+            approval_id: signed.approval_id,
+            outcome,
+        });
+        result
+    }
+
+    fn evaluate(&mut self, signed: &SignedCommand, now: i64) -> Result<Authorization, GateError> {
+        // 1. Who, and is it well formed (fields must not smuggle separators)
+        let key = self.keys.get(&signed.operator).ok_or_else(|| GateError::UnknownOperator(signed.operator.clone()))?;
+        if signed.reason.trim().is_empty() {
+            return Err(GateError::MissingReason);
+        }
+        if signed.reason.contains('|') {
+            return Err(GateError::MalformedField("reason"));
+        }
+        if signed.nonce.is_empty() || signed.nonce.contains('|') {
+            return Err(GateError::MalformedField("nonce"));
+        }
+        match &signed.command {
+            SovereignCommand::PauseSymbol(symbol) | SovereignCommand::ResumeSymbol(symbol)
+                if symbol.trim().is_empty() || symbol.contains('|') =>
+            {
+                return Err(GateError::MalformedField("symbol"));
+            }
+            SovereignCommand::CapTier { duration_secs: 0, .. } => return Err(GateError::MalformedField("duration_secs")),
+            SovereignCommand::RollbackThreshold(0) => return Err(GateError::MalformedField("change_id")),
+            SovereignCommand::UpdateLegislation(l)
+                if !LegislativeChange::BIASES.contains(&l.bias.as_str())
+                    || !l.aggression.is_finite()
+                    || !l.hurdle_rate.is_finite() =>
+            {
+                return Err(GateError::MalformedField("legislation"));
+            }
+            _ => {}
+        }
+
+        // 2. Signature over the canonical payload
+        if !key.verify(signed.payload().as_bytes(), &signed.signature) {
+            return Err(GateError::BadSignature);
+        }
+
+        // 3. Freshness and replay
+        let max_skew_ms = self.config.max_skew_secs as i64 * 1000;
+        let skew_ms = now - signed.timestamp_ms;
+        if skew_ms.abs() > max_skew_ms {
+            return Err(GateError::Stale { skew_ms });
+        }
+        self.seen_nonces.retain(|_, ts| now - *ts <= max_skew_ms);
+        let nonce_key = (signed.operator.clone(), signed.nonce.clone());
+        if self.seen_nonces.contains_key(&nonce_key) {
+            return Err(GateError::Replay);
+        }
+        self.seen_nonces.insert(nonce_key, signed.timestamp_ms);
+
+        // 4. Two-person rule
+        self.pending.retain(|_, p| p.expires_at_ms > now);
+        if signed.approval_id != 0 {
+            let pending = self.pending.get(&signed.approval_id).ok_or(GateError::NoSuchApproval(signed.approval_id))?;
+            if pending.command != signed.command {
+                return Err(GateError::ApprovalMismatch { pending: pending.command.name() });
+            }
+            if pending.operator == signed.operator {
+                return Err(GateError::SelfApproval);
+            }
+            let pending = self.pending.remove(&signed.approval_id).ok_or(GateError::NoSuchApproval(signed.approval_id))?;
+            return Ok(Authorization::Execute(AuthorizedCommand::new(
+                pending.command,
+                vec![pending.operator, signed.operator.clone()],
+                pending.reason,
+            )));
+        }
+        if self.config.two_person.iter().any(|n| n == signed.command.name()) {
+            let approval_id = self.next_approval_id;
+            self.next_approval_id += 1;
+            let expires_at_ms = now + self.config.approval_window_secs as i64 * 1000;
+            self.pending.insert(approval_id, PendingCommand {
+                command: signed.command.clone(),
+                operator: signed.operator.clone(),
+                reason: signed.reason.clone(),
+                expires_at_ms,
+            });
+            return Ok(Authorization::AwaitingApproval { approval_id, expires_at_ms });
+        }
+
+        Ok(Authorization::Execute(AuthorizedCommand::new(
+            signed.command.clone(),
+            vec![signed.operator.clone()],
+            signed.reason.clone(),
+        )))
+    }
+
+    /// Command records, oldest first.
+    pub fn records(&self) -> Vec<CommandRecord> {
+        self.log.iter().cloned().collect()
+    }
+
+    pub fn pending_approvals(&self) -> usize {
+        let now = self.clock.unix_millis();
+        self.pending.values().filter(|p| p.expires_at_ms > now).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use ring::signature::Ed25519KeyPair;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const SECRET: [u8; 32] = [0x42; 32];
+
+    fn keypair(seed: u8) -> Ed25519KeyPair {
+        Ed25519KeyPair::from_seed_unchecked(&[seed; 32]).unwrap()
+    }
+
+    fn gate() -> (CommandGate, Arc<ManualClock>) {
+        use ring::signature::KeyPair;
+        let config = GateConfig {
+            operators: vec![
+                OperatorKeyConfig { id: "alice".into(), scheme: KeyScheme::HmacSha256, key: hex::encode(SECRET) },
+                OperatorKeyConfig { id: "bob".into(), scheme: KeyScheme::Ed25519, key: hex::encode(keypair(1).public_key().as_ref()) },
+            ],
+            ..Default::default()
+        };
+        let clock = Arc::new(ManualClock::new());
+        (CommandGate::with_clock(config, clock.clone()), clock)
+    }
+
+    fn signed(gate: &CommandGate, command: SovereignCommand, operator: &str, nonce: &str, approval_id: u64) -> SignedCommand {
+        let mut cmd = SignedCommand {
+            command,
+            operator: operator.into(),
+            reason: "desk call".into(),
+            timestamp_ms: gate.clock.unix_millis(),
+            nonce: nonce.into(),
+            approval_id,
+            signature: Vec::new(),
+        };
+        let payload = cmd.payload();
+        cmd.signature = if operator == "bob" {
+            keypair(1).sign(payload.as_bytes()).as_ref().to_vec()
+        } else {
+            let mut mac = Hmac::<Sha256>::new_from_slice(&SECRET).unwrap();
+            mac.update(payload.as_bytes());
+            mac.finalize().into_bytes().to_vec()
+        };
+        cmd
+    }
+
+    #[test]
+    fn test_signed_command_executes_and_forgeries_are_recorded() {
+        let (mut gate, _) = gate();
+
+        let ok = gate.authorize(signed(&gate, SovereignCommand::Pause, "alice", "n1", 0)).unwrap();
+        match ok {
+            Authorization::Execute(cmd) => {
+                assert_eq!(cmd.command(), &SovereignCommand::Pause);
+                assert_eq!(cmd.operators(), ["alice".to_string()]);
+                assert_eq!(cmd.reason(), "desk call");
+            }
+            other => panic!("expected execute, got {:?}", other),
+        }
+
+        // Signature over different terms
+        let mut tampered = signed(&gate, SovereignCommand::SetSentimentOverride(0.2), "bob", "n2", 0);
+        tampered.command = SovereignCommand::SetSentimentOverride(0.9);
+        assert_eq!(gate.authorize(tampered).unwrap_err(), GateError::BadSignature);
+
+        // Unknown operator / no reason
+        let mut mallory = signed(&gate, SovereignCommand::Veto, "alice", "n3", 0);
+        mallory.operator = "mallory".into();
+        assert!(matches!(gate.authorize(mallory), Err(GateError::UnknownOperator(_))));
+        let mut silent = signed(&gate, SovereignCommand::Veto, "alice", "n4", 0);
+        silent.reason.clear();
+        assert_eq!(gate.authorize(silent).unwrap_err(), GateError::MissingReason);
+
+        let records = gate.records();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].outcome, CommandOutcome::Executed);
+        assert_eq!((records[2].operator.as_str(), records[2].command.as_str()), ("mallory", "VETO"));
+        assert!(records[1..].iter().all(|r| matches!(r.outcome, CommandOutcome::Rejected(_))));
+    }
+
+    #[test]
+    fn test_replay_and_stale_commands_rejected() {
+        let (mut gate, clock) = gate();
+        let cmd = signed(&gate, SovereignCommand::Resume, "bob", "once", 0);
+        assert!(gate.authorize(cmd.clone()).is_ok());
+        assert_eq!(gate.authorize(cmd).unwrap_err(), GateError::Replay);
+
+        let old = signed(&gate, SovereignCommand::Resume, "bob", "late", 0);
+        clock.advance(Duration::from_secs(31));
+        assert!(matches!(gate.authorize(old), Err(GateError::Stale { .. })));
+    }
+
+    #[test]
+    fn test_kill_needs_a_second_operator() {
+        let (mut gate, clock) = gate();
+
+        let id = match gate.authorize(signed(&gate, SovereignCommand::Kill, "alice", "k1", 0)).unwrap() {
+            Authorization::AwaitingApproval { approval_id, .. } => approval_id,
+            other => panic!("expected approval wait, got {:?}", other),
+        };
+        assert_eq!(gate.pending_approvals(), 1);
+
+        // Same operator can't approve; a different command can't ride the approval
+        assert_eq!(gate.authorize(signed(&gate, SovereignCommand::Kill, "alice", "k2", id)).unwrap_err(), GateError::SelfApproval);
+        assert!(matches!(
+            gate.authorize(signed(&gate, SovereignCommand::CloseAll, "bob", "k3", id)),
+            Err(GateError::ApprovalMismatch { pending: "KILL" })
+        ));
+
+        match gate.authorize(signed(&gate, SovereignCommand::Kill, "bob", "k4", id)).unwrap() {
+            Authorization::Execute(cmd) => {
+                assert_eq!(cmd.command(), &SovereignCommand::Kill);
+                assert_eq!(cmd.operators(), ["alice".to_string(), "bob".to_string()]);
+            }
+            other => panic!("expected execute, got {:?}", other),
+        }
+        assert_eq!(gate.pending_approvals(), 0);
+
+        // Approval window lapses
+        let id = match gate.authorize(signed(&gate, SovereignCommand::CloseAll, "bob", "c1", 0)).unwrap() {
+            Authorization::AwaitingApproval { approval_id, .. } => approval_id,
+            other => panic!("expected approval wait, got {:?}", other),
+        };
+        clock.advance(Duration::from_secs(121));
+        assert_eq!(
+            gate.authorize(signed(&gate, SovereignCommand::CloseAll, "alice", "c2", id)).unwrap_err(),
+            GateError::NoSuchApproval(id)
+        );
+    }
+
+    #[test]
+    fn test_halt_resume_demotion_and_legislation_are_gated() {
+        let (mut gate, _clock) = gate();
+        let demote = SovereignCommand::DemoteLadder(crate::governor::risk_ladder::RiskTier::Q0);
+        assert!(matches!(gate.authorize(signed(&gate, demote, "alice", "d1", 0)).unwrap(), Authorization::Execute(_)));
+
+        let resume = gate.authorize(signed(&gate, SovereignCommand::ResumeFromHalt, "alice", "r1", 0)).unwrap();
+        assert!(matches!(resume, Authorization::AwaitingApproval { .. }));
+
+        let mut change = LegislativeChange {
+            bias: "LONG_ONLY".into(),
+            aggression: 1.5,
+            maker_only: false,
+            hibernation: false,
+            hurdle_rate: 0.0,
+            snap_to_breakeven: false,
+        };
+        let legislate = gate.authorize(signed(&gate, SovereignCommand::UpdateLegislation(change.clone()), "bob", "l1", 0)).unwrap();
+        assert!(matches!(legislate, Authorization::AwaitingApproval { .. }));
+
+        change.bias = "YOLO".into();
+        assert_eq!(
+            gate.authorize(signed(&gate, SovereignCommand::UpdateLegislation(change), "bob", "l2", 0)).unwrap_err(),
+            GateError::MalformedField("legislation")
+        );
+
+        let rollback = gate.authorize(signed(&gate, SovereignCommand::RollbackThreshold(3), "bob", "t1", 0)).unwrap();
+        assert!(matches!(rollback, Authorization::Execute(_)));
+        assert_eq!(
+            gate.authorize(signed(&gate, SovereignCommand::RollbackThreshold(0), "bob", "t2", 0)).unwrap_err(),
+            GateError::MalformedField("change_id")
+        );
+        assert_eq!(gate.records().len(), 6);
+    }
+}
@@ -0,0 +1,286 @@
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use tracing::{error, info, warn};
+
+use crate::db::state::RedisStateStore;
+
+pub const DRAWDOWN_STATE_KEY: &str = "risk:drawdown_marks";
+
+/// Escalating breaker actions. Ordered so the most severe tripped breaker wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BreakerAction {
+    None,
+    ReduceSize, // Scale new orders down
+    ReduceOnly, // Only orders that shrink the position
+    Halt,       // Full stop
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BreakerConfig {
+    pub threshold: f64, // Drawdown fraction (0.02 = 2%)
+    pub action: BreakerAction,
+}
+
+/// Breaker tiers. Loaded from JSON (DRAWDOWN_PATH); missing fields keep their defaults.
+/// The rollover timezone and hour come from RISK_TIMEZONE / RISK_ROLLOVER_HOUR.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrawdownConfig {
+    pub daily: BreakerConfig,
+    pub weekly: BreakerConfig,
+    pub peak: BreakerConfig,
+    pub reduce_size_factor: f64,
+    #[serde(skip)]
+    pub timezone: Tz,
+    #[serde(skip)]
+    pub rollover_hour: u32, // Local hour at which the trading day starts
+}
+
+impl Default for DrawdownConfig {
+    fn default() -> Self {
+        Self {
+            daily: BreakerConfig { threshold: 0.02, action: BreakerAction::ReduceSize },
+            weekly: BreakerConfig { threshold: 0.05, action: BreakerAction::ReduceOnly },
+            peak: BreakerConfig { threshold: 0.15, action: BreakerAction::Halt },
+            reduce_size_factor: 0.5,
+            timezone: Tz::UTC,
+            rollover_hour: 0,
+        }
+    }
+}
+
+impl DrawdownConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        for (name, breaker) in [("daily", &config.daily), ("weekly", &config.weekly), ("peak", &config.peak)] {
+            if !(breaker.threshold > 0.0 && breaker.threshold < 1.0) {
+                return Err(format!("{} threshold must be within (0, 1)", name).into());
+            }
+        }
+        if !(config.reduce_size_factor > 0.0 && config.reduce_size_factor <= 1.0) {
+            return Err("reduce_size_factor must be within (0, 1]".into());
+        }
+        Ok(config)
+    }
+
+    /// Timezone by IANA name (e.g. "America/New_York"); falls back to UTC if unknown.
+    pub fn with_timezone(mut self, name: &str, rollover_hour: u32) -> Self {
+        match name.parse::<Tz>() {
+            Ok(tz) => self.timezone = tz,
+            Err(_) => warn!("⚠️ DRAWDOWN: Unknown timezone '{}'. Using UTC.", name),
+        }
+        self.rollover_hour = rollover_hour.min(23);
+        self
+    }
+}
+
+/// Equity reference points. Persisted so a restart does not forget the peak.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawdownMarks {
+    pub high_water_mark: f64,
+    pub day_start_equity: f64,
+    pub week_start_equity: f64,
+    pub trading_day: i32,  // Days from CE of the local trading day
+    pub trading_week: i32, // Days from CE of that week's Monday
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakerStatus {
+    pub action: BreakerAction,
+    pub daily_dd: f64,
+    pub weekly_dd: f64,
+    pub peak_dd: f64,
+    pub tripped: Vec<String>,
+}
+
+impl BreakerStatus {
+    /// No reading yet (equity not synced from the venue): nothing tripped.
+    pub fn idle() -> Self {
+        Self { action: BreakerAction::None, daily_dd: 0.0, weekly_dd: 0.0, peak_dd: 0.0, tripped: Vec::new() }
+    }
+}
+
+/// Tiered Drawdown Circuit Breakers
+/// Daily and weekly drawdowns roll over at `rollover_hour` in the configured timezone;
+/// peak-to-trough runs from the persisted high-water mark.
+pub struct DrawdownBreaker {
+    pub config: DrawdownConfig,
+    marks: Option<DrawdownMarks>,
+    dirty: bool,
+}
+
+impl DrawdownBreaker {
+    pub fn new(config: DrawdownConfig) -> Self {
+        Self { config, marks: None, dirty: false }
+    }
+
+    pub fn marks(&self) -> Option<&DrawdownMarks> {
+        self.marks.as_ref()
+    }
+
+    /// Restore marks from Redis (no-op if absent or unreachable).
+    pub async fn load(&mut self, store: &RedisStateStore) {
+        match store.get_state::<DrawdownMarks>(DRAWDOWN_STATE_KEY).await {
+            Ok(marks) => {
+                info!("📈 DRAWDOWN: Restored HWM ${:.2} from state store.", marks.high_water_mark);
+                self.marks = Some(marks);
+            }
+            Err(e) => warn!("⚠️ DRAWDOWN: No persisted marks ({}). Starting fresh.", e),
+        }
+    }
+
+    /// Persist marks if a new high or rollover happened since the last save.
+    pub async fn persist(&mut self, store: &RedisStateStore) {
+        if !self.dirty {
+            return;
+        }
+        if let Some(marks) = &self.marks {
+            match store.set_state(DRAWDOWN_STATE_KEY, marks).await {
+                Ok(()) => self.dirty = false,
+                Err(e) => error!("❌ DRAWDOWN: Failed to persist marks: {}", e),
+            }
+        }
+    }
+
+    /// Local trading day and week (Monday) for a timestamp, shifted by the rollover hour.
+    fn trading_period(&self, now: DateTime<Utc>) -> (i32, i32) {
+        let local = now.with_timezone(&self.config.timezone)
+            - ChronoDuration::hours(self.config.rollover_hour as i64);
+        let day = local.date_naive();
+        let monday = day - ChronoDuration::days(day.weekday().num_days_from_monday() as i64);
+        (day.num_days_from_ce(), monday.num_days_from_ce())
+    }
+
+    pub fn update(&mut self, equity: f64, now: DateTime<Utc>) -> BreakerStatus {
+        let (day, week) = self.trading_period(now);
+        let mut dirty = self.marks.is_none();
+
+        let marks = self.marks.get_or_insert(DrawdownMarks {
+            high_water_mark: equity,
+            day_start_equity: equity,
+            week_start_equity: equity,
+            trading_day: day,
+            trading_week: week,
+        });
+
+        if marks.trading_day != day {
+            info!("🌅 DRAWDOWN: Start-of-day rollover. SOD equity ${:.2}", equity);
+            marks.trading_day = day;
+            marks.day_start_equity = equity;
+            dirty = true;
+        }
+        if marks.trading_week != week {
+            marks.trading_week = week;
+            marks.week_start_equity = equity;
+            dirty = true;
+        }
+        if equity > marks.high_water_mark {
+            marks.high_water_mark = equity;
+            dirty = true;
+        }
+
+        let dd = |start: f64| if start > 0.0 { ((start - equity) / start).max(0.0) } else { 0.0 };
+        let daily_dd = dd(marks.day_start_equity);
+        let weekly_dd = dd(marks.week_start_equity);
+        let peak_dd = dd(marks.high_water_mark);
+        self.dirty |= dirty;
+
+        let mut action = BreakerAction::None;
+        let mut tripped = Vec::new();
+        for (name, value, breaker) in [
+            ("Daily", daily_dd, self.config.daily),
+            ("Weekly", weekly_dd, self.config.weekly),
+            ("Peak", peak_dd, self.config.peak),
+        ] {
+            if value > breaker.threshold {
+                tripped.push(format!(
+                    "{} Drawdown {:.2}% > {:.2}% -> {:?}",
+                    name, value * 100.0, breaker.threshold * 100.0, breaker.action
+                ));
+                action = action.max(breaker.action);
+            }
+        }
+
+        BreakerStatus { action, daily_dd, weekly_dd, peak_dd, tripped }
+    }
+
+    pub fn start_of_day_equity(&self) -> Option<f64> {
+        self.marks.as_ref().map(|m| m.day_start_equity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_tiered_actions() {
+        let mut breaker = DrawdownBreaker::new(DrawdownConfig::default());
+        let t = at(2024, 3, 5, 12); // Tuesday
+
+        assert_eq!(breaker.update(100_000.0, t).action, BreakerAction::None);
+        // -3% on the day: daily breaker only
+        let s = breaker.update(97_000.0, t);
+        assert_eq!(s.action, BreakerAction::ReduceSize);
+        assert_eq!(s.tripped.len(), 1);
+        // -6%: weekly escalates to reduce-only
+        assert_eq!(breaker.update(94_000.0, t).action, BreakerAction::ReduceOnly);
+        // -16% from peak: halt
+        assert_eq!(breaker.update(84_000.0, t).action, BreakerAction::Halt);
+    }
+
+    #[test]
+    fn test_config_from_json() {
+        let path = std::env::temp_dir().join(format!("drawdown_{}.json", std::process::id()));
+        fs::write(&path, r#"{ "daily": { "threshold": 0.01, "action": "ReduceOnly" }, "reduce_size_factor": 0.25 }"#).unwrap();
+        let config = DrawdownConfig::load(&path).unwrap();
+        assert_eq!(config.daily.action, BreakerAction::ReduceOnly);
+        assert_eq!(config.reduce_size_factor, 0.25);
+        assert_eq!(config.peak.threshold, DrawdownConfig::default().peak.threshold);
+
+        fs::write(&path, r#"{ "peak": { "threshold": 1.5, "action": "Halt" } }"#).unwrap();
+        assert!(DrawdownConfig::load(&path).is_err());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_rollover_in_configured_timezone() {
+        // New York, day starts at 17:00 local (FX-style)
+        let config = DrawdownConfig::default().with_timezone("America/New_York", 17);
+        let mut breaker = DrawdownBreaker::new(config);
+
+        // Tue 20:00 UTC = 15:00 EST (still Monday's trading day)
+        breaker.update(100_000.0, at(2024, 1, 2, 20));
+        let s = breaker.update(97_000.0, at(2024, 1, 2, 21)); // 16:00 EST
+        assert_eq!(s.action, BreakerAction::ReduceSize);
+
+        // 22:00 UTC = 17:00 EST -> new trading day, SOD resets to current equity
+        let s = breaker.update(97_000.0, at(2024, 1, 2, 22));
+        assert_eq!(s.daily_dd, 0.0);
+        assert_eq!(breaker.start_of_day_equity(), Some(97_000.0));
+        // Peak is unaffected by the rollover
+        assert!((s.peak_dd - 0.03).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weekly_rollover_and_hwm() {
+        let mut breaker = DrawdownBreaker::new(DrawdownConfig::default());
+        breaker.update(100_000.0, at(2024, 3, 4, 1)); // Monday
+        breaker.update(110_000.0, at(2024, 3, 6, 1)); // New high mid-week
+        assert_eq!(breaker.marks().unwrap().high_water_mark, 110_000.0);
+
+        let s = breaker.update(104_000.0, at(2024, 3, 11, 1)); // Next Monday
+        assert_eq!(breaker.marks().unwrap().week_start_equity, 104_000.0);
+        assert_eq!(s.weekly_dd, 0.0);
+        assert!((s.peak_dd - 6_000.0 / 110_000.0).abs() < 1e-12);
+    }
+}
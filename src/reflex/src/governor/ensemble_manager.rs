@@ -1,37 +1,180 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::governor::regime_detector::MarketRegime;
+use crate::ledger::Side;
+
+const HISTORY_LEN: usize = 100; // Selections kept for the API
+
+/// D-95: Regime-to-Adapter map and the demotion policy ("The Chameleon").
+/// Loaded from JSON (ADAPTER_MAP_PATH); missing fields keep their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnsembleConfig {
+    pub adapters: HashMap<MarketRegime, String>,
+    pub fallback: String, // Used for unmapped regimes and in place of demoted adapters
+    pub min_samples: u32, // Evidence required before an adapter can be judged
+    pub min_hit_rate: f64,
+    pub max_nullification_rate: f64,
+    pub min_markout_pnl: Option<f64>, // Mark-out PnL floor (quote currency); None = not enforced
+    pub outcome_horizon: u32, // Ticks before a decision is marked out
+    pub demotion_ticks: u64, // Probation before a demoted adapter gets a fresh record
+}
+
+impl Default for EnsembleConfig {
+    fn default() -> Self {
+        // "The Chameleon": Matching Cognitive Style to Market Physics
+        let adapters = HashMap::from([
+            (MarketRegime::Laminar, "adapter_trend_follower_v1".to_string()),
+            (MarketRegime::Turbulent, "adapter_mean_reversion_v2".to_string()),
+            (MarketRegime::Decoherent, "adapter_volatility_hawk_v1".to_string()),
+            (MarketRegime::Unknown, "adapter_generalist_base".to_string()),
+        ]);
+        Self {
+            adapters,
+            fallback: "adapter_generalist_base".to_string(),
+            min_samples: 20,
+            min_hit_rate: 0.45,
+            max_nullification_rate: 0.30,
+            min_markout_pnl: None,
+            outcome_horizon: 10,
+            demotion_ticks: 1_000,
+        }
+    }
+}
+
+impl EnsembleConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        if config.fallback.is_empty() || config.adapters.values().any(|a| a.is_empty()) {
+            return Err("Adapter ids must not be empty".into());
+        }
+        if !(0.0..=1.0).contains(&config.min_hit_rate) || !(0.0..=1.0).contains(&config.max_nullification_rate) {
+            return Err("Rates must be within [0, 1]".into());
+        }
+        if config.outcome_horizon == 0 {
+            return Err("outcome_horizon must be at least 1 tick".into());
+        }
+        Ok(config)
+    }
+}
+
+/// Running record of one adapter: Firewall verdicts on its context and
+/// the marked-out outcome of the decisions taken while it was active.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AdapterPerformance {
+    pub consultations: u32, // Brain contexts checked by the Firewall
+    pub nullifications: u32, // ... of which were nullified (D-88)
+    pub decisions: u32, // Buy/Sell sent under this adapter
+    pub graded: u32, // ... of which have been marked out
+    pub hits: u32, // ... and moved in our favour
+    pub markout_pnl: f64, // Sum of mark-outs at the outcome horizon
+}
+
+impl AdapterPerformance {
+    pub fn hit_rate(&self) -> Option<f64> {
+        (self.graded > 0).then(|| self.hits as f64 / self.graded as f64)
+    }
+
+    pub fn nullification_rate(&self) -> Option<f64> {
+        (self.consultations > 0).then(|| self.nullifications as f64 / self.consultations as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AdapterSelection {
+    pub timestamp: f64, // Physics time of the swap
+    pub regime: MarketRegime,
+    pub adapter: String,
+    pub reason: String,
+}
+
+/// API view of the ensemble (pushed into SharedState by the owner loop).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EnsembleSnapshot {
+    pub active_adapter: String,
+    pub adapters: Vec<(String, AdapterPerformance, bool)>, // (adapter, record, demoted)
+    pub history: Vec<AdapterSelection>, // Oldest first
+}
+
+#[derive(Debug, Clone)]
+struct OpenCall {
+    adapter: String,
+    sign: f64,
+    qty: f64,
+    entry: f64,
+    age: u32,
+}
 
 #[derive(Debug, Clone)]
 pub struct EnsembleManager {
     pub current_adapter_id: String,
-    regime_map: HashMap<String, String>,
+    config: EnsembleConfig,
+    performance: HashMap<String, AdapterPerformance>,
+    demoted: HashMap<String, u64>, // Adapter -> tick its probation ends
+    open_calls: Vec<OpenCall>,
+    history: VecDeque<AdapterSelection>,
+    tick: u64,
 }
 
 impl EnsembleManager {
     pub fn new() -> Self {
-        let mut map = HashMap::new();
-        // D-95: Regime-to-Adapter Mapping
-        // "The Chameleon": Matching Cognitive Style to Market Physics
-        map.insert("Laminar".to_string(), "adapter_trend_follower_v1".to_string());
-        map.insert("Turbulent".to_string(), "adapter_mean_reversion_v2".to_string());
-        map.insert("Violent".to_string(), "adapter_volatility_hawk_v1".to_string());
-        map.insert("Unknown".to_string(), "adapter_generalist_base".to_string());
+        Self::with_config(EnsembleConfig::default())
+    }
 
+    pub fn with_config(config: EnsembleConfig) -> Self {
         Self {
-            current_adapter_id: "adapter_generalist_base".to_string(),
-            regime_map: map,
+            current_adapter_id: config.fallback.clone(),
+            config,
+            performance: HashMap::new(),
+            demoted: HashMap::new(),
+            open_calls: Vec::new(),
+            history: VecDeque::with_capacity(HISTORY_LEN),
+            tick: 0,
         }
     }
 
+    pub fn config(&self) -> &EnsembleConfig {
+        &self.config
+    }
+
     /// Updates the active adapter based on the identified Regime.
+    /// The mapped adapter is replaced by the fallback while it is demoted.
     /// Returns TRUE if a swap occurred (so we can log it).
-    pub fn update_regime(&mut self, regime: &str) -> bool {
-        // Default to Generalist if regime unknown
-        let target_adapter = self.regime_map.get(regime)
-            .unwrap_or(&"adapter_generalist_base".to_string())
-            .clone();
+    pub fn update_regime(&mut self, regime: MarketRegime, timestamp: f64) -> bool {
+        self.tick += 1;
+        self.end_probations();
+
+        // Default to the fallback (Generalist) if the regime is unmapped
+        let fallback = self.config.fallback.clone();
+        let mapped = self.config.adapters.get(&regime).cloned().unwrap_or_else(|| fallback.clone());
+        let (target_adapter, reason) = if mapped == fallback {
+            (mapped, "Regime map".to_string())
+        } else if self.demoted.contains_key(&mapped) {
+            (fallback, format!("{} on probation", mapped))
+        } else if let Some(why) = self.underperformance(&mapped) {
+            tracing::warn!("🦎 CHAMELEON DEMOTION: {} ({}) -> {}", mapped, why, fallback);
+            self.demoted.insert(mapped.clone(), self.tick + self.config.demotion_ticks);
+            (fallback, format!("{} demoted: {}", mapped, why))
+        } else {
+            (mapped, "Regime map".to_string())
+        };
 
         if self.current_adapter_id != target_adapter {
-            tracing::info!("🦎 CHAMELEON SWAP: {} -> {}", self.current_adapter_id, target_adapter);
+            tracing::info!("🦎 CHAMELEON SWAP: {} -> {} ({})", self.current_adapter_id, target_adapter, reason);
+            if self.history.len() >= HISTORY_LEN {
+                self.history.pop_front();
+            }
+            self.history.push_back(AdapterSelection {
+                timestamp,
+                regime,
+                adapter: target_adapter.clone(),
+                reason,
+            });
             self.current_adapter_id = target_adapter;
             true
         } else {
@@ -42,6 +185,120 @@ impl EnsembleManager {
     pub fn get_active_adapter(&self) -> String {
         self.current_adapter_id.clone()
     }
+
+    /// Firewall verdict on the context produced by the active adapter.
+    pub fn record_consultation(&mut self, nullified: bool) {
+        let perf = self.performance.entry(self.current_adapter_id.clone()).or_default();
+        perf.consultations += 1;
+        if nullified {
+            perf.nullifications += 1;
+        }
+    }
+
+    /// A Buy/Sell sent to the venue while the active adapter shaped the context.
+    /// Graded by `mark` once it is `outcome_horizon` ticks old.
+    pub fn record_decision(&mut self, side: Side, qty: f64, price: f64) {
+        self.performance.entry(self.current_adapter_id.clone()).or_default().decisions += 1;
+        self.open_calls.push(OpenCall {
+            adapter: self.current_adapter_id.clone(),
+            sign: side.sign(),
+            qty,
+            entry: price,
+            age: 0,
+        });
+    }
+
+    /// Ages open decisions and marks out those at the horizon against `price`.
+    pub fn mark(&mut self, price: f64) {
+        let horizon = self.config.outcome_horizon;
+        let mut still_open = Vec::with_capacity(self.open_calls.len());
+        for mut call in self.open_calls.drain(..) {
+            call.age += 1;
+            if call.age < horizon {
+                still_open.push(call);
+                continue;
+            }
+            let pnl = call.sign * (price - call.entry) * call.qty;
+            let perf = self.performance.entry(call.adapter).or_default();
+            perf.graded += 1;
+            perf.markout_pnl += pnl;
+            if pnl > 0.0 {
+                perf.hits += 1;
+            }
+        }
+        self.open_calls = still_open;
+    }
+
+    pub fn performance(&self, adapter: &str) -> Option<&AdapterPerformance> {
+        self.performance.get(adapter)
+    }
+
+    pub fn is_demoted(&self, adapter: &str) -> bool {
+        self.demoted.contains_key(adapter)
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &AdapterSelection> {
+        self.history.iter()
+    }
+
+    pub fn snapshot(&self) -> EnsembleSnapshot {
+        let mut adapters: Vec<_> = self
+            .performance
+            .iter()
+            .map(|(id, perf)| (id.clone(), perf.clone(), self.is_demoted(id)))
+            .collect();
+        adapters.sort_by(|a, b| a.0.cmp(&b.0));
+        EnsembleSnapshot {
+            active_adapter: self.current_adapter_id.clone(),
+            adapters,
+            history: self.history.iter().cloned().collect(),
+        }
+    }
+
+    /// Why the adapter should be demoted, if it has enough evidence against it.
+    fn underperformance(&self, adapter: &str) -> Option<String> {
+        let perf = self.performance.get(adapter)?;
+        let min_samples = self.config.min_samples;
+        if perf.graded >= min_samples {
+            let hit_rate = perf.hit_rate().unwrap_or(0.0);
+            if hit_rate < self.config.min_hit_rate {
+                return Some(format!("hit rate {:.0}% < {:.0}%", hit_rate * 100.0, self.config.min_hit_rate * 100.0));
+            }
+            if let Some(floor) = self.config.min_markout_pnl {
+                if perf.markout_pnl < floor {
+                    return Some(format!("mark-out PnL {:.2} < {:.2}", perf.markout_pnl, floor));
+                }
+            }
+        }
+        if perf.consultations >= min_samples {
+            let rate = perf.nullification_rate().unwrap_or(0.0);
+            if rate > self.config.max_nullification_rate {
+                return Some(format!(
+                    "nullification rate {:.0}% > {:.0}%",
+                    rate * 100.0,
+                    self.config.max_nullification_rate * 100.0
+                ));
+            }
+        }
+        None
+    }
+
+    /// Probation over: the adapter starts again with a clean record.
+    fn end_probations(&mut self) {
+        let tick = self.tick;
+        let expired: Vec<String> = self.demoted.iter().filter(|(_, until)| **until <= tick).map(|(id, _)| id.clone()).collect();
+        for id in expired {
+            tracing::info!("🦎 CHAMELEON PROBATION OVER: {}", id);
+            self.demoted.remove(&id);
+            self.performance.remove(&id);
+        }
+    }
+}
+
+impl Default for EnsembleManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -51,25 +308,95 @@ mod tests {
     #[test]
     fn test_regime_switch_logic() {
         let mut mgr = EnsembleManager::new();
-        
+
         // Initial state
         assert_eq!(mgr.get_active_adapter(), "adapter_generalist_base");
 
         // 1. Laminar -> Trend Follower
-        let switched = mgr.update_regime("Laminar");
+        let switched = mgr.update_regime(MarketRegime::Laminar, 1.0);
         assert!(switched);
         assert_eq!(mgr.get_active_adapter(), "adapter_trend_follower_v1");
 
         // 2. Same Regime -> No Swap
-        let switched_again = mgr.update_regime("Laminar");
+        let switched_again = mgr.update_regime(MarketRegime::Laminar, 2.0);
         assert!(!switched_again);
 
-        // 3. Violent -> Volatility Hawk
-        mgr.update_regime("Violent");
+        // 3. Decoherent -> Volatility Hawk
+        mgr.update_regime(MarketRegime::Decoherent, 3.0);
         assert_eq!(mgr.get_active_adapter(), "adapter_volatility_hawk_v1");
-        
+
         // 4. Unknown -> Generalist
-        mgr.update_regime("Aliens");
+        mgr.update_regime(MarketRegime::Unknown, 4.0);
         assert_eq!(mgr.get_active_adapter(), "adapter_generalist_base");
+
+        let history: Vec<_> = mgr.history().map(|s| s.adapter.as_str()).collect();
+        assert_eq!(history, ["adapter_trend_follower_v1", "adapter_volatility_hawk_v1", "adapter_generalist_base"]);
+    }
+
+    #[test]
+    fn test_losing_adapter_is_demoted_then_paroled() {
+        let config = EnsembleConfig { min_samples: 4, outcome_horizon: 2, demotion_ticks: 3, ..Default::default() };
+        let mut mgr = EnsembleManager::with_config(config);
+        mgr.update_regime(MarketRegime::Laminar, 0.0);
+
+        // Four longs, each marked out two ticks later at a lower price
+        for i in 0..4 {
+            mgr.record_decision(Side::Buy, 1.0, 100.0 - i as f64);
+            mgr.mark(100.0 - i as f64);
+            mgr.mark(90.0);
+        }
+        let perf = mgr.performance("adapter_trend_follower_v1").unwrap();
+        assert_eq!((perf.decisions, perf.graded, perf.hits), (4, 4, 0));
+        assert!(perf.markout_pnl < 0.0);
+
+        // Same regime, but the trend follower is now benched
+        assert!(mgr.update_regime(MarketRegime::Laminar, 1.0));
+        assert_eq!(mgr.get_active_adapter(), "adapter_generalist_base");
+        assert!(mgr.is_demoted("adapter_trend_follower_v1"));
+        assert!(mgr.history().last().unwrap().reason.contains("hit rate"));
+
+        // Probation ends after demotion_ticks with a clean record
+        mgr.update_regime(MarketRegime::Laminar, 2.0);
+        mgr.update_regime(MarketRegime::Laminar, 3.0);
+        assert_eq!(mgr.get_active_adapter(), "adapter_generalist_base");
+        assert!(mgr.update_regime(MarketRegime::Laminar, 4.0));
+        assert_eq!(mgr.get_active_adapter(), "adapter_trend_follower_v1");
+        assert!(mgr.performance("adapter_trend_follower_v1").is_none());
+    }
+
+    #[test]
+    fn test_nullified_adapter_is_demoted() {
+        let config = EnsembleConfig { min_samples: 5, ..Default::default() };
+        let mut mgr = EnsembleManager::with_config(config);
+        mgr.update_regime(MarketRegime::Decoherent, 0.0);
+        for i in 0..5 {
+            mgr.record_consultation(i % 2 == 0); // 60% nullified
+        }
+        let snap = mgr.snapshot();
+        assert_eq!(snap.adapters[0].1.nullification_rate(), Some(0.6));
+
+        mgr.update_regime(MarketRegime::Decoherent, 1.0);
+        assert_eq!(mgr.get_active_adapter(), "adapter_generalist_base");
+        let snap = mgr.snapshot();
+        assert!(snap.adapters.iter().any(|(id, _, demoted)| id == "adapter_volatility_hawk_v1" && *demoted));
+    }
+
+    #[test]
+    fn test_config_from_json() {
+        let path = std::env::temp_dir().join(format!("ensemble_{}.json", std::process::id()));
+        fs::write(&path, r#"{ "adapters": { "Laminar": "adapter_momentum_v3" }, "fallback": "adapter_base_v2", "min_hit_rate": 0.5 }"#).unwrap();
+        let config = EnsembleConfig::load(&path).unwrap();
+        assert_eq!(config.min_samples, EnsembleConfig::default().min_samples);
+
+        let mut mgr = EnsembleManager::with_config(config);
+        assert_eq!(mgr.get_active_adapter(), "adapter_base_v2");
+        mgr.update_regime(MarketRegime::Laminar, 0.0);
+        assert_eq!(mgr.get_active_adapter(), "adapter_momentum_v3");
+        mgr.update_regime(MarketRegime::Turbulent, 1.0); // Unmapped -> fallback
+        assert_eq!(mgr.get_active_adapter(), "adapter_base_v2");
+
+        fs::write(&path, r#"{ "min_hit_rate": 1.5 }"#).unwrap();
+        assert!(EnsembleConfig::load(&path).is_err());
+        let _ = fs::remove_file(&path);
     }
 }
@@ -12,7 +12,7 @@ use std::os::unix::io::RawFd; // Unused AsRawFd removed
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HandoffState {
     pub sequence_id: u64,
-    pub staircase_tier: u8,
+    // No risk tier: the Risk Ladder restores its own from the state store
     pub staircase_progress: f64,
     pub active_orders: Vec<String>, // Placeholder for Order IDs
     pub audit_drift: f64,
@@ -23,7 +23,6 @@ impl Default for HandoffState {
     fn default() -> Self {
         Self {
             sequence_id: 0,
-            staircase_tier: 1, // Start at tier 1 safely
             staircase_progress: 0.0,
             active_orders: Vec::new(),
             audit_drift: 0.0,
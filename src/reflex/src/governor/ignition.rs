@@ -1,7 +1,18 @@
 use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::clock::{self, SharedClock};
+use crate::db::state::RedisStateStore;
 use crate::governor::sentinel::Sentinel;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+pub const IGNITION_STATE_KEY: &str = "governor:ignition";
+pub const PENNY_QTY: f64 = 0.0001; // Venue minimum (XBT)
+pub const BLESSING_TIMEOUT: Duration = Duration::from_secs(2);
+const RETRY_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_ATTEMPTS: u32 = 3; // Per gate, then back to Hibernation
+const RESUME_TTL_MS: i64 = 10 * 60 * 1000; // An older snapshot proves nothing about now
+const HEARTBEAT: Duration = Duration::from_secs(30); // Snapshot refresh while past Hibernation
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IgnitionState {
     Hibernation,
     HardwareCheck, // Gate 1: Sentinel Stability
@@ -11,33 +22,165 @@ pub enum IgnitionState {
     Ignited,       // Live Trading Enabled
 }
 
+/// Gate 3 order: buy `qty` at `price`, then sell it straight back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PennyOrder {
+    pub id: u64,
+    pub price: f64,
+    pub qty: f64,
+}
+
+/// What the venue reported for the round trip, plus its position fetched just before and after.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PennyFill {
+    pub id: u64,
+    pub bought: f64,
+    pub sold: f64,
+    pub position_before: f64,
+    pub position_after: f64,
+}
+
+/// Persisted so a restart with nothing changed can skip the hardware check and warmup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IgnitionSnapshot {
+    pub state: IgnitionState,
+    pub fingerprint: String, // Build + symbol + venue
+    pub saved_at_ms: i64,
+}
+
 pub struct IgnitionSequence {
     pub state: IgnitionState,
     pub hardware_last_checked: Instant,
     pub warmup_start: Option<Instant>,
     pub penny_trade_id: Option<u64>,
+    attempts: u32,
+    retry_at: Option<Instant>,
+    blessing_in_flight: bool,
+    fingerprint: String,
+    dirty: bool,
+    last_saved: Option<Instant>,
+    clock: SharedClock,
 }
 
 impl IgnitionSequence {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             state: IgnitionState::Hibernation,
-            hardware_last_checked: Instant::now(),
+            hardware_last_checked: clock.now(),
             warmup_start: None,
             penny_trade_id: None,
+            attempts: 0,
+            retry_at: None,
+            blessing_in_flight: false,
+            fingerprint: String::new(),
+            dirty: false,
+            last_saved: None,
+            clock,
+        }
+    }
+
+    fn transition(&mut self, next: IgnitionState) {
+        self.state = next;
+        self.attempts = 0;
+        self.retry_at = None;
+        self.penny_trade_id = None;
+        self.blessing_in_flight = false;
+        self.dirty = true;
+    }
+
+    /// Failed attempt at the current gate: back off, or give up after `MAX_ATTEMPTS`.
+    fn retry_or_abort(&mut self, reason: &str) {
+        self.attempts += 1;
+        if self.attempts >= MAX_ATTEMPTS {
+            println!("[IGNITION] {:?} failed {} times ({}).", self.state, self.attempts, reason);
+            self.abort();
+        } else {
+            println!(
+                "[IGNITION] {:?} attempt {} failed ({}). Retrying in {:?}.",
+                self.state, self.attempts, reason, RETRY_BACKOFF
+            );
+            self.retry_at = Some(self.clock.now() + RETRY_BACKOFF);
+        }
+    }
+
+    fn backing_off(&self) -> bool {
+        self.retry_at.is_some_and(|t| self.clock.now() < t)
+    }
+
+    /// Warm restart. A snapshot past warmup, with the same fingerprint and recent enough,
+    /// resumes at the penny trade: venue and Brain are re-checked (seconds), the 300s
+    /// hardware check and 60s warmup are not.
+    pub fn restore(&mut self, snapshot: &IgnitionSnapshot, fingerprint: &str) -> bool {
+        self.fingerprint = fingerprint.to_string();
+        let age_ms = self.clock.unix_millis() - snapshot.saved_at_ms;
+        let past_warmup = matches!(
+            snapshot.state,
+            IgnitionState::PennyTrade | IgnitionState::AwaitingGemma | IgnitionState::Ignited
+        );
+        let same_fingerprint = snapshot.fingerprint == fingerprint;
+        if !past_warmup || !same_fingerprint || !(0..=RESUME_TTL_MS).contains(&age_ms) {
+            println!(
+                "[IGNITION] Snapshot not reusable ({:?}, age {}s, fingerprint {}). Cold start.",
+                snapshot.state,
+                age_ms / 1000,
+                if same_fingerprint { "unchanged" } else { "changed" }
+            );
+            return false;
+        }
+        println!("[IGNITION] Warm restart from {:?}. Skipping hardware check and warmup.", snapshot.state);
+        self.transition(IgnitionState::PennyTrade);
+        true
+    }
+
+    /// Restore from the state store (cold start if absent or unreachable).
+    pub async fn load(&mut self, store: &RedisStateStore, fingerprint: &str) {
+        self.fingerprint = fingerprint.to_string();
+        match store.get_state::<IgnitionSnapshot>(IGNITION_STATE_KEY).await {
+            Ok(snapshot) => {
+                self.restore(&snapshot, fingerprint);
+            }
+            Err(e) => println!("[IGNITION] No persisted state ({}). Cold start.", e),
+        }
+    }
+
+    pub fn snapshot(&self) -> IgnitionSnapshot {
+        IgnitionSnapshot {
+            state: self.state,
+            fingerprint: self.fingerprint.clone(),
+            saved_at_ms: self.clock.unix_millis(),
+        }
+    }
+
+    /// Persist on every transition, and every `HEARTBEAT` while running so the snapshot stays fresh.
+    pub async fn persist(&mut self, store: &RedisStateStore) {
+        let stale = self.state != IgnitionState::Hibernation
+            && self.last_saved.is_none_or(|t| self.clock.elapsed_since(t) >= HEARTBEAT);
+        if !self.dirty && !stale {
+            return;
+        }
+        match store.set_state(IGNITION_STATE_KEY, &self.snapshot()).await {
+            Ok(()) => {
+                self.dirty = false;
+                self.last_saved = Some(self.clock.now());
+            }
+            Err(e) => eprintln!("[IGNITION] Failed to persist state: {}", e),
         }
     }
 
     /// User manually triggers the start sequence (e.g., from HUD)
     pub fn initiate_launch(&mut self) {
         if self.state == IgnitionState::Hibernation {
-            self.state = IgnitionState::HardwareCheck;
+            self.transition(IgnitionState::HardwareCheck);
             println!("[IGNITION] Sequence Initiated. Checking Hardware...");
         }
     }
 
     pub fn abort(&mut self) {
-        self.state = IgnitionState::Hibernation;
+        self.transition(IgnitionState::Hibernation);
         self.warmup_start = None;
         println!("[IGNITION] ABORTED. Returning to Hibernation.");
     }
@@ -49,12 +192,12 @@ impl IgnitionSequence {
             },
             IgnitionState::HardwareCheck => {
                 // Gate 1: Helper function in Sentinel checks for 300s of stability
-                // For development speed, we might use a shorter window if flagged, 
+                // For development speed, we might use a shorter window if flagged,
                 // but requirement is 300s.
                 if sentinel.is_stable_for(Duration::from_secs(300)) {
                     println!("[IGNITION] Hardware Integrity Verified. Warming Up...");
-                    self.state = IgnitionState::WarmingUp;
-                    self.warmup_start = Some(Instant::now());
+                    self.transition(IgnitionState::WarmingUp);
+                    self.warmup_start = Some(self.clock.now());
                 } else {
                      // If we just entered, we wait. If unstable, strict reset logic handled by Sentinel's last_instability
                 }
@@ -63,47 +206,231 @@ impl IgnitionSequence {
                 // Gate 2: 60s of Market Data
                 if !market_active {
                     // Reset if flow stops
-                    self.warmup_start = Some(Instant::now()); 
+                    self.warmup_start = Some(self.clock.now());
                     return;
                 }
-                
+
                 if let Some(start) = self.warmup_start {
-                    if start.elapsed() >= Duration::from_secs(60) {
+                    if self.clock.elapsed_since(start) >= Duration::from_secs(60) {
                          println!("[IGNITION] Warmup Complete. Proceeding to Penny Trade...");
-                         self.state = IgnitionState::PennyTrade;
+                         self.transition(IgnitionState::PennyTrade);
                     }
                 }
             },
             IgnitionState::PennyTrade => {
                 // Gate 3: Penny Trade
-                // Logic handled by OrderManager integration. 
-                // We wait for external confirmation that penny trade filled.
-                // For now, we assume it's pending.
+                // The owner loop places it (`penny_order`) and reports the fills (`confirm_penny_trade`).
             },
             IgnitionState::AwaitingGemma => {
                 // Gate 4: Gemma Blessing
-                // Awaiting explicit "Allow" from Brain.
+                // Awaiting explicit "Allow" from Brain (`blessing_due` -> `confirm_gemma_blessing`).
             },
             IgnitionState::Ignited => {
                 // Live
             }
         }
     }
-    
-    // Called when Penny Trade confirms fill
-    pub fn confirm_penny_trade(&mut self) {
+
+    /// Gate 3: the round trip to place now, if one is due. At most one in flight.
+    pub fn penny_order(&mut self, price: f64) -> Option<PennyOrder> {
+        if self.state != IgnitionState::PennyTrade || self.penny_trade_id.is_some() || self.backing_off() {
+            return None;
+        }
+        if !price.is_finite() || price <= 0.0 {
+            return None;
+        }
+        let id = self.clock.unix_millis() as u64;
+        self.penny_trade_id = Some(id);
+        println!("[IGNITION] Placing Penny Trade #{}: {} XBT round trip @ {:.2}", id, PENNY_QTY, price);
+        Some(PennyOrder { id, price, qty: PENNY_QTY })
+    }
+
+    // Called when Penny Trade confirms fill: both legs filled and the venue position is back where it was
+    pub fn confirm_penny_trade(&mut self, fill: &PennyFill) -> bool {
+        if self.state != IgnitionState::PennyTrade || self.penny_trade_id != Some(fill.id) {
+            return false;
+        }
+        let tolerance = PENNY_QTY * 1e-6;
+        let drift = fill.position_after - fill.position_before;
+        let failure = if (fill.bought - PENNY_QTY).abs() > tolerance {
+            Some(format!("buy leg filled {} of {}", fill.bought, PENNY_QTY))
+        } else if (fill.sold - PENNY_QTY).abs() > tolerance {
+            Some(format!("sell leg filled {} of {}", fill.sold, PENNY_QTY))
+        } else if drift.abs() > tolerance {
+            Some(format!("reconciliation drift {:+.8} XBT", drift))
+        } else {
+            None
+        };
+
+        if let Some(reason) = failure {
+            self.penny_failed(&reason);
+            return false;
+        }
+        println!("[IGNITION] Penny Trade #{} Confirmed and Reconciled. Awaiting Gemma...", fill.id);
+        self.transition(IgnitionState::AwaitingGemma);
+        true
+    }
+
+    /// Venue rejected or timed out, or the fill did not reconcile.
+    pub fn penny_failed(&mut self, reason: &str) {
         if self.state == IgnitionState::PennyTrade {
-            println!("[IGNITION] Penny Trade Confirmed. Awaiting Gemma...");
-             // Skip Gemma for now in this iteration, or move to AwaitingGemma
-            self.state = IgnitionState::AwaitingGemma;
+            self.penny_trade_id = None;
+            self.retry_or_abort(reason);
         }
     }
 
+    /// Gate 4: whether to ask the Brain now. At most one request in flight.
+    pub fn blessing_due(&mut self) -> bool {
+        if self.state != IgnitionState::AwaitingGemma || self.blessing_in_flight || self.backing_off() {
+            return false;
+        }
+        self.blessing_in_flight = true;
+        true
+    }
+
     // Called when Brain confirms Laminar flow
     pub fn confirm_gemma_blessing(&mut self) {
         if self.state == IgnitionState::AwaitingGemma {
             println!("[IGNITION] Gemma Logic Verified. SYSTEMS IGNITED.");
-            self.state = IgnitionState::Ignited;
+            self.transition(IgnitionState::Ignited);
+        }
+    }
+
+    /// Brain said no. That is a verdict, not a glitch: no retry.
+    pub fn deny_gemma_blessing(&mut self, reason: &str) {
+        if self.state == IgnitionState::AwaitingGemma {
+            println!("[IGNITION] Gemma DENIED Ignition: {}", reason);
+            self.abort();
+        }
+    }
+
+    /// Brain unreachable or slower than `BLESSING_TIMEOUT`: back off and ask again.
+    pub fn blessing_unavailable(&mut self, reason: &str) {
+        if self.state == IgnitionState::AwaitingGemma {
+            self.blessing_in_flight = false;
+            self.retry_or_abort(reason);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, ManualClock};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_gates_follow_clock() {
+        let clock = Arc::new(ManualClock::new());
+        let mut sentinel = Sentinel::with_clock(clock.clone());
+        let mut ignition = IgnitionSequence::with_clock(clock.clone());
+        ignition.initiate_launch();
+
+        // Gate 1: 300s of Sentinel stability
+        clock.advance(Duration::from_secs(299));
+        sentinel.tick();
+        ignition.update(&sentinel, true);
+        assert_eq!(ignition.state, IgnitionState::HardwareCheck);
+
+        clock.advance(Duration::from_secs(1));
+        ignition.update(&sentinel, true);
+        assert_eq!(ignition.state, IgnitionState::WarmingUp);
+
+        // Gate 2: 60s of uninterrupted market data; a gap restarts the warmup
+        clock.advance(Duration::from_secs(45));
+        ignition.update(&sentinel, false);
+        clock.advance(Duration::from_secs(45));
+        ignition.update(&sentinel, true);
+        assert_eq!(ignition.state, IgnitionState::WarmingUp);
+
+        clock.advance(Duration::from_secs(15));
+        ignition.update(&sentinel, true);
+        assert_eq!(ignition.state, IgnitionState::PennyTrade);
+    }
+
+    fn at_penny_trade(clock: &Arc<ManualClock>) -> IgnitionSequence {
+        let mut ignition = IgnitionSequence::with_clock(clock.clone());
+        let snapshot = IgnitionSnapshot {
+            state: IgnitionState::PennyTrade,
+            fingerprint: "v1".to_string(),
+            saved_at_ms: clock.unix_millis(),
+        };
+        assert!(ignition.restore(&snapshot, "v1"));
+        ignition
+    }
+
+    #[test]
+    fn test_penny_round_trip_and_blessing() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ignition = at_penny_trade(&clock);
+
+        let order = ignition.penny_order(50000.0).expect("penny due");
+        assert_eq!(order.qty, PENNY_QTY);
+        assert!(ignition.penny_order(50000.0).is_none(), "one in flight");
+
+        // Sell leg short-filled: retry after backoff, not before
+        let fill = PennyFill { id: order.id, bought: PENNY_QTY, sold: 0.0, position_before: 0.5, position_after: 0.5001 };
+        assert!(!ignition.confirm_penny_trade(&fill));
+        assert!(ignition.penny_order(50000.0).is_none());
+        clock.advance(RETRY_BACKOFF);
+        let order = ignition.penny_order(50000.0).expect("retry due");
+
+        let fill = PennyFill { id: order.id, bought: PENNY_QTY, sold: PENNY_QTY, position_before: 0.5, position_after: 0.5 };
+        assert!(ignition.confirm_penny_trade(&fill));
+        assert_eq!(ignition.state, IgnitionState::AwaitingGemma);
+
+        // Gate 4: Brain timeout backs off; a blessing ignites
+        assert!(ignition.blessing_due());
+        assert!(!ignition.blessing_due());
+        ignition.blessing_unavailable("timeout");
+        assert!(!ignition.blessing_due());
+        clock.advance(RETRY_BACKOFF);
+        assert!(ignition.blessing_due());
+        ignition.confirm_gemma_blessing();
+        assert_eq!(ignition.state, IgnitionState::Ignited);
+    }
+
+    #[test]
+    fn test_blessing_denial_and_repeated_failure_abort() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ignition = at_penny_trade(&clock);
+        for _ in 0..MAX_ATTEMPTS {
+            assert!(ignition.penny_order(50000.0).is_some());
+            ignition.penny_failed("venue rejected");
+            clock.advance(RETRY_BACKOFF);
+        }
+        assert_eq!(ignition.state, IgnitionState::Hibernation);
+
+        let mut ignition = at_penny_trade(&clock);
+        let order = ignition.penny_order(50000.0).unwrap();
+        ignition.confirm_penny_trade(&PennyFill { id: order.id, bought: PENNY_QTY, sold: PENNY_QTY, position_before: 0.0, position_after: 0.0 });
+        assert!(ignition.blessing_due());
+        ignition.deny_gemma_blessing("Decoherent regime");
+        assert_eq!(ignition.state, IgnitionState::Hibernation);
+    }
+
+    #[test]
+    fn test_restore_requires_fresh_unchanged_snapshot() {
+        let clock = Arc::new(ManualClock::new());
+        let snapshot = |state, fingerprint: &str, saved_at_ms| IgnitionSnapshot {
+            state,
+            fingerprint: fingerprint.to_string(),
+            saved_at_ms,
+        };
+        let now = clock.unix_millis();
+
+        let mut ignition = IgnitionSequence::with_clock(clock.clone());
+        assert!(ignition.restore(&snapshot(IgnitionState::Ignited, "v1", now - 60_000), "v1"));
+        assert_eq!(ignition.state, IgnitionState::PennyTrade);
+
+        for (snap, fingerprint) in [
+            (snapshot(IgnitionState::Ignited, "v1", now), "v2"),                  // Changed build/venue
+            (snapshot(IgnitionState::Ignited, "v1", now - RESUME_TTL_MS - 1), "v1"), // Too old
+            (snapshot(IgnitionState::WarmingUp, "v1", now), "v1"),                // Never got past warmup
+        ] {
+            let mut ignition = IgnitionSequence::with_clock(clock.clone());
+            assert!(!ignition.restore(&snap, fingerprint));
+            assert_eq!(ignition.state, IgnitionState::Hibernation);
         }
     }
 }
@@ -1,4 +1,5 @@
 use std::time::{Duration, Instant};
+use crate::clock::{self, SharedClock};
 use crate::gateway::order_manager::OrderGateway;
 
 const DEADMAN_TIMEOUT_SEC: u64 = 300;
@@ -6,13 +7,19 @@ const DEADMAN_TIMEOUT_SEC: u64 = 300;
 pub struct KillSwitch {
     pub is_halted: bool,
     last_heartbeat: Instant,
+    clock: SharedClock,
 }
 
 impl KillSwitch {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             is_halted: false,
-            last_heartbeat: Instant::now(),
+            last_heartbeat: clock.now(),
+            clock,
         }
     }
 
@@ -30,7 +37,7 @@ impl KillSwitch {
     /// Reset the Kill Switch (requires strict auth).
     pub fn disarm(&mut self) {
         self.is_halted = false;
-        self.last_heartbeat = Instant::now();
+        self.last_heartbeat = self.clock.now();
     }
 
     /// Called periodically to check for Deadman Timeout.
@@ -39,7 +46,7 @@ impl KillSwitch {
             return true; // Already halted
         }
 
-        if self.last_heartbeat.elapsed() > Duration::from_secs(DEADMAN_TIMEOUT_SEC) {
+        if self.clock.elapsed_since(self.last_heartbeat) > Duration::from_secs(DEADMAN_TIMEOUT_SEC) {
             // Deadman Triggered
             self.is_halted = true;
             return true; // Newly halted
@@ -50,13 +57,15 @@ impl KillSwitch {
     
     /// Keep-alive from the UI/Pilot.
     pub fn pulse(&mut self) {
-        self.last_heartbeat = Instant::now();
+        self.last_heartbeat = self.clock.now();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::ManualClock;
+    use std::sync::Arc;
 
     #[test]
     fn test_manual_trigger() {
@@ -69,7 +78,22 @@ mod tests {
         
         assert!(kill_switch.is_halted);
     }
-    
-    // Note: Deadman test skipped to avoid waiting 300s, 
-    // but logic is standard elapsed check.
+
+    #[test]
+    fn test_deadman_timeout() {
+        let clock = Arc::new(ManualClock::new());
+        let mut kill_switch = KillSwitch::with_clock(clock.clone());
+
+        clock.advance(Duration::from_secs(DEADMAN_TIMEOUT_SEC - 1));
+        assert!(!kill_switch.check_heartbeat());
+
+        // Pulse resets the timer
+        kill_switch.pulse();
+        clock.advance(Duration::from_secs(DEADMAN_TIMEOUT_SEC - 1));
+        assert!(!kill_switch.check_heartbeat());
+
+        clock.advance(Duration::from_secs(2));
+        assert!(kill_switch.check_heartbeat());
+        assert!(kill_switch.is_halted);
+    }
 }
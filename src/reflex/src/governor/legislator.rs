@@ -1,6 +1,7 @@
 use tracing::{info, warn};
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use serde::{Deserialize, Serialize};
 
 // Directive-107: The Legislature
 // Manages manual overrides and strategic bias injected by the Pilot.
@@ -34,6 +35,7 @@ pub struct LegislativeState {
     pub aggression: f64, // Fidelity Multiplier (0.1 - 2.0)
     pub maker_only: bool,
     pub hibernation: bool,
+    pub hurdle_rate: f64, // Annualized Omega hurdle (0.05 = 5%)
 }
 
 impl Default for LegislativeState {
@@ -43,10 +45,43 @@ impl Default for LegislativeState {
             aggression: 1.0,
             maker_only: false,
             hibernation: false,
+            hurdle_rate: 0.05,
         }
     }
 }
 
+/// A Pilot legislative update as signed through the CommandGate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LegislativeChange {
+    pub bias: String, // "NEUTRAL", "LONG_ONLY", "SHORT_ONLY"
+    pub aggression: f64,
+    pub maker_only: bool,
+    pub hibernation: bool,
+    pub hurdle_rate: f64, // 0 = keep current
+    pub snap_to_breakeven: bool, // One-shot
+}
+
+impl LegislativeChange {
+    pub const BIASES: [&'static str; 3] = ["NEUTRAL", "LONG_ONLY", "SHORT_ONLY"];
+
+    pub fn apply(&self, state: &mut LegislativeState) {
+        state.bias = StrategicBias::from(self.bias.as_str());
+        state.aggression = self.aggression;
+        state.maker_only = self.maker_only;
+        state.hibernation = self.hibernation;
+        if self.hurdle_rate > 0.0 {
+            state.hurdle_rate = self.hurdle_rate;
+        }
+        if self.snap_to_breakeven {
+            info!("🛡️ SNAP-TO-BREAK-EVEN TRIGGERED");
+        }
+        info!(
+            "⚖️ LEGISLATION UPDATED: Bias={:?}, Aggression={:.2}, MakerOnly={}, Hibernation={}",
+            state.bias, state.aggression, state.maker_only, state.hibernation
+        );
+    }
+}
+
 pub struct Legislator {
     state: Arc<RwLock<LegislativeState>>,
 }
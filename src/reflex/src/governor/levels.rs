@@ -0,0 +1,267 @@
+use std::collections::{HashMap, VecDeque};
+
+use serde::Serialize;
+
+/// Level detection for the Wave Legislator: a rolling volume profile
+/// (high-volume nodes) plus a swing-high/low map, built from ticks.
+#[derive(Debug, Clone)]
+pub struct LevelConfig {
+    pub bucket_bps: f64, // Profile bucket width, fixed from the first price seen
+    pub window: usize, // Ticks in the rolling profile
+    pub node_threshold: f64, // Bucket volume / mean bucket volume to count as a node
+    pub swing_lookback: usize, // Ticks either side a swing must dominate
+    pub max_swings: usize, // Per side (highs / lows)
+    pub horizon: f64, // Time to reach a level, in physics time units (ms on live ticks)
+}
+
+impl Default for LevelConfig {
+    fn default() -> Self {
+        Self {
+            bucket_bps: 5.0,
+            window: 2000,
+            node_threshold: 1.5,
+            swing_lookback: 20,
+            max_swings: 10,
+            horizon: 1000.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum LevelSource {
+    VolumeNode,
+    SwingHigh,
+    SwingLow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Level {
+    pub price: f64,
+    pub strength: f64, // Node: volume / mean; swing: 1.0
+    pub source: LevelSource,
+}
+
+/// The nearest level in the direction of travel, with coincident levels merged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Barrier {
+    pub price: f64,
+    pub distance: f64,
+    pub strength: f64,
+    pub sources: Vec<LevelSource>,
+}
+
+impl Barrier {
+    /// Potential V for `WaveLegislator::evaluate_tunneling`: the kinetic energy
+    /// (v^2 / 2, as the legislator computes T) needed to cover the distance within
+    /// the horizon, scaled by how much volume/structure sits at the level.
+    pub fn potential(&self, horizon: f64) -> f64 {
+        let v_required = self.distance / horizon.max(f64::EPSILON);
+        0.5 * v_required.powi(2) * self.strength
+    }
+}
+
+pub struct LevelMap {
+    pub config: LevelConfig,
+    bucket_width: Option<f64>,
+    profile: HashMap<i64, f64>, // Bucket -> volume in the window
+    ticks: VecDeque<(i64, f64)>, // (bucket, volume), for expiry
+    recent: VecDeque<f64>, // Prices for swing detection (2 * lookback + 1)
+    swing_highs: VecDeque<f64>,
+    swing_lows: VecDeque<f64>,
+}
+
+impl LevelMap {
+    pub fn new(config: LevelConfig) -> Self {
+        Self {
+            recent: VecDeque::with_capacity(2 * config.swing_lookback + 1),
+            config,
+            bucket_width: None,
+            profile: HashMap::new(),
+            ticks: VecDeque::new(),
+            swing_highs: VecDeque::new(),
+            swing_lows: VecDeque::new(),
+        }
+    }
+
+    pub fn update(&mut self, price: f64, volume: f64) {
+        if !(price.is_finite() && price > 0.0) {
+            return;
+        }
+        let width = *self.bucket_width.get_or_insert(price * self.config.bucket_bps / 10_000.0);
+
+        // 1. Rolling volume profile
+        let bucket = (price / width).floor() as i64;
+        let volume = if volume.is_finite() { volume.max(0.0) } else { 0.0 };
+        *self.profile.entry(bucket).or_insert(0.0) += volume;
+        self.ticks.push_back((bucket, volume));
+        while self.ticks.len() > self.config.window {
+            if let Some((old, v)) = self.ticks.pop_front() {
+                if let Some(total) = self.profile.get_mut(&old) {
+                    *total -= v;
+                    if *total <= f64::EPSILON {
+                        self.profile.remove(&old);
+                    }
+                }
+            }
+        }
+
+        // 2. Swings: the middle of the window is a strict extreme of it
+        let span = 2 * self.config.swing_lookback + 1;
+        self.recent.push_back(price);
+        if self.recent.len() > span {
+            self.recent.pop_front();
+        }
+        if self.recent.len() == span {
+            let mid = self.recent[self.config.swing_lookback];
+            let others = self.recent.iter().enumerate().filter(|(i, _)| *i != self.config.swing_lookback).map(|(_, p)| *p);
+            let (mut above, mut below) = (true, true);
+            for p in others {
+                above &= mid > p;
+                below &= mid < p;
+            }
+            let max = self.config.max_swings;
+            if above {
+                Self::push_swing(&mut self.swing_highs, mid, max);
+            } else if below {
+                Self::push_swing(&mut self.swing_lows, mid, max);
+            }
+        }
+    }
+
+    fn push_swing(swings: &mut VecDeque<f64>, price: f64, max: usize) {
+        if swings.len() >= max {
+            swings.pop_front();
+        }
+        swings.push_back(price);
+    }
+
+    /// High-volume nodes (bucket centres) and the remembered swings.
+    pub fn levels(&self) -> Vec<Level> {
+        let mut levels = Vec::new();
+        if let Some(width) = self.bucket_width {
+            let active: Vec<f64> = self.profile.values().copied().filter(|v| *v > 0.0).collect();
+            if !active.is_empty() {
+                let mean = active.iter().sum::<f64>() / active.len() as f64;
+                for (bucket, volume) in &self.profile {
+                    let strength = volume / mean;
+                    if strength >= self.config.node_threshold {
+                        levels.push(Level {
+                            price: (*bucket as f64 + 0.5) * width,
+                            strength,
+                            source: LevelSource::VolumeNode,
+                        });
+                    }
+                }
+            }
+        }
+        levels.extend(self.swing_highs.iter().map(|p| Level { price: *p, strength: 1.0, source: LevelSource::SwingHigh }));
+        levels.extend(self.swing_lows.iter().map(|p| Level { price: *p, strength: 1.0, source: LevelSource::SwingLow }));
+        levels
+    }
+
+    /// Nearest level ahead of the move: resistance above when rising, support
+    /// below when falling. Levels within one bucket of it add their strength.
+    pub fn nearest_barrier(&self, price: f64, velocity: f64) -> Option<Barrier> {
+        let direction = velocity.signum();
+        if velocity == 0.0 || !velocity.is_finite() {
+            return None;
+        }
+        let ahead: Vec<(f64, Level)> = self
+            .levels()
+            .into_iter()
+            .map(|l| ((l.price - price) * direction, l))
+            .filter(|(d, _)| *d > 0.0)
+            .collect();
+        let nearest = ahead.iter().map(|(d, _)| *d).fold(f64::INFINITY, f64::min);
+        if !nearest.is_finite() {
+            return None;
+        }
+        let merge = self.bucket_width.unwrap_or(0.0);
+        let cluster: Vec<&Level> = ahead.iter().filter(|(d, _)| *d <= nearest + merge).map(|(_, l)| l).collect();
+        let mut sources: Vec<LevelSource> = cluster.iter().map(|l| l.source).collect();
+        sources.sort_unstable();
+        sources.dedup();
+        Some(Barrier {
+            price: price + nearest * direction,
+            distance: nearest,
+            strength: cluster.iter().map(|l| l.strength).sum(),
+            sources,
+        })
+    }
+}
+
+impl Default for LevelMap {
+    fn default() -> Self {
+        Self::new(LevelConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governor::wave_legislator::{WaveLegislator, WaveVerdict};
+
+    fn small() -> LevelConfig {
+        LevelConfig { swing_lookback: 2, window: 100, ..Default::default() }
+    }
+
+    #[test]
+    fn test_volume_node_and_swings() {
+        let mut map = LevelMap::new(small());
+        // Heavy trade around 100, a spike to 103, then a dip to 99
+        for _ in 0..20 {
+            map.update(100.0, 5.0);
+        }
+        for p in [101.0, 102.0, 103.0, 102.0, 101.0, 100.5, 99.5, 99.0, 99.5, 100.0, 100.2] {
+            map.update(p, 0.5);
+        }
+
+        let levels = map.levels();
+        let node = levels.iter().find(|l| l.source == LevelSource::VolumeNode).expect("node at 100");
+        assert!((node.price - 100.0).abs() < 0.05 + 1e-9);
+        assert!(node.strength > 1.5);
+        assert!(levels.iter().any(|l| l.source == LevelSource::SwingHigh && l.price == 103.0));
+        assert!(levels.iter().any(|l| l.source == LevelSource::SwingLow && l.price == 99.0));
+
+        // Rising at 101: resistance is the swing high; falling: the 100 node
+        let up = map.nearest_barrier(101.0, 0.5).unwrap();
+        assert_eq!((up.price, up.sources.as_slice()), (103.0, [LevelSource::SwingHigh].as_slice()));
+        let down = map.nearest_barrier(101.0, -0.5).unwrap();
+        assert_eq!(down.sources, vec![LevelSource::VolumeNode]);
+        assert!(map.nearest_barrier(101.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_profile_window_expires_volume() {
+        let mut map = LevelMap::new(LevelConfig { window: 10, ..small() });
+        for _ in 0..10 {
+            map.update(100.0, 10.0);
+        }
+        map.update(110.0, 1.0);
+        assert!(map.levels().iter().any(|l| l.source == LevelSource::VolumeNode));
+        for _ in 0..10 {
+            map.update(110.0, 1.0);
+        }
+        // Old volume rolled out: a single bucket is never above its own mean
+        assert!(map.levels().iter().all(|l| l.source != LevelSource::VolumeNode));
+    }
+
+    #[test]
+    fn test_barrier_feeds_the_legislator() {
+        let barrier = Barrier { price: 101.0, distance: 1.0, strength: 1.0, sources: vec![LevelSource::SwingHigh] };
+        let horizon = 1.0;
+        let v = barrier.potential(horizon);
+        assert!((v - 0.5).abs() < 1e-12);
+
+        let mut legislator = WaveLegislator::new(0.95);
+        // Enough momentum to cover the distance in time -> classical breakout
+        assert!(matches!(legislator.evaluate_tunneling(1.1, 0.1, 100.0, v), WaveVerdict::Breakout { .. }));
+        // Just short of it -> tunneling
+        match legislator.evaluate_tunneling(0.99, 0.1, 100.0, v) {
+            WaveVerdict::Tunneling { target_price, .. } => assert!(target_price > 100.0),
+            other => panic!("expected tunneling, got {:?}", other),
+        }
+        // Far short -> blocked
+        assert!(matches!(legislator.evaluate_tunneling(0.1, 0.1, 100.0, v), WaveVerdict::BarrierBlocked));
+    }
+}
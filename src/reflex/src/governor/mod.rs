@@ -1,11 +1,12 @@
 pub mod ooda_loop;
-pub mod provisional;
 pub mod superposition;
 pub mod wave_legislator;
+pub mod levels; // Barriers for the Wave Legislator
 pub mod ensemble_manager; // D-95
 pub mod health; // D-96
-pub mod staircase;
+pub mod risk_ladder; // D-43 / D-64: Safety Staircase
 pub mod regime_detector;
+pub mod regime_hmm;
 pub mod audit_loop;
 pub mod kill_switch;
 pub mod supervise;
@@ -15,5 +16,9 @@ pub mod handoff; // D-81
 pub mod ignition; // D-83
 pub mod lockdown; // D-85
 pub mod authority; // D-86
+pub mod command_gate; // D-86: Signed commands, two-person rule
 pub mod legislator;
 pub mod rebalancer;
+pub mod drawdown;
+pub mod schedule; // Trading hours, holidays, blackouts
+pub mod strategy;
@@ -9,6 +9,7 @@ use tokio::sync::mpsc;
 use opentelemetry::trace::TraceContextExt;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 use crate::governor::legislator::LegislativeState; // D-107
+use crate::governor::regime_detector::MarketRegime;
 
 #[derive(Debug, Clone)]
 pub struct OODAState {
@@ -19,6 +20,54 @@ pub struct OODAState {
     pub oriented_at: Instant,
     pub trace_id: String, // Traceability link
     pub brain_latency: Option<f64>, // ms
+    pub regime: MarketRegime, // Deterministic Regime (Strategy selection)
+    pub forecast: Option<ForecastQuantiles>,
+    pub omega: Option<f64>, // Long Omega of the forecast at the legislative hurdle
+    pub omega_short: Option<f64>, // Short Omega: gains below the hurdle-discounted price
+}
+
+impl OODAState {
+    /// Omega for the side of `direction` (negative = short, otherwise long).
+    pub fn omega_for(&self, direction: f64) -> Option<f64> {
+        if direction < 0.0 { self.omega_short } else { self.omega }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForecastSource {
+    Brain,    // Forecast RPC
+    Realized, // In-process fallback: realized return quantiles
+}
+
+/// Forecast distribution as absolute price quantiles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastQuantiles {
+    pub p10: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub source: ForecastSource,
+}
+
+impl ForecastQuantiles {
+    /// Rejects degenerate or inverted distributions (Omega is meaningless on them).
+    pub fn new(p10: f64, p50: f64, p90: f64, source: ForecastSource) -> Option<Self> {
+        let finite = p10.is_finite() && p50.is_finite() && p90.is_finite();
+        if !finite || p10 <= 0.0 || p10 >= p90 || p50 < p10 || p50 > p90 {
+            return None;
+        }
+        Some(Self { p10, p50, p90, source })
+    }
+
+    /// Long Omega against `price` grown by the annualized hurdle (daily approx).
+    pub fn omega(&self, price: f64, hurdle_rate: f64) -> f64 {
+        OmegaScorer::calculate(self.p10, self.p50, self.p90, price * (1.0 + hurdle_rate / 365.0))
+    }
+
+    /// Short Omega: the market has to fall below `price` shrunk by the hurdle,
+    /// so the upside tail above it is the loss.
+    pub fn omega_short(&self, price: f64, hurdle_rate: f64) -> f64 {
+        OmegaScorer::calculate_short(self.p10, self.p50, self.p90, price * (1.0 - hurdle_rate / 365.0))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -37,6 +86,20 @@ pub struct Decision {
     pub confidence: f64,
 }
 
+/// Risk-off order `act` hands to the execution layer (owner loop, async).
+#[derive(Debug, Clone)]
+pub enum RiskOffOrder {
+    Trim(TradeProposal),            // Action::Reduce: shrink the position
+    Flatten(TradeProposal, String), // Action::Halt: IOC out of everything (reason)
+}
+
+/// Set by `Action::Halt`. Only an explicit operator resume clears it.
+#[derive(Debug, Clone)]
+pub struct HaltLatch {
+    pub reason: String,
+    pub since: Instant,
+}
+
 impl Decision {
     pub fn default_hold() -> Self {
         Self {
@@ -57,15 +120,19 @@ impl Default for OODAState {
             oriented_at: Instant::now(),
             trace_id: String::new(),
             brain_latency: None,
+            regime: MarketRegime::Unknown,
+            forecast: None,
+            omega: None,
+            omega_short: None,
         }
     }
 }
 
 // --- The Governor ---
 
-use crate::governor::provisional::ProvisionalExecutive;
+use crate::governor::risk_ladder::RiskLadder;
 use crate::brain::veto_gate::VetoGate;
-use crate::auditor::firewall::{Firewall, LlmInferenceResponse, FirewallError};
+use crate::auditor::firewall::{Firewall, FirewallError, LlmInferenceResponse};
 use crate::auditor::truth_envelope::TruthEnvelope;
 use crate::auditor::nullifier::Nullifier; // D-88
 use crate::auditor::red_team::RedTeam; // D-93
@@ -76,11 +143,23 @@ use crate::db::state::RedisStateStore;
 pub use crate::sequencer::sync_gate::SyncGate;
 use crate::sequencer::shadow_gate::ShadowGate; // D-91
 use crate::gateway::binary_packer::BinaryPacker; // D-94
+use crate::gateway::pre_trade::{PreTradeGuard, RestingOrder};
+use crate::taleb::sizing::{SizingInputs, SizingPipeline, SizingStage};
+use crate::taleb::allocator::PipelineAllocation;
+use crate::governor::drawdown::BreakerAction;
+use crate::governor::audit_loop::TradeResult;
+use crate::ledger::Side;
+use crate::governor::strategy::{PositionContext, StrategyRegistry};
+use crate::taleb::omega::OmegaScorer;
+use crate::taleb::TradeProposal;
+use crate::taleb::tail::TailEstimator;
+use crate::clock::{self, SharedClock};
+
 
 pub struct OODACore {
     // Mock clients for now. In prod, these would be Redis/LanceDB clients.
     pub jitter_threshold: Duration,
-    pub provisional: ProvisionalExecutive,
+    pub ladder: RiskLadder, // Safety Staircase (D-43 / D-64)
     pub veto_gate: VetoGate,
     pub firewall: Firewall, // D-87
     pub nullifier: Nullifier, // D-88
@@ -88,6 +167,25 @@ pub struct OODACore {
     pub sync_gate: SyncGate, // D-91
     pub shadow_gate: ShadowGate, // D-92
     pub binary_packer: BinaryPacker, // D-94
+    pub pre_trade: PreTradeGuard, // Fat-Finger Guard (before BinaryPacker)
+    pub sizing: SizingPipeline, // Single path for order quantity
+    pub allocation: Option<PipelineAllocation>, // Capital budget from the CapitalAllocator, set by the owner loop
+    pub strategies: StrategyRegistry, // Pluggable alpha (per symbol / regime)
+    pub drawdown_action: BreakerAction, // Set by the owner loop from DrawdownBreaker
+    pub net_position: f64, // Signed base units held by this pipeline, for Reduce-Only and the capital cap
+    pub reduce_only: bool, // D-86 operator Reduce-Only, set by the owner loop from the AuthorityBridge
+    pub session_taper: Option<f64>, // Trading schedule: Some(allowed fraction) while tapering into a close
+    taper_anchor: Option<f64>, // |position| when the taper began
+    pub hurdle_rate: f64, // Annualized Omega hurdle, set by the owner loop from legislation
+    pub tail: TailEstimator, // Realized quantiles when the Brain Forecast is unavailable
+    pub brain_forecast: Option<ForecastQuantiles>, // Fresh Brain Forecast from the ForecastService, set by the owner loop
+    halt: Option<HaltLatch>,
+    veto_episode: bool, // Nuclear Veto held on the previous decide
+    schema_mismatch: Option<u32>, // Brain context schema version the firewall refused (logged once)
+    shadow_mark: f64, // Shadow book PnL last reported to the ladder
+    fills: Vec<TradeResult>, // Fills since the owner loop last drained them (Audit Loop)
+    pub clock: SharedClock, // Time source for the halt latch (owner loop shares its clock)
+    last_trace: (String, PhysicsState), // Last decided trace, for ACT forensics
     pub ensemble_manager: EnsembleManager, // D-95
     pub phoenix_monitor: PhoenixMonitor, // D-96
     pub symbol: String,
@@ -109,7 +207,7 @@ impl OODACore {
     ) -> Self {
         Self {
             jitter_threshold: Duration::from_millis(20),
-            provisional: ProvisionalExecutive::new(),
+            ladder: RiskLadder::new(),
             veto_gate: VetoGate::new(),
             firewall: Firewall::new(), // D-87
             nullifier: Nullifier::new(), // D-88
@@ -121,6 +219,25 @@ impl OODACore {
             shadow_gate: ShadowGate::new(symbol), // D-92 & D-110
 >>>>>>> feb49d06 (pushing local changes.)
             binary_packer: BinaryPacker::new(), // D-94
+            pre_trade: PreTradeGuard::default(),
+            sizing: SizingPipeline::new(),
+            allocation: None,
+            strategies: StrategyRegistry::default(),
+            drawdown_action: BreakerAction::None,
+            net_position: 0.0,
+            reduce_only: false,
+            session_taper: None,
+            taper_anchor: None,
+            hurdle_rate: LegislativeState::default().hurdle_rate,
+            tail: TailEstimator::default(),
+            brain_forecast: None,
+            halt: None,
+            veto_episode: false,
+            schema_mismatch: None,
+            shadow_mark: 0.0,
+            fills: Vec::new(),
+            clock: clock::system(),
+            last_trace: (String::new(), PhysicsState::default()),
             ensemble_manager: EnsembleManager::new(), // D-95
             phoenix_monitor: PhoenixMonitor::new(), // D-96
             forensic_tx,
@@ -140,11 +257,26 @@ impl OODACore {
     /// Implements "Semantic Nullification" (D-88): Purges corrupted reasoning.
     /// Implements "Semantic Nullification" (D-88): Purges corrupted reasoning.
     #[tracing::instrument(skip(self, client))]
-    pub async fn orient(&mut self, physics: PhysicsState, regime_id: u8, client: Option<&mut BrainClient>, legislative_bias: String) -> OODAState {
+    pub async fn orient(&mut self, physics: PhysicsState, regime: MarketRegime, mut client: Option<&mut BrainClient>, legislative_bias: String) -> OODAState {
         let _start = Instant::now();
         // D-92: Shadow Gate Reality Check
         // Check for fills on pending virtual orders against current physics price
-        self.shadow_gate.check_fills(physics.price);
+        for fill in self.shadow_gate.check_fills(physics.price) {
+            self.ladder.register_fill(fill.slippage_bps().abs());
+            self.fills.push(fill);
+        }
+        // Filled or cancelled shadow orders stop resting; anything else (the primed gateway order) stays
+        let gate = &self.shadow_gate;
+        self.pre_trade.retain_resting(|o| !gate.virtual_book.contains_key(&o.id) || gate.is_pending(&o.id));
+        // Shadow book mark-to-market feeds the ladder's promotion record
+        let shadow_pnl = self.shadow_gate.pnl(physics.price);
+        if shadow_pnl != self.shadow_mark {
+            self.ladder.record_shadow_pnl(shadow_pnl - self.shadow_mark);
+            self.shadow_mark = shadow_pnl;
+        }
+        self.pre_trade.observe_mark(physics.price); // Band reference is the trailing median, not this print
+        self.tail.update_price(physics.price, self.clock.unix_millis()); // Sampled at the forecast horizon
+        self.ensemble_manager.mark(physics.price); // D-95: Mark out decisions per adapter
         
         // Capture TraceID from current span
         let span = tracing::Span::current();
@@ -152,7 +284,12 @@ impl OODACore {
         let trace_id = cx.span().span_context().trace_id().to_string();
 
         // 1. Asynchronous Fetch Logic
-        let (sentiment, regime, latency, distance) = if let Some(c) = client {
+        // Circuit open (Brain down or timing out): straight to blind physics, no timeout spent
+        let brain_reachable = client.as_deref().is_none_or(|c| c.available());
+        let (sentiment, nearest_regime, latency, distance) = if !brain_reachable {
+            tracing::debug!("🧠 Brain circuit open - Blind Physics");
+            (None, None, None, None)
+        } else if let Some(c) = client.as_deref_mut() {
             // LIVE PATH (D-54)
             // D-87: COGNITIVE FIREWALL - Construct Truth Envelope
             let mut truth = TruthEnvelope {
@@ -167,7 +304,7 @@ impl OODACore {
 =======
                 bid_ask_spread: physics.spread, // D-110: Real Spread
 >>>>>>> feb49d06 (pushing local changes.)
-                regime_id,
+                regime,
                 sequence_id: physics.sequence_id,
             };
             
@@ -179,14 +316,8 @@ impl OODACore {
             // 1. Identify Target Adapter from PREVIOUS Regime (or best guess)
             // Note: In a real loop, we'd use the regime from the LAST cycle to pick the adapter for THIS cycle,
             // or use a "Fast" regime classifier here.
-            // For now, we update based on the passed `regime_id` (assuming it came from heavy DB lookup or cache).
-            let current_regime_name = match regime_id {
-                0 => "Laminar",
-                4 => "Turbulent",
-                5 => "Violent",
-                _ => "Unknown",
-            };
-            self.ensemble_manager.update_regime(current_regime_name);
+            // For now, we update based on the passed `regime` (assuming it came from heavy DB lookup or cache).
+            self.ensemble_manager.update_regime(regime, physics.timestamp);
             let active_adapter = self.ensemble_manager.get_active_adapter();
 
             // Enforce Jitter Budget (e.g., 20ms) via Timeout
@@ -195,6 +326,7 @@ impl OODACore {
                 c.get_context(&truth, &legislative_bias, &active_adapter) // D-107: Pass Bias
             ).await {
                 Ok(Ok(ctx)) => {
+                    c.record_success();
                     // D-91: TEMPORAL SYNC-GATE
                     // 1. Latency Check (Atomic Clock)
                     if let Err(e) = self.sync_gate.measure_latency(_start) {
@@ -204,21 +336,36 @@ impl OODACore {
                         // Map Proto ContextResponse to LlmInferenceResponse for validation
                         // We treat context info as "inference" for validation purposes
                         let llm_resp = LlmInferenceResponse {
+                            schema_version: ctx.schema_version,
                             reasoning: ctx.reasoning.clone(), 
                             decision: "CONTEXT".to_string(),
                             confidence: 1.0,
                             referenced_price: if ctx.referenced_price > 0.0 { Some(ctx.referenced_price) } else { None },
                             regime_classification: Some(ctx.nearest_regime.clone()),
+                            sentiment_score: Some(ctx.sentiment_score),
+                            velocity_direction: Some(ctx.velocity_direction.clone()).filter(|d| !d.is_empty()),
                         };
 
                     match self.firewall.validate(&llm_resp, &truth) {
                         Ok(_) => {
+                            if self.schema_mismatch.take().is_some() {
+                                tracing::info!("🧠 Brain context schema v{} accepted again", llm_resp.schema_version);
+                            }
+                            self.ensemble_manager.record_consultation(false);
                             self.nullifier.reset_continuity(); // D-88: Success resets counter
                             let lat = ctx.computation_time_ns as f64 / 1_000_000.0;
                             (Some(ctx.sentiment_score), Some(ctx.nearest_regime), Some(lat), Some(ctx.regime_distance))
                         },
+                        Err(FirewallError::IncompatibleSchema { version, min, max }) => {
+                            // A version skew, not a hallucination: blind, without nullifying or scoring the adapter
+                            if self.schema_mismatch.replace(version) != Some(version) {
+                                tracing::error!("🧠 Brain context schema v{} outside v{}-v{}: Blind Physics until it matches", version, min, max);
+                            }
+                            (None, None, None, None)
+                        }
                         Err(e) => {
                             // D-88: NULLIFICATION "THE ERASER"
+                            self.ensemble_manager.record_consultation(true);
                             let triggered_amr = self.nullifier.nullify(e, ctx.reasoning.clone());
                             if triggered_amr {
                                 tracing::warn!("⚡ AMR: BRAIN RESET REQUESTED");
@@ -237,10 +384,12 @@ impl OODACore {
                 },
                 Ok(Err(e)) => {
                     tracing::warn!("Brain Error: {}", e);
+                    c.record_error(&e);
                     (None, None, None, None) // Error -> Blind
                 },
                 Err(_) => {
                     tracing::warn!("Brain Timeout (Jitter Violated)");
+                    c.record_timeout();
                     (None, None, None, None) // Timeout -> Blind
                 }
             }
@@ -249,6 +398,11 @@ impl OODACore {
             self.fetch_semantics_simulated()
         };
 
+        // 1b. Forecast Quantiles -> Omega at the legislative hurdle
+        let forecast = self.forecast(physics.price);
+        let omega = forecast.map(|f| f.omega(physics.price, self.hurdle_rate));
+        let omega_short = forecast.map(|f| f.omega_short(physics.price, self.hurdle_rate));
+
         // 2. Final Jitter Check (Redundant if timeout works, but good for local processing tracking)
         let loop_latency = _start.elapsed();
         
@@ -268,14 +422,29 @@ impl OODACore {
         OODAState {
             physics,
             sentiment_score: sentiment,
-            nearest_regime: regime,
+            nearest_regime,
             vector_distance: distance,
             oriented_at: Instant::now(),
             trace_id,
             brain_latency: latency,
+            regime,
+            forecast,
+            omega,
+            omega_short,
         }
     }
 
+    /// Brain Forecast (fetched off-loop by the ForecastService, TTL applied there).
+    /// Falls back to realized return quantiles when the Brain is absent, slow or stale.
+    fn forecast(&self, price: f64) -> Option<ForecastQuantiles> {
+        if let Some(f) = self.brain_forecast {
+            return Some(f);
+        }
+
+        let (q10, q50, q90) = (self.tail.quantile(0.10)?, self.tail.quantile(0.50)?, self.tail.quantile(0.90)?);
+        ForecastQuantiles::new(price * (1.0 + q10), price * (1.0 + q50), price * (1.0 + q90), ForecastSource::Realized)
+    }
+
     /// Mocks the external fetch to LanceDB / DistilBERT
     fn fetch_semantics_simulated(&self) -> (Option<f64>, Option<String>, Option<f64>, Option<f64>) {
         // Simulate variability. 
@@ -288,12 +457,23 @@ impl OODACore {
 
     /// ORIENT -> DECIDE
     /// Weighted Voting: Simons (Physics), Kepler (MeanRev), Hypatia (Sentiment)
-    /// Now includes Directive-43: Provisional Risk Sizing
-    /// Now includes Directive-43: Provisional Risk Sizing
+    /// Now includes Directive-43: Risk Ladder Sizing
     /// Now includes Directive-45: Nuclear Veto (Double-Key)
     #[tracing::instrument(skip(self))]
     pub fn decide(&mut self, state: &OODAState, legislation: &LegislativeState) -> Decision {
         let physics = &state.physics;
+        self.last_trace = (state.trace_id.clone(), state.physics);
+
+        // 0. Halt Latch: nothing new until an operator resumes
+        if let Some(latch) = &self.halt {
+            let d = Decision {
+                action: Action::Hold,
+                reason: format!("HALT LATCHED: {}. Awaiting operator resume.", latch.reason),
+                confidence: 1.0,
+            };
+            self.log_forensics(state, &d, &[]);
+            return d;
+        }
         
         // 1. Update Sentinel Components
         // Feed real sentiment to VetoGate if available
@@ -301,40 +481,51 @@ impl OODACore {
             self.veto_gate.update_sentiment(s);
         }
         
-        // 2. Check Nuclear Veto (Double-Key)
-        // Need Omega Ratio. Calculating simplistic Omega or passing it.
-        // For now, assume Omega > 1.0 (Safe) unless we calculate it.
-        // If we want to test D-45, we need to pass a mock Omega.
-        // Let's assume passed in State or computed.
-        // Mocking Omega = 1.2 normally.
-        let mock_omega = 1.2; 
-        if self.veto_gate.check_hard_stop(physics, mock_omega) {
+        // 2. Initial Signal from the Strategy (default: Simons, Positive Acceleration = Buy)
+        // Vetoes, sizing and breakers below stay here regardless of which alpha is plugged in.
+        let ctx = PositionContext {
+            symbol: &self.symbol,
+            net_position: self.net_position,
+            regime: state.regime,
+        };
+        let strategy = self.strategies.select(&self.symbol, state.regime);
+        let strategy_name = strategy.name().to_string();
+        let signal = strategy.evaluate(state, &ctx);
+        let confidence = signal.confidence.clamp(0.0, 1.0);
+        let mut base_signal: f64 = signal.target_position.clamp(-1.0, 1.0);
+
+        // 3. Check Nuclear Veto (Double-Key)
+        // Omega comes from the forecast quantiles at the legislative hurdle (ORIENT), scored on
+        // the side the signal wants (a flat signal scores the side already held).
+        // Without a forecast the negative-EV key cannot be turned.
+        let side = if base_signal != 0.0 { base_signal } else { self.net_position };
+        let omega = state.omega_for(side).unwrap_or(f64::INFINITY);
+        let hard_stop = self.veto_gate.check_hard_stop(physics, omega);
+        let veto_onset = hard_stop && !self.veto_episode;
+        self.veto_episode = hard_stop;
+        if hard_stop {
+            // The ladder counts episodes, not the ticks a veto persists for
+            if veto_onset {
+                self.ladder.register_veto();
+            }
              let d = Decision {
                 action: Action::Halt,
                 reason: "NUCLEAR VETO: Sentiment + Physics Collapse".to_string(),
                 confidence: 1.0,
             };
-            self.log_forensics(state, &d, 0.0); // 0.0 risk
+            self.log_forensics(state, &d, &[]);
             return d;
         }
 
-        // 3. Update Provisional Executive
+        // 4. Update the Risk Ladder
         // Use real physics metrics for risk sizing
-        let entropy = physics.entropy;
-        let efficiency = physics.efficiency_index;
-        
-        let _promoted = self.provisional.update(physics, entropy, efficiency);
-        let max_risk = self.provisional.get_current_max_risk();
-
+        let _promoted = self.ladder.observe(physics);
+        let max_risk = self.ladder.position_size();
 
-        // 4. Initial Signal from Simons (Pattern/Physics)
-        // Simple heuristic: Positive Acceleration = Buy Signal
-        let mut base_signal: f64 = if physics.acceleration > 0.0 { 1.0 } else { -1.0 };
-        
         // 5. Apply Soft Veto (Qualitative Filter)
         // If we have a semantic score
         if let Some(sentiment) = state.sentiment_score {
-            // VETO: Physics says Buy (1.0), but Sentiment is Negative (< -0.5)
+            // VETO: Strategy says Buy (1.0), but Sentiment is Negative (< -0.5)
             // Note: This is separate from Nuclear Veto. This is just "Don't Buy".
             if base_signal > 0.0 && sentiment < -0.5 {
                  let d = Decision {
@@ -342,7 +533,7 @@ impl OODACore {
                     reason: format!("VETO: Hypatia Sentiment ({}) overruled Physics.", sentiment),
                     confidence: 1.0, 
                 };
-                self.log_forensics(state, &d, max_risk);
+                self.log_forensics(state, &d, &[]);
                 return d;
             }
         } else {
@@ -351,18 +542,39 @@ impl OODACore {
             base_signal *= 0.5; // Reduce sizing by half if flying blind
         }
         
-        // 6. Final Decision Construction w/ Provisional Sizing
-        let mut decision = if base_signal >= 0.5 {
+        // 6. Sizing Pipeline: Kelly -> Tier Cap -> Fidelity -> Aggression -> Risk Limits
+        self.sizing.max_qty = self.pre_trade.limits.max_qty;
+        self.sizing.max_notional = self.pre_trade.limits.max_notional;
+        self.sizing.capital_cap = self.allocation.as_ref().map(|a| a.cap_for(&strategy_name));
+        let sizing = self.sizing.size(&SizingInputs {
+            price: physics.price,
+            confidence,
+            conviction: base_signal.abs(),
+            tier_cap: max_risk,
+            aggression: legislation.aggression,
+            direction: base_signal,
+            net_position: self.net_position,
+        });
+        let qty = sizing.qty;
+
+        // 7. Final Decision Construction
+        let mut decision = if base_signal.abs() >= 0.5 && qty <= 0.0 {
+            Decision {
+                action: Action::Hold,
+                reason: "Sizing: Zero Quantity".to_string(),
+                confidence: 1.0,
+            }
+        } else if base_signal >= 0.5 {
             Decision {
-                action: Action::Buy(max_risk * base_signal.abs()), // Apply Provisional Limit
-                reason: format!("Physics & Sentiment Aligned. Risk Tier: {}", self.provisional.current_tier_index),
-                confidence: 0.9,
+                action: Action::Buy(qty),
+                reason: format!("{} & Sentiment Aligned. Risk Tier: {}", strategy_name, self.ladder.effective_tier()),
+                confidence,
             }
         } else if base_signal <= -0.5 {
             Decision {
-                action: Action::Sell(max_risk * base_signal.abs()), // Apply Provisional Limit
-                reason: "Physics Bearish".to_string(),
-                confidence: 0.9,
+                action: Action::Sell(qty),
+                reason: format!("{} Bearish", strategy_name),
+                confidence,
             }
         } else {
             Decision {
@@ -399,21 +611,69 @@ impl OODACore {
             StrategicBias::Neutral => {}
         }
 
+        // Drawdown Breakers / Operator: Reduce-Only / Halt (ReduceSize is applied in the sizing pipeline)
+        let reduce_only = match self.drawdown_action {
+            BreakerAction::Halt => {
+                decision = Decision {
+                    action: Action::Halt,
+                    reason: "Drawdown Breaker: Halt".to_string(),
+                    confidence: 1.0,
+                };
+                None
+            }
+            BreakerAction::ReduceOnly => Some("Drawdown Breaker: Reduce-Only"),
+            BreakerAction::ReduceSize | BreakerAction::None => self
+                .reduce_only
+                .then_some("Operator: Reduce-Only")
+                .or(self.session_taper.map(|_| "Session: Taper (Reduce-Only)")),
+        };
+        if let Some(reason) = reduce_only {
+            let position = self.net_position;
+            decision.action = match decision.action {
+                Action::Buy(q) if position < 0.0 => Action::Buy(q.min(-position)),
+                Action::Sell(q) if position > 0.0 => Action::Sell(q.min(position)),
+                Action::Buy(_) | Action::Sell(_) => {
+                    decision.reason = reason.to_string();
+                    Action::Hold
+                }
+                other => other,
+            };
+        }
+
+        // Trading schedule: the position shrinks in step with the time left before the close
+        match self.session_taper {
+            Some(taper) if decision.action != Action::Halt => {
+                let anchor = *self.taper_anchor.get_or_insert(self.net_position.abs());
+                let allowed = anchor * taper.clamp(0.0, 1.0);
+                let position = self.net_position.abs();
+                if position > allowed {
+                    decision = Decision {
+                        action: Action::Reduce(1.0 - allowed / position),
+                        reason: format!("Session Taper: {:.0}% of position until close", taper * 100.0),
+                        confidence: 1.0,
+                    };
+                }
+            }
+            Some(_) => {}
+            None => self.taper_anchor = None,
+        }
 
-        self.log_forensics(state, &decision, max_risk);
+        self.log_forensics(state, &decision, &sizing.stages);
         decision
     }
 
-    fn log_forensics(&self, state: &OODAState, decision: &Decision, _max_risk: f64) {
+    fn log_forensics(&self, state: &OODAState, decision: &Decision, sizing: &[SizingStage]) {
         let mut packet = DecisionPacket {
             timestamp: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs_f64(),
             trace_id: state.trace_id.clone(),
             physics: state.physics.clone(),
             sentiment: state.sentiment_score.unwrap_or(0.0),
             vector_distance: state.vector_distance.unwrap_or(0.0),
-            quantile_score: self.provisional.current_tier_index as i32,
+            quantile_score: self.ladder.effective_tier().index() as i32,
             decision: format!("{:?}", decision.action),
             operator_hash: String::new(),
+            sizing: sizing.to_vec(),
+            omega: state.omega,
         };
         packet.seal();
         
@@ -439,20 +699,93 @@ impl OODACore {
         }
     }
 
+    /// Forensic record of what ACT did (trim, flatten, latch, resume) on the last decided trace.
+    /// Scribe only: the Mirror and Decay Monitor compare decisions, not executions.
+    fn log_execution(&self, event: String) {
+        let (trace_id, physics) = &self.last_trace;
+        let mut packet = DecisionPacket {
+            timestamp: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs_f64(),
+            trace_id: trace_id.clone(),
+            physics: *physics,
+            sentiment: 0.0,
+            vector_distance: 0.0,
+            quantile_score: self.ladder.effective_tier().index() as i32,
+            decision: event,
+            operator_hash: String::new(),
+            sizing: Vec::new(),
+            omega: None,
+        };
+        packet.seal();
+        if let Some(tx) = &self.forensic_tx {
+            if let Err(e) = tx.try_send(packet) {
+                tracing::warn!("⚠️ Forensic Log Dropped (Channel Full): {}", e);
+            }
+        }
+    }
+
+    /// Fills seen since the last call, for the Audit Loop's slippage drift.
+    pub fn drain_fills(&mut self) -> Vec<TradeResult> {
+        std::mem::take(&mut self.fills)
+    }
+
+    /// Brain context schema version currently refused by the firewall, if any.
+    pub fn brain_schema_mismatch(&self) -> Option<u32> {
+        self.schema_mismatch
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halt.is_some()
+    }
+
+    pub fn halt_latch(&self) -> Option<&HaltLatch> {
+        self.halt.as_ref()
+    }
+
+    /// Operator resume: the only way out of a latched Halt.
+    pub fn resume(&mut self, operator: &str) -> bool {
+        match self.halt.take() {
+            Some(latch) => {
+                tracing::warn!("▶️ HALT RELEASED by {} (was: {})", operator, latch.reason);
+                self.log_execution(format!("Resume (operator: {}, was: {})", operator, latch.reason));
+                true
+            }
+            None => false,
+        }
+    }
+
     /// DECIDE -> ACT
-    /// Atomic execution (mocked)
-    pub fn act(&mut self, decision: Decision, current_price: f64) {
+    /// Atomic execution (mocked). Buy/Sell go out on the binary hot path; Reduce/Halt
+    /// return the risk-off order for the execution layer.
+    pub fn act(&mut self, decision: Decision, current_price: f64) -> Option<RiskOffOrder> {
+         if let Some(latch) = &self.halt {
+             if decision.action != Action::Hold {
+                 tracing::warn!("🛑 HALT LATCHED ({}): {:?} suppressed", latch.reason, decision.action);
+             }
+             return None;
+         }
+
          // D-92: Shadow Mode Hook
          // We submit every decision to the Shadow Gate for virtual execution
-         self.shadow_gate.submit_order(&decision, current_price);
+         if let Some(id) = self.shadow_gate.submit_order(&decision, current_price) {
+             if let Action::Buy(qty) | Action::Sell(qty) = decision.action {
+                 let side = if matches!(decision.action, Action::Buy(_)) { Side::Buy } else { Side::Sell };
+                 self.pre_trade.add_resting(RestingOrder { id, side, price: current_price, qty });
+             }
+         }
          
          // D-94: ADAPTIVE LATENCY HARVEST (The Shortcut)
          // Hot-Path Zero-Copy Serialization
          match decision.action {
              Action::Buy(qty) => {
+                 // Pre-Trade Sanity: price band, size, rate, duplicate, self-trade
+                 if self.pre_trade.check(Side::Buy, current_price, qty).is_err() {
+                     return None;
+                 }
                  // D-94 Part C: Late-Check Veto
                  if self.sync_gate.check_late_l1(current_price) {
                      let _packet = self.binary_packer.pack_buy(current_price, qty);
+                     // D-95: Attribute the order to the adapter that shaped the context
+                     self.ensemble_manager.record_decision(Side::Buy, qty, current_price);
                      // In prod: unsafe { socket.send(_packet) };
                      // tracing::info!("⚡ SENT BINARY BUY: {} bytes", _packet.len());
                  } else {
@@ -460,23 +793,68 @@ impl OODACore {
                  }
              },
              Action::Sell(qty) => {
+                 if self.pre_trade.check(Side::Sell, current_price, qty).is_err() {
+                     return None;
+                 }
                  if self.sync_gate.check_late_l1(current_price) {
                      let _packet = self.binary_packer.pack_sell(current_price, qty);
+                     self.ensemble_manager.record_decision(Side::Sell, qty, current_price);
                      // In prod: unsafe { socket.send(_packet) };
                      // tracing::info!("⚡ SENT BINARY SELL: {} bytes", _packet.len());
                  } else {
                      tracing::warn!("⛔ D-94 PRE-FLIGHT ABORT: Price Moved");
                  }
              },
-             _ => {}
+             Action::Reduce(fraction) => return self.reduce(fraction, current_price),
+             Action::Halt => return self.halt(&decision.reason, current_price),
+             Action::Hold => {}
          }
-
-        if let Action::Halt = decision.action {
-            // In prod: Panic / Kill Switch
-            println!("!!! SYSTEM SUPER-HALT !!!");
-        }
         // In prod: send to Order Gateway
         // println!("ACT: {:?}", decision); 
+        None
+    }
+
+    /// Trim `fraction` (0-1] of the current position.
+    fn reduce(&mut self, fraction: f64, price: f64) -> Option<RiskOffOrder> {
+        let position = self.net_position;
+        if position == 0.0 || fraction.is_nan() || fraction <= 0.0 {
+            return None;
+        }
+        let side = if position > 0.0 { Side::Sell } else { Side::Buy };
+        let qty = position.abs() * fraction.min(1.0);
+        // Risk-off: only sanity and the price band; size, rate and duplicate limits would strand the trim
+        if self.pre_trade.check_risk_off(side, price, qty).is_err() {
+            return None;
+        }
+        tracing::warn!("✂️ REDUCE: {} {:.6} ({:.0}% of {:.6})", side, qty, fraction.min(1.0) * 100.0, position);
+        self.log_execution(format!("Reduce({}) -> Trim {} {:.6}", fraction, side, qty));
+        Some(RiskOffOrder::Trim(TradeProposal { side, price, qty }))
+    }
+
+    /// D-86 CANCEL_ALL: drop every working order, leave positions alone.
+    pub fn cancel_working_orders(&mut self) -> usize {
+        let cancelled = self.shadow_gate.cancel_pending();
+        self.pre_trade.clear_resting();
+        cancelled
+    }
+
+    /// Cancel working orders, latch, and flatten whatever we hold.
+    fn halt(&mut self, reason: &str, price: f64) -> Option<RiskOffOrder> {
+        let cancelled = self.cancel_working_orders();
+        self.halt = Some(HaltLatch { reason: reason.to_string(), since: self.clock.now() });
+        tracing::error!("🛑 SYSTEM SUPER-HALT: {} (cancelled {}). Operator resume required.", reason, cancelled);
+
+        let position = self.net_position;
+        let flatten = (position != 0.0).then(|| TradeProposal {
+            side: if position > 0.0 { Side::Sell } else { Side::Buy },
+            price,
+            qty: position.abs(),
+        });
+        self.log_execution(match &flatten {
+            Some(p) => format!("Halt -> Cancelled {} | Flatten {} {:.6} | LATCHED", cancelled, p.side, p.qty),
+            None => format!("Halt -> Cancelled {} | Flat | LATCHED", cancelled),
+        });
+        flatten.map(|p| RiskOffOrder::Flatten(p, reason.to_string()))
     }
 }
 
@@ -507,13 +885,13 @@ mod tests {
 
         // Standard Orient (Simulated)
 <<<<<<< HEAD
-        let state = core.orient(physics, 0, None, "Neutral".to_string()).await;
+        let state = core.orient(physics, MarketRegime::Unknown, None, "Neutral".to_string()).await;
         
         // Decide
         let legislation = LegislativeState::default();
         let decision = core.decide(&state, &legislation);
 =======
-        let state = core.orient(physics, 0, None, "NEUTRAL".to_string()).await;
+        let state = core.orient(physics, MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
         
         // Decide
         let decision = core.decide(&state, &crate::governor::legislator::LegislativeState::default());
@@ -551,6 +929,10 @@ mod tests {
             oriented_at: Instant::now(),
             trace_id: "test_trace".to_string(),
             brain_latency: None,
+            regime: MarketRegime::Unknown,
+            forecast: None,
+            omega: None,
+            omega_short: None,
         };
 
 <<<<<<< HEAD
@@ -571,6 +953,282 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_nuclear_veto_uses_forecast_omega() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+
+        // Panic narrative + kinetic chaos: the third key is the forecast's Omega
+        let physics = PhysicsState { price: 100.0, acceleration: 1.0, jerk: 60.0, ..Default::default() };
+        let fragile = ForecastQuantiles::new(80.0, 99.0, 102.0, ForecastSource::Brain).unwrap();
+        let omega = fragile.omega(physics.price, 0.05);
+        let omega_short = fragile.omega_short(physics.price, 0.05);
+        assert!(omega < 1.0 && omega_short > 1.0);
+
+        let mut state = OODAState {
+            physics,
+            sentiment_score: Some(-1.0),
+            forecast: Some(fragile),
+            omega: Some(omega),
+            omega_short: Some(omega_short),
+            ..Default::default()
+        };
+        let decision = core.decide(&state, &LegislativeState::default());
+        assert_eq!(decision.action, Action::Halt);
+
+        // A veto held over many ticks is one episode for the ladder
+        for _ in 0..10 {
+            assert_eq!(core.decide(&state, &LegislativeState::default()).action, Action::Halt);
+        }
+        assert!(!core.ladder.is_in_cooldown());
+
+        // Selling into the same left skew is positive EV: the short side is scored
+        let mut short = state.clone();
+        short.physics.acceleration = -1.0;
+        assert_ne!(core.decide(&short, &LegislativeState::default()).action, Action::Halt);
+
+        // No forecast: the EV key stays unturned
+        state.forecast = None;
+        state.omega = None;
+        state.omega_short = None;
+        let decision = core.decide(&state, &LegislativeState::default());
+        assert_ne!(decision.action, Action::Halt);
+    }
+
+    #[tokio::test]
+    async fn test_realized_forecast_fallback() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        // The tail samples once per forecast horizon, not per tick
+        let clock = std::sync::Arc::new(crate::clock::ManualClock::new());
+        core.clock = clock.clone();
+
+        // Not enough history: no forecast, no Omega
+        let state = core.orient(PhysicsState { price: 100.0, ..Default::default() }, MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
+        assert!(state.forecast.is_none() && state.omega.is_none());
+
+        for i in 0..60 {
+            let price = 100.0 + if i % 2 == 0 { 1.0 } else { -0.5 } + i as f64 * 0.01;
+            core.orient(PhysicsState { price, ..Default::default() }, MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
+            core.orient(PhysicsState { price: price * 1.5, ..Default::default() }, MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
+            clock.advance(core.tail.horizon);
+        }
+        assert_eq!(core.tail.len(), 59);
+        let state = core.orient(PhysicsState { price: 100.5, ..Default::default() }, MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
+        let forecast = state.forecast.expect("realized fallback");
+        assert_eq!(forecast.source, ForecastSource::Realized);
+        assert!(forecast.p10 < 100.5 && forecast.p90 > 100.5);
+        assert_eq!(state.omega, Some(forecast.omega(100.5, core.hurdle_rate)));
+        assert_eq!(state.omega_short, Some(forecast.omega_short(100.5, core.hurdle_rate)));
+    }
+
+    #[tokio::test]
+    async fn test_strategy_swap_keeps_vetoes() {
+        use crate::governor::strategy::KeplerReversion;
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        core.strategies.register_regime(MarketRegime::Turbulent, Box::new(KeplerReversion));
+
+        // Decelerating rally: Simons sells, Kepler fades it as well
+        let mut state = OODAState {
+            physics: PhysicsState { price: 50000.0, velocity: 10.0, acceleration: -5.0, ..Default::default() },
+            regime: MarketRegime::Turbulent,
+            ..Default::default()
+        };
+        let decision = core.decide(&state, &LegislativeState::default());
+        assert!(matches!(decision.action, Action::Sell(_)), "Got: {:?}", decision);
+        assert!(decision.reason.starts_with("Kepler"));
+
+        // Selloff losing steam: Kepler goes long, and ShortOnly legislation still vetoes it
+        state.physics.velocity = -10.0;
+        state.physics.acceleration = 5.0;
+        let legislation = LegislativeState {
+            bias: crate::governor::legislator::StrategicBias::ShortOnly,
+            ..Default::default()
+        };
+        let decision = core.decide(&state, &legislation);
+        assert_eq!(decision.action, Action::Hold);
+        assert!(decision.reason.contains("Short Only"));
+    }
+
+    #[tokio::test]
+    async fn test_operator_reduce_only_and_cancel_all() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        let bullish = OODAState {
+            physics: PhysicsState { price: 50000.0, acceleration: 5.0, ..Default::default() },
+            sentiment_score: Some(0.9),
+            ..Default::default()
+        };
+
+        // Long already: a Buy would grow it
+        core.reduce_only = true;
+        core.net_position = 0.5;
+        let decision = core.decide(&bullish, &LegislativeState::default());
+        assert_eq!(decision.action, Action::Hold);
+        assert_eq!(decision.reason, "Operator: Reduce-Only");
+
+        // Short: the Buy covers, clipped to the position
+        core.net_position = -0.001;
+        match core.decide(&bullish, &LegislativeState::default()).action {
+            Action::Buy(q) => assert!(q <= 0.001 + 1e-12),
+            other => panic!("Expected clipped Buy, got {:?}", other),
+        }
+
+        // Cancel-all drops working orders but neither flattens nor latches
+        let buy = Decision { action: Action::Buy(0.1), reason: "Test".to_string(), confidence: 0.9 };
+        core.act(buy, 50000.0);
+        assert_eq!(core.cancel_working_orders(), 1);
+        assert!(core.shadow_gate.virtual_book.values().all(|o| o.status == crate::sequencer::shadow_gate::ShadowStatus::Cancelled));
+        assert_eq!(core.net_position, -0.001);
+        assert!(!core.is_halted());
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_credits_sent_orders_only() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        let bullish = OODAState {
+            physics: PhysicsState { price: 50000.0, acceleration: 5.0, ..Default::default() },
+            sentiment_score: Some(0.9),
+            ..Default::default()
+        };
+        let adapter = core.ensemble_manager.get_active_adapter();
+        let sent = |core: &OODACore| core.ensemble_manager.performance(&adapter).map_or(0, |p| p.decisions);
+        core.pre_trade.set_reference_price(50000.0);
+
+        // Deciding alone credits nothing; the order going out does
+        let decision = core.decide(&bullish, &LegislativeState::default());
+        assert!(matches!(decision.action, Action::Buy(_)));
+        assert_eq!(sent(&core), 0);
+        core.act(decision.clone(), 50000.0);
+        assert_eq!(sent(&core), 1);
+
+        // Suppressed by the halt latch: not credited
+        core.halt = Some(HaltLatch { reason: "Test".to_string(), since: core.clock.now() });
+        core.act(decision, 50000.0);
+        assert_eq!(sent(&core), 1);
+    }
+
+    #[tokio::test]
+    async fn test_session_taper_trims_towards_close() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        let bullish = OODAState {
+            physics: PhysicsState { price: 50000.0, acceleration: 5.0, ..Default::default() },
+            sentiment_score: Some(0.9),
+            ..Default::default()
+        };
+
+        // Taper starts with 0.4 held: nothing to trim yet, but no adding either
+        core.net_position = 0.4;
+        core.session_taper = Some(1.0);
+        let decision = core.decide(&bullish, &LegislativeState::default());
+        assert_eq!((decision.action, decision.reason.as_str()), (Action::Hold, "Session: Taper (Reduce-Only)"));
+
+        // Halfway to the close: trim to 0.2 (half of what we hold)
+        core.session_taper = Some(0.5);
+        match core.decide(&bullish, &LegislativeState::default()).action {
+            Action::Reduce(f) => assert!((f - 0.5).abs() < 1e-12),
+            other => panic!("Expected Reduce, got {:?}", other),
+        }
+        core.net_position = 0.2;
+        core.session_taper = Some(0.0);
+        assert_eq!(core.decide(&bullish, &LegislativeState::default()).action, Action::Reduce(1.0));
+
+        // Next session: anchor forgotten, normal trading
+        core.net_position = 0.0;
+        core.session_taper = None;
+        assert!(matches!(core.decide(&bullish, &LegislativeState::default()).action, Action::Buy(_)));
+        assert!(core.taper_anchor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reduce_trims_position() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        core.pre_trade.set_reference_price(50000.0);
+
+        let reduce = |f| Decision { action: Action::Reduce(f), reason: "Risk-Off".to_string(), confidence: 1.0 };
+        assert!(core.act(reduce(0.5), 50000.0).is_none(), "Nothing to trim when flat");
+
+        core.net_position = -0.4;
+        match core.act(reduce(0.5), 50000.0) {
+            Some(RiskOffOrder::Trim(p)) => {
+                assert_eq!(p.side, Side::Buy);
+                assert!((p.qty - 0.2).abs() < 1e-12);
+            }
+            other => panic!("Expected Trim, got {:?}", other),
+        }
+        // A repeat trim beyond max_qty is not stranded by the size or duplicate limits
+        core.net_position = -2.0 * core.pre_trade.limits.max_qty;
+        assert!(matches!(core.act(reduce(1.0), 50000.0), Some(RiskOffOrder::Trim(_))));
+        assert!(matches!(core.act(reduce(1.0), 50000.0), Some(RiskOffOrder::Trim(_))));
+        assert!(!core.is_halted());
+    }
+
+    #[tokio::test]
+    async fn test_shadow_orders_rest_against_self_trade() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        core.pre_trade.set_reference_price(50000.0);
+
+        let buy = Decision { action: Action::Buy(0.1), reason: "Test".to_string(), confidence: 0.9 };
+        let sell = Decision { action: Action::Sell(0.1), reason: "Test".to_string(), confidence: 0.9 };
+        core.act(buy, 50000.0);
+        core.act(sell.clone(), 49990.0);
+        let reject = core.pre_trade.rejections().last().map(|r| r.reason.clone());
+        assert!(matches!(reject, Some(crate::gateway::pre_trade::PreTradeReject::SelfTrade { .. })), "{:?}", reject);
+
+        // Cancelled orders stop resting
+        core.cancel_working_orders();
+        let rejected = core.pre_trade.rejections().count();
+        core.act(sell, 49980.0);
+        assert_eq!(core.pre_trade.rejections().count(), rejected);
+    }
+
+    #[tokio::test]
+    async fn test_halt_cancels_flattens_and_latches() {
+        let store = RedisStateStore::new("redis://127.0.0.1:6379/").await.unwrap();
+        let mut core = OODACore::new("BTC-USDT".to_string(), None, None, None, store);
+        let clock = std::sync::Arc::new(crate::clock::ManualClock::new());
+        core.clock = clock.clone();
+
+        let buy = Decision { action: Action::Buy(0.1), reason: "Test".to_string(), confidence: 0.9 };
+        core.act(buy.clone(), 50000.0);
+        core.net_position = 0.3;
+
+        let halt = Decision { action: Action::Halt, reason: "NUCLEAR VETO".to_string(), confidence: 1.0 };
+        match core.act(halt, 50000.0) {
+            Some(RiskOffOrder::Flatten(p, reason)) => {
+                assert_eq!(p.side, Side::Sell);
+                assert_eq!(p.qty, 0.3);
+                assert_eq!(reason, "NUCLEAR VETO");
+            }
+            other => panic!("Expected Flatten, got {:?}", other),
+        }
+        assert!(core.shadow_gate.virtual_book.values().all(|o| o.status == crate::sequencer::shadow_gate::ShadowStatus::Cancelled));
+        assert!(core.is_halted());
+        assert_eq!(core.halt_latch().unwrap().since, crate::clock::Clock::now(clock.as_ref()));
+
+        // Latched: DECIDE holds and ACT sends nothing, whatever the signal
+        let state = OODAState {
+            physics: PhysicsState { price: 50000.0, acceleration: 1.0, ..Default::default() },
+            sentiment_score: Some(0.9),
+            ..Default::default()
+        };
+        let decision = core.decide(&state, &LegislativeState::default());
+        assert_eq!(decision.action, Action::Hold);
+        assert!(decision.reason.starts_with("HALT LATCHED"));
+        assert!(core.act(buy.clone(), 50000.0).is_none());
+        assert_eq!(core.shadow_gate.virtual_book.len(), 1);
+
+        assert!(core.resume("ops@desk"));
+        assert!(!core.is_halted());
+        assert!(!core.resume("ops@desk"));
+        assert_ne!(core.decide(&state, &LegislativeState::default()).action, Action::Hold);
+    }
+
     #[tokio::test]
     async fn test_cycle_latency() {
 <<<<<<< HEAD
@@ -593,10 +1251,10 @@ mod tests {
         for _ in 0..10_000 {
             // Using logic internal simulation for speed test
 <<<<<<< HEAD
-            let state = core.orient(physics.clone(), 0, None, "Neutral".to_string()).await;
+            let state = core.orient(physics.clone(), MarketRegime::Unknown, None, "Neutral".to_string()).await;
             let dec = core.decide(&state, &legislation);
 =======
-            let state = core.orient(physics.clone(), 0, None, "NEUTRAL".to_string()).await;
+            let state = core.orient(physics.clone(), MarketRegime::Unknown, None, "NEUTRAL".to_string()).await;
             let dec = core.decide(&state, &crate::governor::legislator::LegislativeState::default());
 >>>>>>> feb49d06 (pushing local changes.)
             core.act(dec, physics.price);
@@ -4,21 +4,29 @@ use tracing::{info, warn, error};
 // D-90: Recursive Risk Re-balancer (The Governor)
 pub struct Rebalancer {
     pub fidelity: f64,
-    start_equity: f64,
+    start_equity: Option<f64>, // Anchored on the first venue sync
     slippage_window: VecDeque<f64>, // Stores delta % (Actual - Expected) / Expected
     max_mdd_percent: f64,
 }
 
 impl Rebalancer {
-    pub fn new(start_equity: f64) -> Self {
+    pub fn new() -> Self {
         Self {
             fidelity: 1.0,
-            start_equity,
+            start_equity: None,
             slippage_window: VecDeque::with_capacity(10),
             max_mdd_percent: 0.15, // 15% Max Session Drawdown
         }
     }
 
+    /// Anchor the session start equity (first synced venue equity; later calls are no-ops).
+    pub fn anchor(&mut self, equity: f64) {
+        if self.start_equity.is_none() && equity > 0.0 {
+            info!("⚖️ REBALANCER: Session equity anchored at ${:.2}", equity);
+            self.start_equity = Some(equity);
+        }
+    }
+
     /// Update Fidelity based on Nullification (Punish)
     pub fn punish_nullification(&mut self) {
         self.fidelity = (self.fidelity - 0.05).max(0.0);
@@ -32,11 +40,16 @@ impl Rebalancer {
 
     /// Calculate Adjusted Size
     pub fn get_safe_size(&self, standard_size: f64) -> f64 {
-        if self.fidelity < 0.5 {
-            warn!("🛑 FIDELITY CRITICAL (F={:.2} < 0.5). OBSERVATION MODE LOCKED.", self.fidelity);
+        Self::scale_by_fidelity(self.fidelity, standard_size)
+    }
+
+    /// Fidelity scaling shared with the sizing pipeline (observation lock below 0.5).
+    pub fn scale_by_fidelity(fidelity: f64, standard_size: f64) -> f64 {
+        if fidelity < 0.5 {
+            warn!("🛑 FIDELITY CRITICAL (F={:.2} < 0.5). OBSERVATION MODE LOCKED.", fidelity);
             return 0.0;
         }
-        standard_size * self.fidelity
+        standard_size * fidelity
     }
 
     /// Record a trade fill and check slippage anomalies
@@ -68,8 +81,12 @@ impl Rebalancer {
 
     /// Check Omega Kill-Switch (Session Drawdown)
     /// Returns true if OMEGA Triggered (KILL)
+    /// Never triggers before the session equity is anchored.
     pub fn check_omega(&self, current_equity: f64) -> bool {
-        let drawdown = (self.start_equity - current_equity) / self.start_equity;
+        let Some(start_equity) = self.start_equity else {
+            return false;
+        };
+        let drawdown = (start_equity - current_equity) / start_equity;
         if drawdown > self.max_mdd_percent {
             error!("💀 OMEGA KILL-SWITCH TRIGGERED: Drawdown {:.2}% > Max {:.2}%", drawdown * 100.0, self.max_mdd_percent * 100.0);
             return true;
@@ -77,3 +94,9 @@ impl Rebalancer {
         false
     }
 }
+
+impl Default for Rebalancer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,23 +1,103 @@
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+use crate::feynman::PhysicsState;
+use crate::governor::regime_hmm::{self, GaussianHmm, HmmFilter};
+
+/// The canonical market regime. `id()` is the wire value everywhere
+/// (Truth Envelope, Firewall, SyncGate, `brain.Regime` in the protos).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum MarketRegime {
-    /// Regime 0: Sharp, singular peak in |ψ|^2. Consistent Phase Gradient.
+    /// Regime 0: Not classified yet (or the classifier lost track).
+    #[default]
+    Unknown,
+
+    /// Regime 1: Sharp, singular peak in |ψ|^2. Consistent Phase Gradient.
     /// Safety Staircase allowed to Level Up.
-    Laminar, 
-    
-    /// Regime 1: Multiple peaks, rapid oscillations. 
+    Laminar,
+
+    /// Regime 2: Multiple peaks, rapid oscillations.
     /// Safety Staircase locked. Mean-Reversion logic.
     Turbulent,
-    
-    /// Regime 2: Wave function spreads across price axis. Zero identifiable phase.
-    /// Soft Veto triggers. Exit all positions.
+
+    /// Regime 3: Wave function spreads across price axis. Zero identifiable phase.
+    /// Soft Veto triggers. Exit all positions. (Formerly "Violent".)
     Decoherent,
 }
 
+impl MarketRegime {
+    pub const ALL: [MarketRegime; 4] =
+        [MarketRegime::Unknown, MarketRegime::Laminar, MarketRegime::Turbulent, MarketRegime::Decoherent];
+
+    pub fn id(self) -> u8 {
+        match self {
+            MarketRegime::Unknown => 0,
+            MarketRegime::Laminar => 1,
+            MarketRegime::Turbulent => 2,
+            MarketRegime::Decoherent => 3,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Self {
+        match id {
+            1 => MarketRegime::Laminar,
+            2 => MarketRegime::Turbulent,
+            3 => MarketRegime::Decoherent,
+            _ => MarketRegime::Unknown,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MarketRegime::Unknown => "UNKNOWN",
+            MarketRegime::Laminar => "LAMINAR",
+            MarketRegime::Turbulent => "TURBULENT",
+            MarketRegime::Decoherent => "DECOHERENT",
+        }
+    }
+
+    /// Turbulent/Decoherent: tighter staleness budgets, no Staircase promotion.
+    pub fn is_high_entropy(self) -> bool {
+        matches!(self, MarketRegime::Turbulent | MarketRegime::Decoherent)
+    }
+}
+
+impl fmt::Display for MarketRegime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MarketRegime {
+    type Err = String;
+
+    /// Case-insensitive name (legacy "VIOLENT" is Decoherent) or numeric id.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_uppercase();
+        match name.as_str() {
+            "UNKNOWN" | "0" => Ok(MarketRegime::Unknown),
+            "LAMINAR" | "1" => Ok(MarketRegime::Laminar),
+            "TURBULENT" | "2" => Ok(MarketRegime::Turbulent),
+            "DECOHERENT" | "VIOLENT" | "3" => Ok(MarketRegime::Decoherent),
+            _ => Err(format!("Unknown regime '{}'", s)),
+        }
+    }
+}
+
+/// Snapshot classifier behind the hysteresis.
+pub enum RegimeModel {
+    /// Coherence/entropy thresholds (Directive-65).
+    Thresholds,
+    /// Gaussian HMM trained offline over physics features, filtered online.
+    Hmm(HmmFilter),
+}
+
 pub struct RegimeDetector {
     current_regime: MarketRegime,
-    
+    model: RegimeModel,
+
     // Hysteresis State
     pending_regime: Option<MarketRegime>,
     confirmation_counter: u32,
@@ -28,12 +108,21 @@ impl RegimeDetector {
     pub fn new(required_confirmations: u32) -> Self {
         Self {
             current_regime: MarketRegime::Laminar, // Default to optimistic, Staircase checks will catch up
+            model: RegimeModel::Thresholds,
             pending_regime: None,
             confirmation_counter: 0,
             required_confirmations,
         }
     }
 
+    pub fn with_hmm(required_confirmations: u32, hmm: GaussianHmm) -> Self {
+        Self { model: RegimeModel::Hmm(HmmFilter::new(hmm)), ..Self::new(required_confirmations) }
+    }
+
+    pub fn model(&self) -> &RegimeModel {
+        &self.model
+    }
+
     pub fn current_regime(&self) -> MarketRegime {
         self.current_regime
     }
@@ -48,6 +137,16 @@ impl RegimeDetector {
         self.apply_hysteresis(raw_regime)
     }
 
+    /// Classify a physics snapshot with the configured model, then apply hysteresis.
+    pub fn observe(&mut self, physics: &PhysicsState) -> MarketRegime {
+        let raw_regime = if let RegimeModel::Hmm(filter) = &mut self.model {
+            filter.step(&regime_hmm::features(physics))
+        } else {
+            self.classify_snapshot(physics.efficiency_index, physics.entropy)
+        };
+        self.apply_hysteresis(raw_regime)
+    }
+
     fn classify_snapshot(&self, coherence: f64, entropy: f64) -> MarketRegime {
         // Thresholds based on Directive-65 requirements
         // Laminar: High Coherence, Low Entropy
@@ -62,7 +161,7 @@ impl RegimeDetector {
             return MarketRegime::Laminar;
         }
 
-        // Default to Turbulent (Regime 1) for mixed signals
+        // Default to Turbulent (Regime 2) for mixed signals
         MarketRegime::Turbulent
     }
 
@@ -157,4 +256,33 @@ mod tests {
         assert_eq!(detector.confirmation_counter, 0, "Counter should reset");
         assert_eq!(detector.pending_regime, None, "Pending should clear");
     }
+
+    #[test]
+    fn test_canonical_ids_and_names() {
+        for regime in MarketRegime::ALL {
+            assert_eq!(MarketRegime::from_id(regime.id()), regime);
+            assert_eq!(regime.as_str().parse::<MarketRegime>(), Ok(regime));
+        }
+        assert_eq!("violent".parse::<MarketRegime>(), Ok(MarketRegime::Decoherent));
+        assert_eq!(MarketRegime::from_id(9), MarketRegime::Unknown);
+        assert!("Liquidity Crisis 2020".parse::<MarketRegime>().is_err());
+    }
+
+    #[test]
+    fn test_hmm_model_keeps_hysteresis() {
+        // Two well-separated states: calm (Laminar) and chaotic (Decoherent)
+        let hmm = GaussianHmm {
+            initial: vec![0.5, 0.5],
+            transition: vec![vec![0.9, 0.1], vec![0.1, 0.9]],
+            means: vec![vec![0.8, 0.2, 0.5], vec![0.1, 0.9, 5.0]],
+            variances: vec![vec![0.01; 3], vec![0.01; 3]],
+            labels: vec![MarketRegime::Laminar, MarketRegime::Decoherent],
+        };
+        let mut detector = RegimeDetector::with_hmm(3, hmm);
+        let chaos = PhysicsState { efficiency_index: 0.1, entropy: 0.9, jerk: 5.0_f64.exp_m1(), ..Default::default() };
+
+        assert_eq!(detector.observe(&chaos), MarketRegime::Laminar);
+        assert_eq!(detector.observe(&chaos), MarketRegime::Laminar);
+        assert_eq!(detector.observe(&chaos), MarketRegime::Decoherent);
+    }
 }
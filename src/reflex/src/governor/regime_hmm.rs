@@ -0,0 +1,367 @@
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::feynman::PhysicsState;
+use crate::governor::regime_detector::MarketRegime;
+
+/// Feature vector the HMM is trained on and filters with. Order matters:
+/// [efficiency_index (coherence), entropy, ln(1 + |jerk|)].
+pub const N_FEATURES: usize = 3;
+const ENTROPY: usize = 1; // Feature used to order (and label) the states
+const MIN_VARIANCE: f64 = 1e-6;
+const MIN_OBSERVATIONS_PER_STATE: usize = 10;
+
+pub fn features(physics: &PhysicsState) -> [f64; N_FEATURES] {
+    [physics.efficiency_index, physics.entropy, physics.jerk.abs().ln_1p()]
+}
+
+#[derive(Debug)]
+pub enum HmmError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for HmmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HmmError::Io(e) => write!(f, "HMM model I/O: {}", e),
+            HmmError::Format(e) => write!(f, "HMM model format: {}", e),
+            HmmError::Invalid(msg) => write!(f, "HMM model invalid: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for HmmError {}
+
+/// Gaussian Hidden Markov Model (diagonal covariance) over physics features.
+/// Trained offline (Baum-Welch) from history, loaded at startup, filtered online.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GaussianHmm {
+    pub initial: Vec<f64>,
+    pub transition: Vec<Vec<f64>>, // transition[i][j] = P(j | i)
+    pub means: Vec<Vec<f64>>,
+    pub variances: Vec<Vec<f64>>,
+    pub labels: Vec<MarketRegime>, // Canonical regime of each hidden state
+}
+
+impl GaussianHmm {
+    pub fn n_states(&self) -> usize {
+        self.initial.len()
+    }
+
+    pub fn validate(&self) -> Result<(), HmmError> {
+        let n = self.n_states();
+        let invalid = |msg: &str| Err(HmmError::Invalid(msg.to_string()));
+        if n < 2 {
+            return invalid("need at least 2 states");
+        }
+        if self.transition.len() != n || self.transition.iter().any(|row| row.len() != n) {
+            return invalid("transition matrix is not n x n");
+        }
+        if self.means.len() != n || self.variances.len() != n || self.labels.len() != n {
+            return invalid("means/variances/labels do not match the state count");
+        }
+        if self.means.iter().chain(&self.variances).any(|v| v.len() != N_FEATURES) {
+            return invalid("feature dimension mismatch");
+        }
+        if self.variances.iter().flatten().any(|v| !(v.is_finite() && *v > 0.0)) {
+            return invalid("variances must be finite and positive");
+        }
+        let stochastic = |row: &[f64]| {
+            row.iter().all(|p| p.is_finite() && *p >= 0.0) && (row.iter().sum::<f64>() - 1.0).abs() < 1e-6
+        };
+        if !stochastic(&self.initial) || !self.transition.iter().all(|row| stochastic(row)) {
+            return invalid("initial/transition rows must be probability distributions");
+        }
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HmmError> {
+        let raw = std::fs::read_to_string(path).map_err(HmmError::Io)?;
+        let model: Self = serde_json::from_str(&raw).map_err(HmmError::Format)?;
+        model.validate()?;
+        Ok(model)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HmmError> {
+        let raw = serde_json::to_string_pretty(self).map_err(HmmError::Format)?;
+        std::fs::write(path, raw).map_err(HmmError::Io)
+    }
+
+    /// Emission likelihoods for one observation, rescaled so the largest is 1.0
+    /// (the scale cancels in every normalisation below).
+    fn emissions(&self, x: &[f64]) -> Vec<f64> {
+        let log_b: Vec<f64> = self
+            .means
+            .iter()
+            .zip(&self.variances)
+            .map(|(mean, var)| {
+                -0.5 * x
+                    .iter()
+                    .zip(mean.iter().zip(var))
+                    .map(|(xi, (m, v))| (2.0 * std::f64::consts::PI * v).ln() + (xi - m).powi(2) / v)
+                    .sum::<f64>()
+            })
+            .collect();
+        let max = log_b.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        log_b.iter().map(|l| (l - max).exp()).collect()
+    }
+
+    /// Baum-Welch on a single history. States start from entropy-ordered slices of the
+    /// data and are labelled by mean entropy: lowest Laminar, highest Decoherent, rest Turbulent.
+    pub fn fit(observations: &[[f64; N_FEATURES]], n_states: usize, iterations: usize) -> Result<Self, HmmError> {
+        if n_states < 2 {
+            return Err(HmmError::Invalid("need at least 2 states".to_string()));
+        }
+        if observations.len() < n_states * MIN_OBSERVATIONS_PER_STATE {
+            return Err(HmmError::Invalid(format!(
+                "{} observations is too little history for {} states",
+                observations.len(),
+                n_states
+            )));
+        }
+        if observations.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(HmmError::Invalid("non-finite feature in history".to_string()));
+        }
+
+        let mut model = Self::seed(observations, n_states);
+        for _ in 0..iterations {
+            model.reestimate(observations);
+        }
+        model.label_by_entropy();
+        model.validate()?;
+        Ok(model)
+    }
+
+    fn seed(observations: &[[f64; N_FEATURES]], n: usize) -> Self {
+        let mut sorted: Vec<&[f64; N_FEATURES]> = observations.iter().collect();
+        sorted.sort_by(|a, b| a[ENTROPY].total_cmp(&b[ENTROPY]));
+        let chunk = sorted.len() / n;
+        let (mut means, mut variances) = (Vec::with_capacity(n), Vec::with_capacity(n));
+        for k in 0..n {
+            let end = if k == n - 1 { sorted.len() } else { (k + 1) * chunk };
+            let slice = &sorted[k * chunk..end];
+            let count = slice.len() as f64;
+            let mean: Vec<f64> = (0..N_FEATURES).map(|f| slice.iter().map(|x| x[f]).sum::<f64>() / count).collect();
+            let var: Vec<f64> = (0..N_FEATURES)
+                .map(|f| (slice.iter().map(|x| (x[f] - mean[f]).powi(2)).sum::<f64>() / count).max(MIN_VARIANCE))
+                .collect();
+            means.push(mean);
+            variances.push(var);
+        }
+        let stay = 0.9;
+        let transition = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { stay } else { (1.0 - stay) / (n - 1) as f64 }).collect())
+            .collect();
+        Self {
+            initial: vec![1.0 / n as f64; n],
+            transition,
+            means,
+            variances,
+            labels: vec![MarketRegime::Unknown; n],
+        }
+    }
+
+    /// One scaled forward-backward pass and M-step.
+    fn reestimate(&mut self, obs: &[[f64; N_FEATURES]]) {
+        let (n, t_len) = (self.n_states(), obs.len());
+        let b: Vec<Vec<f64>> = obs.iter().map(|x| self.emissions(x)).collect();
+
+        // Forward (each step normalised; scale[t] is the normaliser)
+        let mut alpha = vec![vec![0.0; n]; t_len];
+        let mut scale = vec![0.0; t_len];
+        for t in 0..t_len {
+            for j in 0..n {
+                let prior = if t == 0 {
+                    self.initial[j]
+                } else {
+                    (0..n).map(|i| alpha[t - 1][i] * self.transition[i][j]).sum()
+                };
+                alpha[t][j] = prior * b[t][j];
+            }
+            scale[t] = alpha[t].iter().sum::<f64>().max(f64::MIN_POSITIVE);
+            alpha[t].iter_mut().for_each(|a| *a /= scale[t]);
+        }
+
+        // Backward (same scaling)
+        let mut beta = vec![vec![1.0; n]; t_len];
+        for t in (0..t_len - 1).rev() {
+            for i in 0..n {
+                beta[t][i] = (0..n).map(|j| self.transition[i][j] * b[t + 1][j] * beta[t + 1][j]).sum::<f64>() / scale[t + 1];
+            }
+        }
+
+        // E-step accumulators
+        let mut gamma_sum = vec![0.0; n];
+        let mut gamma_sum_head = vec![0.0; n]; // Excludes the last step (transition denominators)
+        let mut xi_sum = vec![vec![0.0; n]; n];
+        let mut mean_acc = vec![vec![0.0; N_FEATURES]; n];
+        let mut gammas = vec![vec![0.0; n]; t_len];
+        for t in 0..t_len {
+            let norm: f64 = (0..n).map(|i| alpha[t][i] * beta[t][i]).sum::<f64>().max(f64::MIN_POSITIVE);
+            for i in 0..n {
+                let g = alpha[t][i] * beta[t][i] / norm;
+                gammas[t][i] = g;
+                gamma_sum[i] += g;
+                if t + 1 < t_len {
+                    gamma_sum_head[i] += g;
+                }
+                for (acc, x) in mean_acc[i].iter_mut().zip(&obs[t]) {
+                    *acc += g * x;
+                }
+            }
+            if t + 1 < t_len {
+                for i in 0..n {
+                    for j in 0..n {
+                        xi_sum[i][j] += alpha[t][i] * self.transition[i][j] * b[t + 1][j] * beta[t + 1][j] / scale[t + 1];
+                    }
+                }
+            }
+        }
+
+        // M-step (states that lost all mass keep their previous parameters)
+        self.initial = gammas[0].clone();
+        for i in 0..n {
+            if gamma_sum_head[i] > f64::MIN_POSITIVE {
+                let row_sum: f64 = xi_sum[i].iter().sum();
+                if row_sum > f64::MIN_POSITIVE {
+                    self.transition[i] = xi_sum[i].iter().map(|x| x / row_sum).collect();
+                }
+            }
+            if gamma_sum[i] > f64::MIN_POSITIVE {
+                self.means[i] = mean_acc[i].iter().map(|m| m / gamma_sum[i]).collect();
+                for (f, (variance, mean)) in self.variances[i].iter_mut().zip(&self.means[i]).enumerate() {
+                    let var = obs.iter().zip(&gammas).map(|(x, g)| g[i] * (x[f] - mean).powi(2)).sum::<f64>()
+                        / gamma_sum[i];
+                    *variance = var.max(MIN_VARIANCE);
+                }
+            }
+        }
+    }
+
+    fn label_by_entropy(&mut self) {
+        let n = self.n_states();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| self.means[a][ENTROPY].total_cmp(&self.means[b][ENTROPY]));
+        for (rank, &state) in order.iter().enumerate() {
+            self.labels[state] = match rank {
+                0 => MarketRegime::Laminar,
+                r if r == n - 1 => MarketRegime::Decoherent,
+                _ => MarketRegime::Turbulent,
+            };
+        }
+    }
+}
+
+/// Online forward filter: belief over hidden states, one observation at a time.
+#[derive(Debug, Clone)]
+pub struct HmmFilter {
+    model: GaussianHmm,
+    belief: Vec<f64>,
+}
+
+impl HmmFilter {
+    pub fn new(model: GaussianHmm) -> Self {
+        let belief = model.initial.clone();
+        Self { model, belief }
+    }
+
+    pub fn belief(&self) -> &[f64] {
+        &self.belief
+    }
+
+    fn most_likely(&self) -> MarketRegime {
+        self.belief
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| self.model.labels[i])
+            .unwrap_or(MarketRegime::Unknown)
+    }
+
+    /// Predict with the transition matrix, update with the emission, return the MAP regime.
+    /// Non-finite features leave the belief untouched.
+    pub fn step(&mut self, x: &[f64; N_FEATURES]) -> MarketRegime {
+        if x.iter().any(|v| !v.is_finite()) {
+            return self.most_likely();
+        }
+        let n = self.model.n_states();
+        let b = self.model.emissions(x);
+        let posterior: Vec<f64> = (0..n)
+            .map(|j| (0..n).map(|i| self.belief[i] * self.model.transition[i][j]).sum::<f64>() * b[j])
+            .collect();
+        let total: f64 = posterior.iter().sum();
+        self.belief = if total.is_finite() && total > 0.0 {
+            posterior.iter().map(|p| p / total).collect()
+        } else {
+            self.model.initial.clone() // Lost track: restart from the prior
+        };
+        self.most_likely()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    /// Alternating calm / choppy / chaotic blocks of synthetic physics features.
+    fn synthetic_history() -> Vec<[f64; N_FEATURES]> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let regimes = [(0.85, 0.2, 0.5), (0.5, 0.55, 2.0), (0.1, 0.9, 5.0)];
+        let mut history = Vec::new();
+        for block in 0..12 {
+            let (coherence, entropy, jerk) = regimes[block % 3];
+            for _ in 0..50 {
+                history.push([
+                    coherence + rng.gen_range(-0.05..0.05),
+                    entropy + rng.gen_range(-0.05..0.05),
+                    jerk + rng.gen_range(-0.3..0.3),
+                ]);
+            }
+        }
+        history
+    }
+
+    #[test]
+    fn test_fit_labels_and_filters() {
+        let hmm = GaussianHmm::fit(&synthetic_history(), 3, 20).unwrap();
+        let mut labels = hmm.labels.clone();
+        labels.sort_by_key(|r| r.id());
+        assert_eq!(labels, vec![MarketRegime::Laminar, MarketRegime::Turbulent, MarketRegime::Decoherent]);
+
+        let mut filter = HmmFilter::new(hmm);
+        for _ in 0..3 {
+            filter.step(&[0.85, 0.2, 0.5]);
+        }
+        assert_eq!(filter.step(&[0.85, 0.2, 0.5]), MarketRegime::Laminar);
+        for _ in 0..3 {
+            filter.step(&[0.1, 0.9, 5.0]);
+        }
+        assert_eq!(filter.step(&[0.1, 0.9, 5.0]), MarketRegime::Decoherent);
+        assert_eq!(filter.step(&[f64::NAN, 0.2, 0.5]), MarketRegime::Decoherent);
+        assert!((filter.belief().iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_save_load_round_trip_and_validation() {
+        let hmm = GaussianHmm::fit(&synthetic_history(), 3, 5).unwrap();
+        let path = std::env::temp_dir().join(format!("regime_hmm_{}.json", std::process::id()));
+        hmm.save(&path).unwrap();
+        let loaded = GaussianHmm::load(&path).unwrap();
+        assert_eq!(loaded.labels, hmm.labels);
+        let close = |a: &[Vec<f64>], b: &[Vec<f64>]| a.iter().flatten().zip(b.iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-12);
+        assert!(close(&loaded.means, &hmm.means) && close(&loaded.transition, &hmm.transition));
+
+        let mut broken = hmm.clone();
+        broken.transition[0][0] += 0.5;
+        broken.save(&path).unwrap();
+        assert!(matches!(GaussianHmm::load(&path), Err(HmmError::Invalid(_))));
+        std::fs::remove_file(&path).ok();
+
+        assert!(GaussianHmm::fit(&synthetic_history()[..20], 3, 5).is_err());
+    }
+}
@@ -0,0 +1,671 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+
+use crate::clock::{self, SharedClock};
+use crate::db::state::RedisStateStore;
+use crate::feynman::PhysicsState;
+
+pub const RISK_LADDER_STATE_KEY: &str = "governor:risk_ladder";
+pub const RISK_LADDER_CAP_KEY: &str = "governor:risk_ladder:cap";
+const EVENT_LOG_LEN: usize = 256; // Transitions kept (and persisted) for replay/audit
+const SHADOW_WINDOW: usize = 1000;
+
+/// Safety Staircase tiers (D-43 / D-64). One ladder, one set of sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RiskTier {
+    Q0 = 0, // Tier 1: 0.01 lots (Survival / Floor)
+    Q1 = 1, // Tier 2: 0.05 lots
+    Q2 = 2, // Tier 3: 0.10 lots
+    Q3 = 3, // Tier 4: 0.25 lots
+    Q4 = 4, // Tier 5: 0.50 lots
+    Max = 5, // Tier 6: 1.00 lots
+}
+
+impl RiskTier {
+    pub const ALL: [RiskTier; 6] = [RiskTier::Q0, RiskTier::Q1, RiskTier::Q2, RiskTier::Q3, RiskTier::Q4, RiskTier::Max];
+
+    pub fn position_size(&self) -> f64 {
+        match self {
+            RiskTier::Q0 => 0.01,
+            RiskTier::Q1 => 0.05,
+            RiskTier::Q2 => 0.10,
+            RiskTier::Q3 => 0.25,
+            RiskTier::Q4 => 0.50,
+            RiskTier::Max => 1.00,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    pub fn prev(&self) -> Option<Self> {
+        self.index().checked_sub(1).map(|i| Self::ALL[i])
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskTier::Q0 => "Q0",
+            RiskTier::Q1 => "Q1",
+            RiskTier::Q2 => "Q2",
+            RiskTier::Q3 => "Q3",
+            RiskTier::Q4 => "Q4",
+            RiskTier::Max => "MAX",
+        }
+    }
+}
+
+impl fmt::Display for RiskTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RiskTier {
+    type Err = String;
+
+    /// "Q0".."Q4", "MAX", "FLOOR" (= Q0) or the tier index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_uppercase();
+        if s == "FLOOR" {
+            return Ok(RiskTier::Q0);
+        }
+        if let Some(tier) = Self::ALL.iter().find(|t| t.as_str() == s) {
+            return Ok(*tier);
+        }
+        s.parse::<usize>()
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+            .ok_or_else(|| format!("Unknown risk tier: {}", s))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LadderConfig {
+    pub required_stable_cycles: u32, // Consecutive Q1-Q3 physics cycles per step (D-43)
+    pub required_tight_fills: u32, // Consecutive fills within tight_fill_bps per step (D-64)
+    pub tight_fill_bps: f64,
+    pub warmup: Duration, // No promotion this soon after boot (Sandbox Verification)
+    pub instability_score: u8, // Physics score at/above which we slide to the floor
+    pub alpha_decay_limit: f64, // 0.15 = 15%
+    pub veto_limit: u32, // Nuclear vetoes within veto_window -> lockout
+    pub veto_window: Duration,
+    pub cooldown: Duration, // Lockout length (pinned to the floor)
+}
+
+impl Default for LadderConfig {
+    fn default() -> Self {
+        Self {
+            required_stable_cycles: 2,
+            required_tight_fills: 50,
+            tight_fill_bps: 2.0,
+            warmup: Duration::from_secs(300),
+            instability_score: 9,
+            alpha_decay_limit: 0.15,
+            veto_limit: 3,
+            veto_window: Duration::from_secs(3600),
+            cooldown: Duration::from_secs(4 * 3600),
+        }
+    }
+}
+
+/// One promotion or demotion, with the reason. The persisted log of these is the
+/// ladder's state: replaying it yields the tier and any lockout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LadderEvent {
+    pub seq: u64,
+    pub at_ms: i64,
+    pub from: RiskTier,
+    pub to: RiskTier,
+    pub reason: String,
+    pub cooldown_until_ms: Option<i64>, // Set by a veto lockout
+}
+
+/// Operator ceiling on the effective tier until a wall-clock time (D-86 CAP_TIER).
+/// The ladder keeps promoting underneath; sizing uses the lower of the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TierCap {
+    pub tier: RiskTier,
+    pub until_ms: i64,
+}
+
+/// API view of the ladder (pushed into SharedState by the owner loop).
+#[derive(Debug, Clone, Serialize)]
+pub struct LadderSnapshot {
+    pub tier: RiskTier,
+    pub position_size: f64,
+    pub progress: f64,
+    pub cooldown_until_ms: Option<i64>,
+    pub cap: Option<TierCap>, // Active operator cap
+    pub events: Vec<LadderEvent>, // Oldest first
+}
+
+impl Default for LadderSnapshot {
+    fn default() -> Self {
+        Self {
+            tier: RiskTier::Q0,
+            position_size: RiskTier::Q0.position_size(),
+            progress: 0.0,
+            cooldown_until_ms: None,
+            cap: None,
+            events: Vec::new(),
+        }
+    }
+}
+
+/// The Risk Ladder: the single owner of the risk tier.
+/// Promotion needs stable physics, tight fills, a positive shadow record and no lockout;
+/// instability, alpha decay, repeated vetoes and operators demote.
+pub struct RiskLadder {
+    pub config: LadderConfig,
+    tier: RiskTier,
+    stable_cycles: u32,
+    tight_fills: u32,
+    shadow_pnl: VecDeque<f64>, // Rolling PnL of the shadow sim
+    veto_count: u32,
+    last_veto: Option<Instant>,
+    cooldown_until_ms: Option<i64>, // Wall clock, so a lockout survives restarts
+    cap: Option<TierCap>,
+    events: VecDeque<LadderEvent>,
+    seq: u64,
+    boot: Instant,
+    dirty: bool,
+    clock: SharedClock,
+}
+
+impl RiskLadder {
+    pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self {
+            config: LadderConfig::default(),
+            tier: RiskTier::Q0,
+            stable_cycles: 0,
+            tight_fills: 0,
+            shadow_pnl: VecDeque::with_capacity(SHADOW_WINDOW),
+            veto_count: 0,
+            last_veto: None,
+            cooldown_until_ms: None,
+            cap: None,
+            events: VecDeque::with_capacity(EVENT_LOG_LEN),
+            seq: 0,
+            boot: clock.now(),
+            dirty: false,
+            clock,
+        }
+    }
+
+    pub fn tier(&self) -> RiskTier {
+        self.tier
+    }
+
+    /// The tier sizing runs at: the ladder tier, held under any operator cap.
+    pub fn effective_tier(&self) -> RiskTier {
+        match self.active_cap() {
+            Some(cap) => self.tier.min(cap.tier),
+            None => self.tier,
+        }
+    }
+
+    /// Max position size (lots). Pinned to the floor during a lockout.
+    pub fn position_size(&self) -> f64 {
+        if self.is_in_cooldown() {
+            return RiskTier::Q0.position_size();
+        }
+        self.effective_tier().position_size()
+    }
+
+    pub fn active_cap(&self) -> Option<TierCap> {
+        self.cap.filter(|c| self.clock.unix_millis() < c.until_ms)
+    }
+
+    /// Hold the effective tier at or below `tier` until `until_ms`. Never loosens:
+    /// over an active cap the lower tier and the later expiry win.
+    pub fn cap(&mut self, tier: RiskTier, until_ms: i64, reason: &str) -> TierCap {
+        let cap = match self.active_cap() {
+            Some(active) => TierCap { tier: active.tier.min(tier), until_ms: active.until_ms.max(until_ms) },
+            None => TierCap { tier, until_ms },
+        };
+        warn!("🪜 RISK LADDER CAP: {} until {} ({})", cap.tier, cap.until_ms, reason);
+        self.cap = Some(cap);
+        self.dirty = true;
+        cap
+    }
+
+    pub fn is_in_cooldown(&self) -> bool {
+        self.cooldown_until_ms.is_some_and(|until| self.clock.unix_millis() < until)
+    }
+
+    /// Progress towards the next promotion (0.0 - 1.0): the slower of the two counters.
+    pub fn progress(&self) -> f64 {
+        if self.is_in_cooldown() {
+            return 0.0;
+        }
+        let frac = |n: u32, required: u32| if required == 0 { 1.0 } else { (n as f64 / required as f64).min(1.0) };
+        frac(self.stable_cycles, self.config.required_stable_cycles)
+            .min(frac(self.tight_fills, self.config.required_tight_fills))
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &LadderEvent> {
+        self.events.iter()
+    }
+
+    pub fn snapshot(&self) -> LadderSnapshot {
+        LadderSnapshot {
+            tier: self.tier,
+            position_size: self.position_size(),
+            progress: self.progress(),
+            cooldown_until_ms: self.cooldown_until_ms.filter(|_| self.is_in_cooldown()),
+            cap: self.active_cap(),
+            events: self.events.iter().cloned().collect(),
+        }
+    }
+
+    /// Tier and lockout implied by an event log.
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a LadderEvent>) -> (RiskTier, Option<i64>) {
+        events.into_iter().fold((RiskTier::Q0, None), |(_, cooldown), e| (e.to, e.cooldown_until_ms.or(cooldown)))
+    }
+
+    /// Rebuild from a persisted log. Progress counters start over.
+    pub fn restore(&mut self, events: Vec<LadderEvent>) {
+        let (tier, cooldown) = Self::replay(&events);
+        self.tier = tier;
+        self.cooldown_until_ms = cooldown;
+        self.seq = events.last().map_or(0, |e| e.seq);
+        self.events = events.into_iter().collect();
+        while self.events.len() > EVENT_LOG_LEN {
+            self.events.pop_front();
+        }
+        self.stable_cycles = 0;
+        self.tight_fills = 0;
+    }
+
+    /// Restore the ladder from Redis (no-op if absent or unreachable).
+    pub async fn load(&mut self, store: &RedisStateStore) {
+        match store.get_state::<Vec<LadderEvent>>(RISK_LADDER_STATE_KEY).await {
+            Ok(events) => {
+                let n = events.len();
+                self.restore(events);
+                info!("🪜 RISK LADDER: Restored {} from {} events.", self.tier, n);
+            }
+            Err(e) => warn!("⚠️ RISK LADDER: No persisted ladder ({}). Starting at the floor.", e),
+        }
+        if let Ok(Some(cap)) = store.get_state::<Option<TierCap>>(RISK_LADDER_CAP_KEY).await {
+            self.cap = Some(cap);
+            if let Some(cap) = self.active_cap() {
+                warn!("🪜 RISK LADDER: Operator cap {} restored (until {}).", cap.tier, cap.until_ms);
+            }
+        }
+    }
+
+    /// Persist the event log if a transition happened since the last save.
+    pub async fn persist(&mut self, store: &RedisStateStore) {
+        if !self.dirty {
+            return;
+        }
+        let events: Vec<&LadderEvent> = self.events.iter().collect();
+        let saved = match store.set_state(RISK_LADDER_STATE_KEY, &events).await {
+            Ok(()) => store.set_state(RISK_LADDER_CAP_KEY, &self.cap).await,
+            Err(e) => Err(e),
+        };
+        match saved {
+            Ok(()) => self.dirty = false,
+            Err(e) => error!("❌ RISK LADDER: Failed to persist: {}", e),
+        }
+    }
+
+    /// Per-cycle physics evidence. Returns TRUE on promotion.
+    pub fn observe(&mut self, physics: &PhysicsState) -> bool {
+        let score = Self::stability_score(physics.jerk, physics.entropy, physics.efficiency_index);
+
+        // Logic: If Score <= Q3 (3), we are stable.
+        if score <= 3 {
+            self.stable_cycles += 1;
+        } else {
+            // Reset if instability detected
+            self.stable_cycles = 0;
+            if score >= self.config.instability_score {
+                self.demote_to_floor(&format!("Physics instability (Q{})", score)); // Emergency Freeze
+            }
+        }
+
+        self.try_promote()
+    }
+
+    /// PnL change of the shadow book (ShadowGate fills marked to market).
+    pub fn record_shadow_pnl(&mut self, pnl: f64) {
+        if self.shadow_pnl.len() >= SHADOW_WINDOW {
+            self.shadow_pnl.pop_front();
+        }
+        self.shadow_pnl.push_back(pnl);
+    }
+
+    /// Fill quality. slippage_bps: expected vs realized price in basis points.
+    pub fn register_fill(&mut self, slippage_bps: f64) {
+        if slippage_bps.abs() <= self.config.tight_fill_bps {
+            self.tight_fills += 1;
+        } else {
+            self.tight_fills = 0; // Reset on poor fill
+        }
+    }
+
+    /// The "Emergency Slide". alpha_decay: 0.0 to 1.0 (e.g., 0.15 = 15%).
+    pub fn check_alpha_decay(&mut self, alpha_decay: f64) -> bool {
+        if alpha_decay > self.config.alpha_decay_limit {
+            self.demote_to_floor(&format!("Alpha Decay {:.0}% > {:.0}%", alpha_decay * 100.0, self.config.alpha_decay_limit * 100.0));
+            return true;
+        }
+        false
+    }
+
+    /// Called when a Nuclear Veto is issued. Too many in the window -> floor + lockout.
+    pub fn register_veto(&mut self) {
+        let now = self.clock.now();
+        if self.last_veto.is_some_and(|last| now.duration_since(last) > self.config.veto_window) {
+            self.veto_count = 0; // Reset if window expired
+        }
+        self.veto_count += 1;
+        self.last_veto = Some(now);
+
+        if self.veto_count >= self.config.veto_limit {
+            let until = self.clock.unix_millis() + self.config.cooldown.as_millis() as i64;
+            self.cooldown_until_ms = Some(until);
+            let reason = format!("{} Nuclear Vetoes within {}m", self.veto_count, self.config.veto_window.as_secs() / 60);
+            self.transition(RiskTier::Q0, reason, Some(until));
+            self.veto_count = 0; // Reset count after triggering lock
+        }
+    }
+
+    /// Operator (or governor) demotion. Never promotes. Returns TRUE if the tier changed.
+    pub fn demote(&mut self, target: RiskTier, reason: &str) -> bool {
+        if target >= self.tier {
+            return false;
+        }
+        self.transition(target, reason.to_string(), None);
+        true
+    }
+
+    pub fn demote_to_floor(&mut self, reason: &str) -> bool {
+        self.demote(RiskTier::Q0, reason)
+    }
+
+    /// Check criteria for moving up the ladder.
+    fn try_promote(&mut self) -> bool {
+        if self.is_in_cooldown()
+            || self.stable_cycles < self.config.required_stable_cycles
+            || self.tight_fills < self.config.required_tight_fills
+            || self.clock.elapsed_since(self.boot) < self.config.warmup
+        {
+            return false;
+        }
+        // Shadow Consistency (Omega > 1 => Sum PnL > 0)
+        let shadow: f64 = self.shadow_pnl.iter().sum();
+        if shadow <= 0.0 {
+            return false;
+        }
+        let Some(next) = self.tier.next() else {
+            return false;
+        };
+        let reason = format!(
+            "{} stable cycles, {} tight fills, shadow PnL {:+.1}",
+            self.stable_cycles, self.tight_fills, shadow
+        );
+        self.transition(next, reason, None);
+        true
+    }
+
+    fn transition(&mut self, to: RiskTier, reason: String, cooldown_until_ms: Option<i64>) {
+        let from = self.tier;
+        if to > from {
+            info!("🪜 RISK LADDER PROMOTION: {} -> {} ({})", from, to, reason);
+        } else {
+            warn!("🪜 RISK LADDER DEMOTION: {} -> {} ({})", from, to, reason);
+        }
+        self.seq += 1;
+        if self.events.len() >= EVENT_LOG_LEN {
+            self.events.pop_front();
+        }
+        self.events.push_back(LadderEvent {
+            seq: self.seq,
+            at_ms: self.clock.unix_millis(),
+            from,
+            to,
+            reason,
+            cooldown_until_ms,
+        });
+        self.tier = to;
+        self.stable_cycles = 0;
+        self.tight_fills = 0;
+        self.dirty = true;
+    }
+
+    /// Q1 (Best) -> Q10 (Worst)
+    /// Heuristic mapping based on directives
+    fn stability_score(jerk: f64, entropy: f64, efficiency: f64) -> u8 {
+        // 1. Jerk Component (Lower is better)
+        // Assume experimental range 0.0 to 1.0 for normalized jerk
+        let j_score = if jerk.abs() < 0.01 { 1 }
+                      else if jerk.abs() < 0.05 { 2 }
+                      else if jerk.abs() < 0.1 { 5 }
+                      else { 10 };
+
+        // 2. Efficiency Component (Higher is better)
+        // Efficiency > 0.8 is target
+        let e_score = if efficiency > 0.9 { 1 }
+                      else if efficiency > 0.8 { 2 }
+                      else if efficiency > 0.5 { 5 }
+                      else { 10 };
+
+        // 3. Entropy Component (High Entropy = Chaos (Bad) for this heuristic)
+        let h_score = if entropy < 1.0 { 1 } else { 10 };
+
+        // Simple fused average rounded up
+        let avg = (j_score + e_score + h_score) as f64 / 3.0;
+        avg.ceil() as u8
+    }
+}
+
+impl Default for RiskLadder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, ManualClock};
+    use std::sync::Arc;
+
+    fn stable() -> PhysicsState {
+        PhysicsState { price: 100.0, jerk: 0.001, entropy: 0.5, efficiency_index: 0.95, ..Default::default() }
+    }
+
+    fn chaos() -> PhysicsState {
+        PhysicsState { price: 100.0, velocity: 100.0, jerk: 5.0, entropy: 5.0, efficiency_index: 0.1, ..Default::default() }
+    }
+
+    /// Ladder past warmup with a quick fill gate and a profitable shadow record.
+    fn warmed(clock: &Arc<ManualClock>) -> RiskLadder {
+        let mut ladder = RiskLadder::with_clock(clock.clone());
+        ladder.config.required_tight_fills = 3;
+        ladder.record_shadow_pnl(10.0);
+        clock.advance(Duration::from_secs(301));
+        ladder
+    }
+
+    #[test]
+    fn test_initial_state() {
+        let ladder = RiskLadder::new();
+        assert_eq!(ladder.tier(), RiskTier::Q0);
+        assert_eq!(ladder.position_size(), 0.01);
+        assert_eq!(ladder.events().count(), 0);
+    }
+
+    #[test]
+    fn test_promotion_needs_stable_physics_and_tight_fills() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ladder = warmed(&clock);
+
+        // Stable physics alone is not enough
+        assert!(!ladder.observe(&stable()));
+        assert!(!ladder.observe(&stable()));
+        assert_eq!(ladder.tier(), RiskTier::Q0);
+
+        // Poor fill resets the fill counter
+        ladder.register_fill(1.0);
+        ladder.register_fill(1.0);
+        ladder.register_fill(5.0);
+        assert!(!ladder.observe(&stable()));
+
+        for _ in 0..3 {
+            ladder.register_fill(1.0);
+        }
+        assert!(ladder.observe(&stable()));
+        assert_eq!(ladder.tier(), RiskTier::Q1);
+        assert_eq!(ladder.position_size(), 0.05);
+        assert_eq!(ladder.progress(), 0.0, "Counters reset after promotion");
+
+        let event = ladder.events().last().unwrap();
+        assert_eq!((event.from, event.to, event.seq), (RiskTier::Q0, RiskTier::Q1, 1));
+        assert!(event.reason.contains("tight fills"));
+    }
+
+    #[test]
+    fn test_warmup_and_shadow_gates() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ladder = RiskLadder::with_clock(clock.clone());
+        ladder.config.required_tight_fills = 0;
+
+        // Should not promote during 5-min warmup (even with stable conditions)
+        ladder.observe(&stable());
+        assert!(!ladder.observe(&stable()));
+
+        // No shadow record, or a losing one, blocks promotion after warmup
+        clock.advance(Duration::from_secs(301));
+        assert!(!ladder.observe(&stable()));
+        ladder.record_shadow_pnl(-1000.0);
+        assert!(!ladder.observe(&stable()));
+        assert_eq!(ladder.tier(), RiskTier::Q0);
+
+        // Stable physics alone never manufactures a record
+        for _ in 0..10 {
+            ladder.observe(&stable());
+        }
+        assert_eq!(ladder.tier(), RiskTier::Q0);
+        ladder.record_shadow_pnl(1500.0);
+        assert!(ladder.observe(&stable()));
+    }
+
+    #[test]
+    fn test_emergency_slides() {
+        let mut ladder = RiskLadder::new();
+        assert!(!ladder.demote_to_floor("already at the floor"));
+
+        ladder.restore(vec![LadderEvent { seq: 7, at_ms: 0, from: RiskTier::Q4, to: RiskTier::Max, reason: "test".into(), cooldown_until_ms: None }]);
+        assert!(ladder.check_alpha_decay(0.20)); // 20% Alpha Decay
+        assert_eq!(ladder.tier(), RiskTier::Q0, "Should slide to floor");
+
+        ladder.restore(vec![LadderEvent { seq: 8, at_ms: 0, from: RiskTier::Q2, to: RiskTier::Q3, reason: "test".into(), cooldown_until_ms: None }]);
+        ladder.observe(&chaos()); // High Entropy, Low Efficiency, Massive Jerk
+        assert_eq!(ladder.position_size(), 0.01, "Should drop to 0.01");
+        let event = ladder.events().last().unwrap();
+        assert_eq!((event.seq, event.from), (9, RiskTier::Q3));
+        assert!(event.reason.starts_with("Physics instability"));
+    }
+
+    #[test]
+    fn test_veto_lockout_window_and_expiry() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ladder = warmed(&clock);
+
+        // Vetoes spread beyond the 60m window never lock
+        ladder.register_veto();
+        ladder.register_veto();
+        clock.advance(Duration::from_secs(3601));
+        ladder.register_veto();
+        assert!(!ladder.is_in_cooldown());
+
+        ladder.register_veto();
+        ladder.register_veto();
+        assert!(ladder.is_in_cooldown());
+        assert_eq!(ladder.position_size(), 0.01, "Should be forced to min size");
+        assert!(ladder.events().last().unwrap().cooldown_until_ms.is_some());
+
+        // Cannot promote during cooldown
+        for _ in 0..3 {
+            ladder.register_fill(1.0);
+        }
+        ladder.observe(&stable());
+        assert!(!ladder.observe(&stable()));
+
+        // Lock lifts after 4 hours
+        clock.advance(Duration::from_secs(4 * 3600 - 1));
+        assert!(ladder.is_in_cooldown());
+        clock.advance(Duration::from_secs(2));
+        assert!(!ladder.is_in_cooldown());
+    }
+
+    #[test]
+    fn test_replay_and_operator_demotion() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ladder = warmed(&clock);
+        for _ in 0..2 {
+            for _ in 0..3 {
+                ladder.register_fill(1.0);
+            }
+            ladder.observe(&stable());
+            ladder.observe(&stable());
+        }
+        assert_eq!(ladder.tier(), RiskTier::Q2);
+        assert!(!ladder.demote(RiskTier::Q3, "operator"), "Demotion never promotes");
+        assert!(ladder.demote("q1".parse().unwrap(), "Operator alice: desk review"));
+
+        // A fresh process replays the log to the same place
+        let log: Vec<LadderEvent> = ladder.events().cloned().collect();
+        assert_eq!(RiskLadder::replay(&log), (RiskTier::Q1, None));
+        let mut restarted = RiskLadder::with_clock(clock.clone());
+        restarted.restore(log);
+        assert_eq!(restarted.tier(), RiskTier::Q1);
+        assert_eq!(restarted.snapshot().events.len(), 3);
+        restarted.demote_to_floor("Sentinel critical");
+        assert_eq!(restarted.events().last().unwrap().seq, 4);
+
+        assert_eq!("floor".parse::<RiskTier>(), Ok(RiskTier::Q0));
+        assert_eq!("MAX".parse::<RiskTier>(), Ok(RiskTier::Max));
+        assert!("Q9".parse::<RiskTier>().is_err());
+    }
+
+    #[test]
+    fn test_operator_cap_only_lowers_and_expires() {
+        let clock = Arc::new(ManualClock::new());
+        let mut ladder = RiskLadder::with_clock(clock.clone());
+        ladder.restore(vec![LadderEvent { seq: 1, at_ms: 0, from: RiskTier::Q2, to: RiskTier::Q3, reason: "test".into(), cooldown_until_ms: None }]);
+
+        let now = clock.unix_millis();
+        ladder.cap(RiskTier::Q1, now + 60_000, "desk review");
+        assert_eq!((ladder.tier(), ladder.effective_tier()), (RiskTier::Q3, RiskTier::Q1));
+        assert_eq!(ladder.position_size(), 0.05);
+
+        // A looser or shorter cap can't raise the ceiling or end it early
+        let cap = ladder.cap(RiskTier::Max, now + 10_000, "try to loosen");
+        assert_eq!(cap, TierCap { tier: RiskTier::Q1, until_ms: now + 60_000 });
+        assert_eq!(ladder.snapshot().cap, Some(cap));
+
+        clock.advance(Duration::from_secs(61));
+        assert!(ladder.active_cap().is_none());
+        assert_eq!(ladder.effective_tier(), RiskTier::Q3);
+        assert!(ladder.snapshot().cap.is_none());
+    }
+}
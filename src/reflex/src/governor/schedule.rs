@@ -0,0 +1,343 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+const LOOKAHEAD_DAYS: i64 = 8; // How far ahead the next open/close is searched
+
+/// Weekly trading window in the schedule's timezone. `end <= start` runs past midnight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowConfig {
+    pub days: Vec<String>, // "Mon".."Sun"
+    pub start: String, // "HH:MM"
+    pub end: String,
+}
+
+/// No trading between two local times ("YYYY-MM-DDTHH:MM"), e.g. around a release.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlackoutConfig {
+    pub start: String,
+    pub end: String,
+    pub reason: String,
+}
+
+/// Trading-hours schedule. Loaded from JSON (SCHEDULE_PATH); missing fields keep
+/// their defaults. No windows = open around the clock (holidays/blackouts still apply).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleConfig {
+    pub timezone: String, // IANA name
+    pub windows: Vec<WindowConfig>,
+    pub holidays: Vec<String>, // "YYYY-MM-DD", closed the whole local day
+    pub blackouts: Vec<BlackoutConfig>,
+    pub taper_minutes: u32, // Positions shrink to flat over this long before a close
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            timezone: "UTC".to_string(),
+            windows: Vec::new(),
+            holidays: Vec::new(),
+            blackouts: Vec::new(),
+            taper_minutes: 30,
+        }
+    }
+}
+
+impl ScheduleConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        TradingSchedule::new(config.clone())?; // Validate
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SessionPhase {
+    Open,
+    Tapering, // Open, but inside taper_minutes of the close
+    Closed,
+}
+
+/// Where the schedule stands at one instant (pushed into SharedState by the owner loop).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionStatus {
+    pub phase: SessionPhase,
+    pub reason: String,
+    pub closes_at_ms: Option<i64>, // None = no close within the lookahead
+    pub opens_at_ms: Option<i64>, // When closed; None = nothing open within the lookahead
+    pub taper: f64, // Fraction of the position allowed: 1 open, falling to 0 at the close
+}
+
+impl Default for SessionStatus {
+    fn default() -> Self {
+        Self { phase: SessionPhase::Open, reason: "Open".to_string(), closes_at_ms: None, opens_at_ms: None, taper: 1.0 }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Window {
+    day: Weekday,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+#[derive(Debug, Clone)]
+struct Blackout {
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    reason: String,
+}
+
+type Interval = (DateTime<Utc>, DateTime<Utc>);
+
+pub struct TradingSchedule {
+    pub config: ScheduleConfig,
+    tz: Tz,
+    windows: Vec<Window>,
+    holidays: BTreeSet<NaiveDate>,
+    blackouts: Vec<Blackout>,
+}
+
+impl TradingSchedule {
+    pub fn new(config: ScheduleConfig) -> Result<Self, String> {
+        let tz: Tz = config.timezone.parse().map_err(|_| format!("Unknown timezone '{}'", config.timezone))?;
+        let time = |s: &str| NaiveTime::parse_from_str(s.trim(), "%H:%M").map_err(|_| format!("Bad time '{}' (HH:MM)", s));
+        let datetime = |s: &str| {
+            NaiveDateTime::parse_from_str(s.trim(), "%Y-%m-%dT%H:%M").map_err(|_| format!("Bad datetime '{}' (YYYY-MM-DDTHH:MM)", s))
+        };
+
+        let mut windows = Vec::new();
+        for w in &config.windows {
+            let (start, end) = (time(&w.start)?, time(&w.end)?);
+            if w.days.is_empty() {
+                return Err(format!("Window {}-{} has no days", w.start, w.end));
+            }
+            for d in &w.days {
+                let day = d.trim().parse::<Weekday>().map_err(|_| format!("Bad weekday '{}'", d))?;
+                windows.push(Window { day, start, end });
+            }
+        }
+        let holidays = config
+            .holidays
+            .iter()
+            .map(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").map_err(|_| format!("Bad holiday '{}' (YYYY-MM-DD)", d)))
+            .collect::<Result<_, _>>()?;
+        let mut blackouts = Vec::new();
+        for b in &config.blackouts {
+            let (start, end) = (datetime(&b.start)?, datetime(&b.end)?);
+            if end <= start {
+                return Err(format!("Blackout '{}' ends before it starts", b.reason));
+            }
+            blackouts.push(Blackout { start, end, reason: b.reason.clone() });
+        }
+        Ok(Self { config, tz, windows, holidays, blackouts })
+    }
+
+    /// Around the clock in UTC, nothing closed.
+    pub fn always_open() -> Self {
+        Self::new(ScheduleConfig::default()).expect("default schedule is valid")
+    }
+
+    pub fn status(&self, now: DateTime<Utc>) -> SessionStatus {
+        let horizon_end = self.local(self.local_date(now) + ChronoDuration::days(LOOKAHEAD_DAYS + 1), NaiveTime::MIN);
+        let intervals = self.open_intervals(now);
+
+        if let Some(&(_, end)) = intervals.iter().find(|(s, e)| *s <= now && now < *e) {
+            let closes_at = (end < horizon_end).then_some(end);
+            let taper_window = ChronoDuration::minutes(self.config.taper_minutes as i64);
+            let remaining = end - now;
+            return match closes_at {
+                Some(end) if taper_window > ChronoDuration::zero() && remaining <= taper_window => SessionStatus {
+                    phase: SessionPhase::Tapering,
+                    reason: format!("Tapering: closes in {}m", remaining.num_minutes()),
+                    closes_at_ms: Some(end.timestamp_millis()),
+                    opens_at_ms: None,
+                    taper: remaining.num_milliseconds() as f64 / taper_window.num_milliseconds() as f64,
+                },
+                _ => SessionStatus {
+                    phase: SessionPhase::Open,
+                    reason: "Open".to_string(),
+                    closes_at_ms: closes_at.map(|t| t.timestamp_millis()),
+                    opens_at_ms: None,
+                    taper: 1.0,
+                },
+            };
+        }
+
+        SessionStatus {
+            phase: SessionPhase::Closed,
+            reason: self.closed_reason(now),
+            closes_at_ms: None,
+            opens_at_ms: intervals.iter().map(|(s, _)| *s).find(|s| *s > now).map(|t| t.timestamp_millis()),
+            taper: 0.0,
+        }
+    }
+
+    fn closed_reason(&self, now: DateTime<Utc>) -> String {
+        let date = self.local_date(now);
+        if self.holidays.contains(&date) {
+            return format!("Holiday {}", date);
+        }
+        let local = now.with_timezone(&self.tz).naive_local();
+        match self.blackouts.iter().find(|b| b.start <= local && local < b.end) {
+            Some(b) => format!("Blackout: {}", b.reason),
+            None => "Outside trading windows".to_string(),
+        }
+    }
+
+    /// Open intervals from the day before `now` to the lookahead, closures removed,
+    /// merged where they touch (e.g. a Sunday-night window running into Monday's).
+    fn open_intervals(&self, now: DateTime<Utc>) -> Vec<Interval> {
+        let today = self.local_date(now);
+        let first = today - ChronoDuration::days(1);
+        let days = (0..=LOOKAHEAD_DAYS + 1).map(|i| first + ChronoDuration::days(i));
+
+        let mut open: Vec<Interval> = if self.windows.is_empty() {
+            vec![(self.local(first, NaiveTime::MIN), self.local(first + ChronoDuration::days(LOOKAHEAD_DAYS + 2), NaiveTime::MIN))]
+        } else {
+            days.clone()
+                .flat_map(|date| {
+                    self.windows.iter().filter(move |w| w.day == date.weekday()).map(move |w| {
+                        let end_date = if w.end <= w.start { date + ChronoDuration::days(1) } else { date };
+                        (self.local(date, w.start), self.local(end_date, w.end))
+                    })
+                })
+                .collect()
+        };
+
+        let closures: Vec<Interval> = days
+            .filter(|d| self.holidays.contains(d))
+            .map(|d| (self.local(d, NaiveTime::MIN), self.local(d + ChronoDuration::days(1), NaiveTime::MIN)))
+            .chain(self.blackouts.iter().map(|b| (self.to_utc(b.start), self.to_utc(b.end))))
+            .collect();
+        for (cs, ce) in closures {
+            open = open
+                .into_iter()
+                .flat_map(|(s, e)| {
+                    if ce <= s || cs >= e {
+                        return vec![(s, e)];
+                    }
+                    [(s, cs), (ce, e)].into_iter().filter(|(a, b)| a < b).collect()
+                })
+                .collect();
+        }
+
+        open.sort();
+        let mut merged: Vec<Interval> = Vec::with_capacity(open.len());
+        for (s, e) in open {
+            match merged.last_mut() {
+                Some(last) if s <= last.1 => last.1 = last.1.max(e),
+                _ => merged.push((s, e)),
+            }
+        }
+        merged
+    }
+
+    fn local_date(&self, t: DateTime<Utc>) -> NaiveDate {
+        t.with_timezone(&self.tz).date_naive()
+    }
+
+    fn local(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        self.to_utc(date.and_time(time))
+    }
+
+    /// Local wall time -> UTC. Ambiguous (DST fall-back) takes the earlier instant;
+    /// a time skipped by spring-forward moves to the first valid one after it.
+    fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        (0..=2)
+            .find_map(|h| self.tz.from_local_datetime(&(local + ChronoDuration::hours(h))).earliest())
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+    }
+}
+
+impl Default for TradingSchedule {
+    fn default() -> Self {
+        Self::always_open()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn equities() -> TradingSchedule {
+        TradingSchedule::new(ScheduleConfig {
+            timezone: "America/New_York".to_string(),
+            windows: vec![WindowConfig {
+                days: ["Mon", "Tue", "Wed", "Thu", "Fri"].iter().map(|d| d.to_string()).collect(),
+                start: "09:30".into(),
+                end: "16:00".into(),
+            }],
+            holidays: vec!["2024-07-04".into()],
+            blackouts: vec![BlackoutConfig { start: "2024-07-03T13:45".into(), end: "2024-07-03T14:30".into(), reason: "FOMC".into() }],
+            taper_minutes: 30,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_windows_taper_and_weekend() {
+        let s = equities();
+        // Tue 2 Jul 2024, 10:00 EDT = 14:00 UTC
+        let open = s.status(at(2024, 7, 2, 14, 0));
+        assert_eq!(open.phase, SessionPhase::Open);
+        assert_eq!(open.closes_at_ms, Some(at(2024, 7, 2, 20, 0).timestamp_millis()));
+        assert_eq!(open.taper, 1.0);
+
+        // 15:45 EDT: halfway through the taper
+        let taper = s.status(at(2024, 7, 2, 19, 45));
+        assert_eq!(taper.phase, SessionPhase::Tapering);
+        assert!((taper.taper - 0.5).abs() < 1e-9);
+
+        // Friday after the close -> Monday 09:30
+        let weekend = s.status(at(2024, 7, 5, 21, 0));
+        assert_eq!((weekend.phase, weekend.reason.as_str()), (SessionPhase::Closed, "Outside trading windows"));
+        assert_eq!(weekend.opens_at_ms, Some(at(2024, 7, 8, 13, 30).timestamp_millis()));
+    }
+
+    #[test]
+    fn test_holiday_and_blackout() {
+        let s = equities();
+        // The blackout splits Wednesday: morning session closes (and tapers) into it
+        let before = s.status(at(2024, 7, 3, 17, 30)); // 13:30 EDT
+        assert_eq!(before.phase, SessionPhase::Tapering);
+        assert_eq!(before.closes_at_ms, Some(at(2024, 7, 3, 17, 45).timestamp_millis()));
+
+        let during = s.status(at(2024, 7, 3, 18, 0));
+        assert_eq!((during.phase, during.reason.as_str()), (SessionPhase::Closed, "Blackout: FOMC"));
+        assert_eq!(during.opens_at_ms, Some(at(2024, 7, 3, 18, 30).timestamp_millis()));
+
+        // Independence Day -> Friday
+        let holiday = s.status(at(2024, 7, 4, 15, 0));
+        assert_eq!(holiday.reason, "Holiday 2024-07-04");
+        assert_eq!(holiday.opens_at_ms, Some(at(2024, 7, 5, 13, 30).timestamp_millis()));
+    }
+
+    #[test]
+    fn test_overnight_window_and_always_open() {
+        // Sunday 18:00 - Monday 17:00 (futures style) in UTC
+        let s = TradingSchedule::new(ScheduleConfig {
+            windows: vec![WindowConfig { days: vec!["Sun".into()], start: "18:00".into(), end: "17:00".into() }],
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(s.status(at(2024, 7, 8, 3, 0)).phase, SessionPhase::Open); // Monday 03:00
+        assert_eq!(s.status(at(2024, 7, 8, 17, 0)).phase, SessionPhase::Closed);
+
+        let always = TradingSchedule::always_open().status(at(2024, 7, 4, 12, 0));
+        assert_eq!((always.phase, always.closes_at_ms), (SessionPhase::Open, None));
+
+        assert!(TradingSchedule::new(ScheduleConfig { timezone: "Mars/Olympus".into(), ..Default::default() }).is_err());
+    }
+}
@@ -1,8 +1,13 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::collections::VecDeque;
+use crate::clock::{self, SharedClock};
 
-/// Vitality Status for the System
-#[derive(Debug, Clone, Copy, PartialEq)]
+// Consecutive budget overruns of any one phase before vitality degrades
+const OVERRUNS_DEGRADED: u64 = 3;
+const OVERRUNS_CRITICAL: u64 = 10;
+
+/// Vitality Status for the System. Ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum VitalityStatus {
     Optimal,
     Degraded,
@@ -19,6 +24,78 @@ impl VitalityStatus {
     }
 }
 
+/// The four OODA phases, each held to its own time budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoopPhase {
+    Observe,
+    Orient,
+    Decide,
+    Act,
+}
+
+impl LoopPhase {
+    pub const ALL: [LoopPhase; 4] = [LoopPhase::Observe, LoopPhase::Orient, LoopPhase::Decide, LoopPhase::Act];
+
+    pub fn index(&self) -> usize {
+        match self {
+            LoopPhase::Observe => 0,
+            LoopPhase::Orient => 1,
+            LoopPhase::Decide => 2,
+            LoopPhase::Act => 3,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoopPhase::Observe => "observe",
+            LoopPhase::Orient => "orient",
+            LoopPhase::Decide => "decide",
+            LoopPhase::Act => "act",
+        }
+    }
+}
+
+/// Wall-clock allowance per phase. Orient carries the Brain round trip, Act the venue call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseBudgets {
+    pub observe: Duration,
+    pub orient: Duration,
+    pub decide: Duration,
+    pub act: Duration,
+}
+
+impl Default for PhaseBudgets {
+    fn default() -> Self {
+        Self {
+            observe: Duration::from_millis(1),
+            orient: Duration::from_millis(250), // D-84 cognitive lag threshold
+            decide: Duration::from_millis(1),
+            act: Duration::from_millis(50),
+        }
+    }
+}
+
+impl PhaseBudgets {
+    pub fn budget(&self, phase: LoopPhase) -> Duration {
+        match phase {
+            LoopPhase::Observe => self.observe,
+            LoopPhase::Orient => self.orient,
+            LoopPhase::Decide => self.decide,
+            LoopPhase::Act => self.act,
+        }
+    }
+}
+
+/// Running timing record for one phase
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhaseStats {
+    pub last_us: f64,
+    pub max_us: f64,
+    pub samples: u64,
+    pub overruns: u64,
+    pub streak: u64, // Consecutive overruns (0 once the phase is back in budget)
+}
+
 pub struct Sentinel {
     // Config
     jitter_threshold_us: f64,
@@ -33,31 +110,76 @@ pub struct Sentinel {
     pub current_latency_us: f64,
     pub current_jitter_us: f64,
     pub status: VitalityStatus,
+    budgets: PhaseBudgets,
+    phases: [PhaseStats; 4],
+    clock: SharedClock,
 }
 
 impl Sentinel {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             jitter_threshold_us: 50.0, // 50 microseconds (Directive-80)
             latency_threshold_us: 1000.0, // 1ms target for loop (Simulated/Real)
-            last_tick: Instant::now(),
+            last_tick: clock.now(),
             history: VecDeque::with_capacity(100),
-            last_instability: Instant::now(), // Assume unstable at boot
+            last_instability: clock.now(), // Assume unstable at boot
             current_latency_us: 0.0,
             current_jitter_us: 0.0,
             status: VitalityStatus::Optimal,
+            budgets: PhaseBudgets::default(),
+            phases: [PhaseStats::default(); 4],
+            clock,
         }
     }
 
+    pub fn with_budgets(mut self, budgets: PhaseBudgets) -> Self {
+        self.budgets = budgets;
+        self
+    }
+
+    pub fn budgets(&self) -> &PhaseBudgets {
+        &self.budgets
+    }
+
+    /// Record how long a phase took this cycle. Returns the excess over budget on an overrun.
+    pub fn record_phase(&mut self, phase: LoopPhase, elapsed: Duration) -> Option<Duration> {
+        let elapsed_us = elapsed.as_secs_f64() * 1_000_000.0;
+        let stats = &mut self.phases[phase.index()];
+        stats.last_us = elapsed_us;
+        stats.max_us = stats.max_us.max(elapsed_us);
+        stats.samples += 1;
+
+        let Some(excess) = elapsed.checked_sub(self.budgets.budget(phase)).filter(|d| !d.is_zero()) else {
+            stats.streak = 0;
+            return None;
+        };
+        stats.overruns += 1;
+        stats.streak += 1;
+        Some(excess)
+    }
+
+    pub fn phase_stats(&self, phase: LoopPhase) -> PhaseStats {
+        self.phases[phase.index()]
+    }
+
+    /// Budget overruns across all phases since boot
+    pub fn total_overruns(&self) -> u64 {
+        self.phases.iter().map(|s| s.overruns).sum()
+    }
+
     /// Check if system has been stable (Optimal) for at least the given duration
     pub fn is_stable_for(&self, duration: std::time::Duration) -> bool {
-        self.status == VitalityStatus::Optimal && self.last_instability.elapsed() >= duration
+        self.status == VitalityStatus::Optimal && self.clock.elapsed_since(self.last_instability) >= duration
     }
 
     /// Call this at the start/end of every OODA loop cycle
     pub fn tick(&mut self) -> VitalityStatus {
-        let now = Instant::now();
-        let elapsed = now.duration_since(self.last_tick).as_micros() as f64;
+        let now = self.clock.now();
+        let elapsed = now.saturating_duration_since(self.last_tick).as_micros() as f64;
         self.last_tick = now;
 
         // Update History
@@ -82,20 +204,86 @@ impl Sentinel {
             self.current_jitter_us = 0.0;
         }
 
-        // Determine Status
-        let new_status = if self.current_jitter_us > self.jitter_threshold_us * 2.0 {
+        // Determine Status: the worse of cycle jitter and repeated phase overruns
+        let jitter_status = if self.current_jitter_us > self.jitter_threshold_us * 2.0 {
             VitalityStatus::Critical
         } else if self.current_jitter_us > self.jitter_threshold_us {
             VitalityStatus::Degraded
         } else {
             VitalityStatus::Optimal
         };
+        let streak = self.phases.iter().map(|s| s.streak).max().unwrap_or(0);
+        let overrun_status = if streak >= OVERRUNS_CRITICAL {
+            VitalityStatus::Critical
+        } else if streak >= OVERRUNS_DEGRADED {
+            VitalityStatus::Degraded
+        } else {
+            VitalityStatus::Optimal
+        };
+        let new_status = jitter_status.max(overrun_status);
 
         if new_status != VitalityStatus::Optimal {
-             self.last_instability = Instant::now();
+             self.last_instability = now;
         }
         
         self.status = new_status;
         self.status
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_phase_budget_overruns() {
+        let clock = Arc::new(ManualClock::new());
+        let budgets = PhaseBudgets { decide: Duration::from_micros(500), ..PhaseBudgets::default() };
+        let mut sentinel = Sentinel::with_clock(clock).with_budgets(budgets);
+
+        // Within budget (and exactly on it) is not an overrun
+        assert_eq!(sentinel.record_phase(LoopPhase::Decide, Duration::from_micros(200)), None);
+        assert_eq!(sentinel.record_phase(LoopPhase::Decide, Duration::from_micros(500)), None);
+
+        let excess = sentinel.record_phase(LoopPhase::Decide, Duration::from_micros(800));
+        assert_eq!(excess, Some(Duration::from_micros(300)));
+
+        let stats = sentinel.phase_stats(LoopPhase::Decide);
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.overruns, 1);
+        assert!((stats.last_us - 800.0).abs() < 1e-6);
+        assert!((stats.max_us - 800.0).abs() < 1e-6);
+
+        // Phases are tracked independently
+        assert_eq!(sentinel.phase_stats(LoopPhase::Orient), PhaseStats::default());
+        assert!(sentinel.record_phase(LoopPhase::Act, Duration::from_millis(60)).is_some());
+        assert_eq!(sentinel.total_overruns(), 2);
+    }
+
+    #[test]
+    fn test_repeated_overruns_degrade_vitality() {
+        let clock = Arc::new(ManualClock::new());
+        let mut sentinel = Sentinel::with_clock(clock);
+        let slow = Duration::from_millis(300);
+
+        // Occasional overruns are tolerated
+        sentinel.record_phase(LoopPhase::Orient, slow);
+        sentinel.record_phase(LoopPhase::Orient, slow);
+        assert_eq!(sentinel.tick(), VitalityStatus::Optimal);
+
+        sentinel.record_phase(LoopPhase::Orient, slow);
+        assert_eq!(sentinel.tick(), VitalityStatus::Degraded);
+        for _ in 0..7 {
+            sentinel.record_phase(LoopPhase::Orient, slow);
+        }
+        assert_eq!(sentinel.tick(), VitalityStatus::Critical);
+        assert!(!sentinel.is_stable_for(Duration::ZERO));
+
+        // Back in budget clears the streak
+        sentinel.record_phase(LoopPhase::Orient, Duration::from_millis(10));
+        assert_eq!(sentinel.phase_stats(LoopPhase::Orient).streak, 0);
+        assert_eq!(sentinel.tick(), VitalityStatus::Optimal);
+    }
+}
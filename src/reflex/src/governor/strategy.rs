@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::governor::ooda_loop::OODAState;
+use crate::governor::regime_detector::MarketRegime;
+
+/// What the alpha sees about our book when it forms a view.
+#[derive(Debug, Clone, Copy)]
+pub struct PositionContext<'a> {
+    pub symbol: &'a str,
+    pub net_position: f64, // Signed base units
+    pub regime: MarketRegime,
+}
+
+/// Desired exposure, not an order: the governor turns this into an `Action`
+/// after vetoes, sizing and breakers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrategySignal {
+    pub target_position: f64, // -1.0 (full short) .. 1.0 (full long); 0.0 = flat
+    pub confidence: f64,      // 0.0 .. 1.0, feeds Kelly
+}
+
+impl StrategySignal {
+    pub fn flat() -> Self {
+        Self { target_position: 0.0, confidence: 0.0 }
+    }
+}
+
+/// Pluggable alpha for the DECIDE phase.
+pub trait Strategy: Send + Sync {
+    fn name(&self) -> &str;
+    fn evaluate(&self, state: &OODAState, ctx: &PositionContext) -> StrategySignal;
+}
+
+/// Simons (Physics): positive acceleration = long, otherwise short.
+/// The original DECIDE heuristic and the default for every symbol/regime.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SimonsMomentum;
+
+impl Strategy for SimonsMomentum {
+    fn name(&self) -> &str {
+        "Simons"
+    }
+
+    fn evaluate(&self, state: &OODAState, _ctx: &PositionContext) -> StrategySignal {
+        let target_position = if state.physics.acceleration > 0.0 { 1.0 } else { -1.0 };
+        StrategySignal { target_position, confidence: 0.9 }
+    }
+}
+
+/// Kepler (MeanRev): fades velocity once acceleration turns against it.
+/// Intended for Turbulent regimes; flat while the move is still accelerating.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeplerReversion;
+
+impl Strategy for KeplerReversion {
+    fn name(&self) -> &str {
+        "Kepler"
+    }
+
+    fn evaluate(&self, state: &OODAState, _ctx: &PositionContext) -> StrategySignal {
+        let p = &state.physics;
+        if p.velocity == 0.0 || p.velocity.signum() == p.acceleration.signum() {
+            return StrategySignal::flat();
+        }
+        StrategySignal { target_position: -p.velocity.signum(), confidence: 0.6 }
+    }
+}
+
+/// Strategy selection. Most specific wins: (symbol, regime) -> symbol -> regime -> default.
+pub struct StrategyRegistry {
+    default: Box<dyn Strategy>,
+    by_symbol_regime: HashMap<(String, MarketRegime), Box<dyn Strategy>>,
+    by_symbol: HashMap<String, Box<dyn Strategy>>,
+    by_regime: HashMap<MarketRegime, Box<dyn Strategy>>,
+}
+
+impl Default for StrategyRegistry {
+    fn default() -> Self {
+        Self::new(Box::new(SimonsMomentum))
+    }
+}
+
+impl StrategyRegistry {
+    pub fn new(default: Box<dyn Strategy>) -> Self {
+        Self {
+            default,
+            by_symbol_regime: HashMap::new(),
+            by_symbol: HashMap::new(),
+            by_regime: HashMap::new(),
+        }
+    }
+
+    pub fn set_default(&mut self, strategy: Box<dyn Strategy>) {
+        self.default = strategy;
+    }
+
+    pub fn register(&mut self, symbol: &str, regime: MarketRegime, strategy: Box<dyn Strategy>) {
+        self.by_symbol_regime.insert((symbol.to_string(), regime), strategy);
+    }
+
+    pub fn register_symbol(&mut self, symbol: &str, strategy: Box<dyn Strategy>) {
+        self.by_symbol.insert(symbol.to_string(), strategy);
+    }
+
+    pub fn register_regime(&mut self, regime: MarketRegime, strategy: Box<dyn Strategy>) {
+        self.by_regime.insert(regime, strategy);
+    }
+
+    pub fn select(&self, symbol: &str, regime: MarketRegime) -> &dyn Strategy {
+        self.by_symbol_regime
+            .get(&(symbol.to_string(), regime))
+            .or_else(|| self.by_symbol.get(symbol))
+            .or_else(|| self.by_regime.get(&regime))
+            .unwrap_or(&self.default)
+            .as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feynman::PhysicsState;
+
+    fn state(velocity: f64, acceleration: f64) -> OODAState {
+        OODAState {
+            physics: PhysicsState { price: 100.0, velocity, acceleration, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_selection_precedence() {
+        let mut registry = StrategyRegistry::default();
+        registry.register_regime(MarketRegime::Turbulent, Box::new(KeplerReversion));
+        registry.register_symbol("ETH-USDT", Box::new(KeplerReversion));
+        registry.register("ETH-USDT", MarketRegime::Laminar, Box::new(SimonsMomentum));
+
+        assert_eq!(registry.select("BTC-USDT", MarketRegime::Laminar).name(), "Simons");
+        assert_eq!(registry.select("BTC-USDT", MarketRegime::Turbulent).name(), "Kepler");
+        assert_eq!(registry.select("ETH-USDT", MarketRegime::Decoherent).name(), "Kepler");
+        assert_eq!(registry.select("ETH-USDT", MarketRegime::Laminar).name(), "Simons");
+    }
+
+    #[test]
+    fn test_builtin_signals() {
+        let ctx = PositionContext { symbol: "BTC-USDT", net_position: 0.0, regime: MarketRegime::Unknown };
+
+        assert_eq!(SimonsMomentum.evaluate(&state(1.0, 0.5), &ctx).target_position, 1.0);
+        assert_eq!(SimonsMomentum.evaluate(&state(1.0, -0.5), &ctx).target_position, -1.0);
+
+        // Still accelerating: Kepler stays out; decelerating rally: fade it
+        assert_eq!(KeplerReversion.evaluate(&state(1.0, 0.5), &ctx), StrategySignal::flat());
+        assert_eq!(KeplerReversion.evaluate(&state(1.0, -0.5), &ctx).target_position, -1.0);
+        assert_eq!(KeplerReversion.evaluate(&state(-1.0, 0.5), &ctx).target_position, 1.0);
+    }
+}
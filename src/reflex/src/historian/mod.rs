@@ -6,6 +6,8 @@ pub mod biopsy;
 use serde::Deserialize;
 use reqwest::Client;
 use crate::reflex_proto::PhysicsResponse;
+use crate::feynman::PhysicsState;
+use crate::governor::regime_detector::MarketRegime;
 use tokio::sync::mpsc;
 use error_chain::error_chain;
 
@@ -122,6 +124,9 @@ impl TickReader {
                             system_sanity_score: 1.0,
                             positions: vec![],
                             orders: vec![],
+                            halted: false,
+                            halt_reason: String::new(),
+                            regime: MarketRegime::Unknown.to_string(),
                         };
                         
                         if let Err(_) = tx.send(Ok(physics)).await {
@@ -134,4 +139,39 @@ impl TickReader {
 
         Ok(())
     }
+
+    /// Recorded physics states for offline training (e.g. the regime HMM).
+    pub async fn fetch_physics(
+        &self,
+        start_time_ms: f64,
+        end_time_ms: f64,
+    ) -> std::result::Result<Vec<PhysicsState>, Box<dyn std::error::Error + Send + Sync>> {
+        let query = format!(
+            "SELECT price, velocity, acceleration, jerk, entropy, efficiency_index, timestamp, sequence_id \
+             FROM physics \
+             WHERE timestamp >= {} AND timestamp <= {} \
+             ORDER BY timestamp ASC",
+             start_time_ms, end_time_ms
+        );
+        let url = format!("{}/exec?query={}", self.questdb_url, urlencoding::encode(&query));
+        let resp = self.client.get(&url).send().await?.json::<serde_json::Value>().await?;
+
+        let rows = resp.get("dataset").and_then(|d| d.as_array()).cloned().unwrap_or_default();
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                Some(PhysicsState {
+                    price: row[0].as_f64()?,
+                    velocity: row[1].as_f64()?,
+                    acceleration: row[2].as_f64()?,
+                    jerk: row[3].as_f64()?,
+                    entropy: row[4].as_f64()?,
+                    efficiency_index: row[5].as_f64()?,
+                    timestamp: row[6].as_f64()?,
+                    sequence_id: row[7].as_i64()? as u64,
+                    ..Default::default()
+                })
+            })
+            .collect())
+    }
 }
@@ -198,8 +198,17 @@ pub async fn fetch_account_data(api_key: &str, api_secret: &str) -> Result<(f64,
     if let Ok(op_res) = kraken_request(api_key, api_secret, "/0/private/OpenPositions", "docalcs=true").await {
         if let Some(obj) = op_res.as_object() {
             for (_, val) in obj {
-                let symbol = val.get("pair").and_then(|v| v.as_str()).unwrap_or("UNKNOWN").to_string();
+                let pair = val.get("pair").and_then(|v| v.as_str()).unwrap_or("UNKNOWN");
+                let symbol = crate::market::symbol::canonical(pair); // "XXBTZUSD" -> "BTC/USD"
                 let vol = val.get("vol").and_then(|v| v.as_str()).unwrap_or("0").parse::<f64>().unwrap_or(0.0);
+                // 'vol' is unsigned; the lot's direction is its 'type'
+                let side = match val.get("type").and_then(|v| v.as_str()).and_then(crate::ledger::Side::parse) {
+                    Some(side) => side,
+                    None => {
+                        warn!("⚠️ Kraken position {} without a type, skipped", pair);
+                        continue;
+                    }
+                };
                 let cost = val.get("cost").and_then(|v| v.as_str()).unwrap_or("0").parse::<f64>().unwrap_or(0.0);
                 let net = val.get("net").and_then(|v| v.as_str()).unwrap_or("0").parse::<f64>().unwrap_or(0.0); // Unrealized PnL? Check API. 'net' is usually PnL in docalcs mode.
                 // Actually 'net' might be something else. 'value' - 'cost' = pnl.
@@ -211,13 +220,16 @@ pub async fn fetch_account_data(api_key: &str, api_secret: &str) -> Result<(f64,
                 // Entry price = cost / vol
                 let entry = if vol != 0.0 { cost / vol } else { 0.0 };
 
+                // docalcs: 'value' is the lot marked at the current price
+                let mark = if vol != 0.0 && value > 0.0 { value / vol } else { 0.0 };
+
                 positions.push(PositionState {
                     symbol,
-                    net_size: vol,
+                    net_size: side.sign() * vol,
                     avg_entry_price: entry,
                     unrealized_pnl: pnl,
                     entry_timestamp: time * 1000, // s to ms
-                    current_price: 0.0, // Filled by ticker in main?
+                    current_price: mark, // Pipeline symbols are re-marked from the live feed in main
                 });
             }
         }
@@ -255,3 +267,11 @@ pub async fn fetch_account_data(api_key: &str, api_secret: &str) -> Result<(f64,
 
     Ok((zusd, xxbt, equity, total_unrealized, positions, orders))
 }
+
+/// Fresh signed holding of `symbol` straight from the venue (spot BTC plus margin lots),
+/// for reconciling an execution without waiting on the periodic account sync.
+pub async fn fetch_position(api_key: &str, api_secret: &str, symbol: &str) -> Result<f64, Box<dyn std::error::Error>> {
+    let (usd, btc, _, _, positions, _) = fetch_account_data(api_key, api_secret).await?;
+    let account = crate::ledger::AccountState::new(usd, btc);
+    Ok(account.position_in(symbol, positions.iter().map(|p| (p.symbol.as_str(), p.net_size))))
+}
@@ -1,4 +1,52 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Trade direction shared by the risk, sim and ledger layers.
+/// Replaces the "BUY"/"LONG"/"SELL" strings that used to diverge between callers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    /// Parses the wire/Brain vocabulary ("BUY", "LONG", "SELL", "SHORT").
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "BUY" | "LONG" => Some(Side::Buy),
+            "SELL" | "SHORT" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Buy => "BUY",
+            Side::Sell => "SELL",
+        }
+    }
+
+    /// +1.0 for Buy, -1.0 for Sell (signed quantity helper).
+    pub fn sign(&self) -> f64 {
+        match self {
+            Side::Buy => 1.0,
+            Side::Sell => -1.0,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountState {
@@ -38,9 +86,9 @@ impl AccountState {
     }
 
     /// Update local state based on an execution (Fill)
-    pub fn update_fill(&mut self, side: &str, price: f64, qty: f64) {
+    pub fn update_fill(&mut self, side: Side, price: f64, qty: f64) {
         match side {
-            "BUY" => {
+            Side::Buy => {
                 let cost = price * qty;
                 self.usdt_balance -= cost;
                 self.btc_position += qty;
@@ -52,12 +100,11 @@ impl AccountState {
                 // We'll assume locked_balance is managed separately or we decr it here.
                 // Let's keep it simple for Directive-09: Direct impact on balances.
             }
-            "SELL" => {
+            Side::Sell => {
                 let revenue = price * qty;
                 self.usdt_balance += revenue;
                 self.btc_position -= qty;
             }
-            _ => {}
         }
     }
 
@@ -68,6 +115,18 @@ impl AccountState {
         self.locked_balance = locked;
     }
 
+    /// Signed holding of `symbol`: the synced spot BTC balance (the only spot asset tracked)
+    /// plus venue margin lots of the same canonical instrument.
+    pub fn position_in<'a>(&self, symbol: &str, margin: impl IntoIterator<Item = (&'a str, f64)>) -> f64 {
+        let key = crate::market::symbol::canonical(symbol);
+        let spot = if crate::market::symbol::base(&key) == "BTC" { self.btc_position } else { 0.0 };
+        spot + margin
+            .into_iter()
+            .filter(|(s, _)| crate::market::symbol::canonical(s) == key)
+            .map(|(_, qty)| qty)
+            .sum::<f64>()
+    }
+
     pub fn available_balance(&self) -> f64 {
         self.usdt_balance - self.locked_balance
     }
@@ -106,7 +165,7 @@ mod tests {
         assert_eq!(account.current_drawdown_pct(50000.0), 0.0);
 
         // Buy 0.01 BTC @ 50,000 (Cost 500)
-        account.update_fill("BUY", 50000.0, 0.01);
+        account.update_fill(Side::Buy, 50000.0, 0.01);
         // USDT = 500, BTC = 0.01
         assert_eq!(account.usdt_balance, 500.0);
         assert_eq!(account.btc_position, 0.01);
@@ -118,4 +177,23 @@ mod tests {
         // Loss 100. Drawdown = 100 / 1000 = 0.10 (10%)
         assert!((account.current_drawdown_pct(40000.0) - 0.10).abs() < 1e-6);
     }
+
+    #[test]
+    fn test_position_in_symbol() {
+        let account = AccountState::new(1000.0, 0.5);
+        let margin = [("BTC/USD", -0.2), ("ETH/USD", 3.0), ("XXBTZUSD", 0.1)];
+        assert!((account.position_in("BTC-USDT", margin) - 0.4).abs() < 1e-12);
+        assert_eq!(account.position_in("ETH/USD", margin), 3.0);
+        assert_eq!(account.position_in("SOL/USD", margin), 0.0);
+    }
+
+    #[test]
+    fn test_side_parsing() {
+        assert_eq!(Side::parse("LONG"), Some(Side::Buy));
+        assert_eq!(Side::parse("buy"), Some(Side::Buy));
+        assert_eq!(Side::parse("SHORT"), Some(Side::Sell));
+        assert_eq!(Side::parse("HOLD"), None);
+        assert_eq!(Side::Sell.sign(), -1.0);
+        assert_eq!(Side::Buy.opposite(), Side::Sell);
+    }
 }
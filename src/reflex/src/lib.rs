@@ -18,6 +18,7 @@ pub mod db;
 pub mod server;
 pub mod sequencer;
 pub mod config; // Added config module
+pub mod clock;
 
 // Import the generated code
 pub mod reflex_proto {
@@ -11,7 +11,7 @@ use reflex::telemetry;
 use reflex::sim;
 use reflex::db;
 use reflex::ingest;
-use reflex::governor::sentinel; // D-80
+use reflex::governor::sentinel::{self, LoopPhase}; // D-80
 use reflex::governor::handoff::{HandoffManager, HandoffState}; // D-81
 
 // Proto imports via lib
@@ -27,7 +27,7 @@ use rand::Rng; // D-81
 use std::time::{Duration, Instant};
 use tracing::{info, warn, error};
 
-use reflex::governor::regime_detector::{RegimeDetector, MarketRegime}; // D-87
+use reflex::governor::regime_detector::RegimeDetector; // D-87
 use reflex::config::Config;
 
 #[tokio::main]
@@ -236,7 +236,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let shared_state = std::sync::Arc::new(std::sync::RwLock::new({
         let mut s = reflex::server::SharedState::default();
         if is_hotswap {
-            s.governance.staircase_tier = handoff_state.staircase_tier as i32;
             s.governance.staircase_progress = handoff_state.staircase_progress;
             s.governance.audit_drift = handoff_state.audit_drift;
         }
@@ -289,12 +288,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut ooda = reflex::governor::ooda_loop::OODACore::new("XBT/USD".to_string(), Some(forensic_tx), Some(mirror_tx), Some(decay_tx));
 >>>>>>> feb49d06 (pushing local changes.)
 
+    // D-86: Command Gate (operator keys + two-person rule)
+    let gate_config = match &config.sovereign_keys_path {
+        Some(path) => match reflex::governor::command_gate::GateConfig::load(path) {
+            Ok(gate_config) => {
+                info!("🔐 COMMAND GATE: {} operator keys from {} (two-person: {:?})", gate_config.operators.len(), path, gate_config.two_person);
+                gate_config
+            }
+            Err(e) => {
+                error!("❌ COMMAND GATE: {} ({}). Refusing all sovereign commands.", e, path);
+                reflex::governor::command_gate::GateConfig::default()
+            }
+        },
+        None => reflex::governor::command_gate::GateConfig::default(),
+    };
+    let command_gate = reflex::governor::command_gate::CommandGate::new(gate_config);
+
     // Spawn API Server
     let server_state = shared_state.clone();
     let server_tx = tx_broadcast.clone(); // Pass Sender for subscribing
     let server_auth_tx = authority_tx.clone();
     let _server_handle = tokio::spawn(async move {
-        reflex::server::run_server(server_state, server_tx, server_auth_tx).await;
+        reflex::server::run_server(server_state, server_tx, server_auth_tx, command_gate).await;
     });
 
 
@@ -304,49 +319,113 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let brain_url = "http://[::1]:50052".to_string(); 
     println!("🔌 Connecting to BrainD at {}...", brain_url);
     
-    let brain_client_opt = match client::BrainClient::connect(brain_url).await {
+    // Lazily dialled: a Brain that is down now (or dies later) is redialled behind the circuit breaker
+    let brain_client_opt = match client::BrainClient::lazy(brain_url) {
         Ok(c) => {
-            println!("✅ Connected to BrainD.");
+            c.spawn_heartbeat(client::HEARTBEAT_INTERVAL);
+            println!("✅ BrainD link armed (heartbeat every {:?}).", client::HEARTBEAT_INTERVAL);
             Some(c)
         },
         Err(e) => {
              // In prod this might be fatal, but for dev autonomous is okay
-            eprintln!("⚠️ Failed to connect to BrainD: {}. Running in AUTONOMOUS mode.", e);
+            eprintln!("⚠️ Invalid BrainD endpoint: {}. Running in AUTONOMOUS mode.", e);
             None
         }
     };
 
     // Components
+    // Single time source for every time-dependent governor (SimulatedClock in replays)
+    let clock = reflex::clock::system();
+    ooda.clock = clock.clone();
     let mut market = market::MarketData::new();
     let mut feynman = feynman::PhysicsEngine::new(2000);
     let mut _ledger = ledger::AccountState::new(50000.0, 0.0);
-    let _taleb = taleb::RiskGuardian::new();
+    let guardian = taleb::RiskGuardian::with_clock(clock.clone());
+    // D-22: Position-Aware Risk Shroud (trailing BES stops on what we hold)
+    let mut shroud = taleb::shroud::RiskShroud::new();
+    // Portfolio limits (gross/net exposure, concentration, VaR) over the venue book
+    let mut portfolio = taleb::portfolio::PortfolioRisk::default();
     let mut simons = simons::EchoStateNetwork::new(100);
-    let _execution = execution::actor::ExecutionAdapter::new();
-    // Directive-64: Safety Staircase (Real Instance)
-    let staircase_governor = reflex::governor::staircase::Staircase::new();
+    let execution_adapter = execution::actor::ExecutionAdapter::new();
     // Directive-79: Sequencer (Master Clock)
     let sequencer = reflex::sequencer::Sequencer::new();
     // Directive-80: Vitality Sentinel
-    let mut sentinel = sentinel::Sentinel::new();
+    let mut sentinel = sentinel::Sentinel::with_clock(clock.clone()).with_budgets(config.phase_budgets);
+
+    // D-87: Regime Detector (Hysteresis = 5 ticks); Gaussian HMM if a trained model is configured
+    let mut regime_detector = match &config.regime_model_path {
+        Some(path) => match reflex::governor::regime_hmm::GaussianHmm::load(path) {
+            Ok(hmm) => {
+                info!("🧭 REGIME: Loaded {}-state HMM from {}", hmm.n_states(), path);
+                RegimeDetector::with_hmm(5, hmm)
+            }
+            Err(e) => {
+                warn!("⚠️ REGIME: {} ({}). Falling back to thresholds.", e, path);
+                RegimeDetector::new(5)
+            }
+        },
+        None => RegimeDetector::new(5),
+    };
+
+    // D-95: Regime-to-Adapter map and demotion policy
+    if let Some(path) = &config.adapter_map_path {
+        match reflex::governor::ensemble_manager::EnsembleConfig::load(path) {
+            Ok(ensemble) => {
+                info!("🦎 ENSEMBLE: Loaded {} adapter mappings from {}", ensemble.adapters.len(), path);
+                ooda.ensemble_manager = reflex::governor::ensemble_manager::EnsembleManager::with_config(ensemble);
+            }
+            Err(e) => warn!("⚠️ ENSEMBLE: {} ({}). Using the built-in map.", e, path),
+        }
+    }
 
-    // D-87: Regime Detector (Hysteresis = 5 ticks)
-    let mut regime_detector = RegimeDetector::new(5);
+    // D-87: Cognitive Firewall rule set (per-rule toggles and tolerances, Brain schema range)
+    if let Some(path) = &config.firewall_path {
+        match reflex::auditor::firewall::FirewallConfig::load(path) {
+            Ok(rules) => {
+                info!("🧱 FIREWALL: Loaded rules from {} (Brain schema v{}..=v{})", path, rules.schema.min_version, rules.schema.max_version);
+                ooda.firewall = reflex::auditor::firewall::Firewall::with_config(rules);
+            }
+            Err(e) => warn!("⚠️ FIREWALL: {} ({}). Using the built-in rules.", e, path),
+        }
+    }
+
+    // Realized tail: VaR/ES method and the forecast horizon it samples returns at
+    ooda.tail = taleb::tail::TailEstimator::new(config.tail_method).with_horizon(config.tail_horizon);
+    info!("📉 TAIL: {:?} estimator, one return per {:?}", config.tail_method, config.tail_horizon);
 
     println!("Components Initialized.");
 
+    // Brain Forecast RPC, refreshed off-loop per symbol (TTL'd quantiles for Guardian, Shroud and Omega)
+    let mut forecasts = reflex::brain::forecast::ForecastService::with_clock(
+        reflex::brain::forecast::ForecastConfig::default(),
+        brain_client_opt.clone(),
+        clock.clone(),
+    );
+
     // Spawn Simulation Loop
     let mut client_clone = brain_client_opt; 
 
     println!("🔄 Simulation Loop Started.");
     let mut _last_processed_yield = Instant::now();
-    let tick_rate = Duration::from_micros(100); 
+    let tick_rate = Duration::from_micros(100); // Simulation pacing; live mode wakes on tick arrival
+    let idle_wake = Duration::from_millis(100); // Housekeeping cadence when the feed is quiet
+    const MAX_COALESCE: usize = 256; // Burst drained into one cycle
     let mut now_ms = 0.0;
 
     // --- Metrics Setup ---
     let meter = opentelemetry::global::meter("reflex_engine");
     let metrics = telemetry::metrics::EngineMetrics::new(&meter);
     let kv = [opentelemetry::KeyValue::new("mode", "simulation")];
+    let phase_kv = LoopPhase::ALL.map(|p| [kv[0].clone(), opentelemetry::KeyValue::new("phase", p.as_str())]);
+    let record_phase = |sentinel: &mut sentinel::Sentinel, phase: LoopPhase, started: Instant| {
+        let elapsed = started.elapsed();
+        let attrs = &phase_kv[phase.index()];
+        metrics.phase_duration.record(elapsed.as_secs_f64() * 1000.0, attrs);
+        if let Some(excess) = sentinel.record_phase(phase, elapsed) {
+            metrics.budget_overruns.add(1, attrs);
+            tracing::debug!("⏱️ {} over budget by {:?} ({:?})", phase.as_str(), excess, elapsed);
+        }
+    };
 
     // --- Directive-72: Ingestion Spawning ---
     let (ingest_tx, mut ingest_rx) = tokio::sync::mpsc::channel(100);
@@ -391,18 +470,104 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // --- Directive-66: Audit Loop (Real Instance) ---
-    let audit_loop = reflex::governor::audit_loop::AuditLoop::new();
+    let mut audit_loop = reflex::governor::audit_loop::AuditLoop::with_clock(clock.clone());
+
+    // Wave Legislator (Pre-Ignition): barriers from the volume profile and swing map
+    let mut level_map = reflex::governor::levels::LevelMap::default();
+    let mut wave_legislator = reflex::governor::wave_legislator::WaveLegislator::new(0.95);
+    audit_loop.load(&state_store, &mut wave_legislator).await;
+    let mut order_gateway = reflex::gateway::order_manager::OrderGateway::new(
+        config.kraken_api_key.clone(),
+        config.kraken_secret.clone(),
+    );
 
     // D-83: Ignition Sequence (Capital Gate)
-    let mut ignition = reflex::governor::ignition::IgnitionSequence::new();
+    let mut ignition = reflex::governor::ignition::IgnitionSequence::with_clock(clock.clone());
+    // Warm restart: same build, symbol and venue skip the hardware check and warmup
+    let ignition_fingerprint = format!(
+        "{}|XBT/USD|{}",
+        env!("CARGO_PKG_VERSION"),
+        if is_sim_mode_flag { "SIM" } else { "KRAKEN" }
+    );
+    ignition.load(&state_store, &ignition_fingerprint).await;
+    let mut penny_latency_ms = 0.0;
 
     // D-90: Rebalancer (The Governor)
-    let mut rebalancer = reflex::governor::rebalancer::Rebalancer::new(50000.0); // Match Ledger
+    let mut rebalancer = reflex::governor::rebalancer::Rebalancer::new(); // Anchored on first venue sync
+
+    // Tiered Drawdown Breakers (HWM persisted in DragonflyDB)
+    use reflex::governor::drawdown::{BreakerAction, DrawdownBreaker, DrawdownConfig};
+    let drawdown_config = match &config.drawdown_path {
+        Some(path) => match DrawdownConfig::load(path) {
+            Ok(loaded) => {
+                info!("📉 DRAWDOWN: Loaded breaker tiers from {}", path);
+                loaded
+            }
+            Err(e) => {
+                warn!("⚠️ DRAWDOWN: {} ({}). Using the built-in tiers.", e, path);
+                DrawdownConfig::default()
+            }
+        },
+        None => DrawdownConfig::default(),
+    };
+    let mut drawdown = DrawdownBreaker::new(
+        drawdown_config.with_timezone(&config.risk_timezone, config.risk_rollover_hour),
+    );
+    drawdown.load(&state_store).await;
+    let mut last_breaker_action = BreakerAction::None;
+    // The ledger holds a placeholder until the venue answers; marks must not be seeded from it
+    let mut ledger_synced = false;
+
+    // Trading-hours schedule: no new exposure (hibernation) outside the windows, taper into a close
+    use reflex::governor::schedule::{ScheduleConfig, SessionPhase, TradingSchedule};
+    let trading_schedule = match &config.schedule_path {
+        Some(path) => match ScheduleConfig::load(path).map_err(|e| e.to_string()).and_then(TradingSchedule::new) {
+            Ok(schedule) => {
+                info!("🗓️ SCHEDULE: {} windows, {} holidays, {} blackouts ({}) from {}",
+                    schedule.config.windows.len(), schedule.config.holidays.len(), schedule.config.blackouts.len(), schedule.config.timezone, path);
+                schedule
+            }
+            Err(e) => {
+                warn!("⚠️ SCHEDULE: {} ({}). Trading around the clock.", e, path);
+                TradingSchedule::always_open()
+            }
+        },
+        None => TradingSchedule::always_open(),
+    };
+    if let Ok(mut w) = shared_state.write() {
+        w.schedule = trading_schedule.config.clone();
+    }
+    let mut last_session_phase = SessionPhase::Open;
+
+    // Capital Allocator: per-pipeline budgets (risk parity / performance); one whole-account sleeve if unset
+    use reflex::taleb::allocator::{AllocatorConfig, CapitalAllocator};
+    let allocator_config = match &config.allocator_path {
+        Some(path) => match AllocatorConfig::load(path) {
+            Ok(cfg) => {
+                info!("💰 ALLOCATOR: {:?} over {} symbols, refresh {}s, reserve {:.0}% from {}",
+                    cfg.mode, cfg.sleeves.len(), cfg.refresh_secs, cfg.reserve * 100.0, path);
+                cfg
+            }
+            Err(e) => {
+                warn!("⚠️ ALLOCATOR: {} ({}). Whole account to {}.", e, path, ooda.symbol);
+                AllocatorConfig::for_symbols(&[ooda.symbol.as_str()])
+            }
+        },
+        None => AllocatorConfig::for_symbols(&[ooda.symbol.as_str()]),
+    };
+    let mut allocator = CapitalAllocator::with_clock(allocator_config, clock.clone());
+
+    // D-43 / D-64: Risk Ladder (Safety Staircase), replayed from its persisted event log
+    ooda.ladder = reflex::governor::risk_ladder::RiskLadder::with_clock(clock.clone());
+    ooda.ladder.load(&state_store).await;
 
     let mut last_equity = 0.0;
     let mut last_pnl = 0.0;
     let mut last_positions: Vec<PositionState> = Vec::new();
     let mut last_orders: Vec<OrderState> = Vec::new();
+    // Mark price per canonical symbol: live feed for the pipeline, venue marks for the rest
+    let pipeline_key = market::symbol::canonical(&ooda.symbol);
+    let mut marks: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
 
     loop {
         let loop_start = Instant::now();
@@ -453,20 +618,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 SovereignCommand::CloseAll => {
                     tracing::warn!("📛 SOVEREIGN CLOSE ALL POSITIONS");
-                    // Assuming `gateway` is available here, which it isn't in this snippet.
-                    // This part of the code needs to be adapted if `gateway` is not in scope.
-                    // For now, commenting out or assuming `gateway` exists.
-                    // match gateway.close_all_positions().await {
-                    //     Ok(count) => tracing::info!("✅ SOVEREIGN CLOSE COMPLETED. Closed: {}", count),
-                    //     Err(e) => tracing::error!("❌ SOVEREIGN CLOSE FAILED: {}", e),
-                    // }
+                    match order_gateway.close_all_positions().await {
+                        Ok(count) => tracing::info!("✅ SOVEREIGN CLOSE COMPLETED. Closed: {}", count),
+                        Err(e) => tracing::error!("❌ SOVEREIGN CLOSE FAILED: {}", e),
+                    }
                 }
                 SovereignCommand::Veto => {
                     tracing::warn!("⛔ SOVEREIGN VETO - Skipping this cycle");
                     continue; // Skip rest of OODA loop
                 }
+                SovereignCommand::CapTier { tier, duration_secs } => {
+                    let until = clock.unix_millis() + (duration_secs as i64).saturating_mul(1000);
+                    ooda.ladder.cap(tier, until, &format!("Sovereign CAP_TIER for {}s", duration_secs));
+                }
+                SovereignCommand::CancelAll => {
+                    let cancelled = ooda.cancel_working_orders();
+                    let primed = order_gateway.disarm();
+                    tracing::warn!("🧹 SOVEREIGN CANCEL ALL: {} working orders cancelled{} (positions kept)", cancelled, if primed { ", primed order dropped" } else { "" });
+                }
+                SovereignCommand::ResumeFromHalt => {
+                    let operators = authority_bridge
+                        .last_authorized()
+                        .map(|a| a.operators().join(" + "))
+                        .unwrap_or_default();
+                    if !ooda.resume(&operators) {
+                        tracing::warn!("▶️ RESUME FROM HALT by {}: not halted", operators);
+                    }
+                }
+                SovereignCommand::DemoteLadder(tier) => {
+                    let by = authority_bridge
+                        .last_authorized()
+                        .map(|a| format!("Operator {}: {}", a.operators().join(" + "), a.reason()))
+                        .unwrap_or_default();
+                    ooda.ladder.demote(tier, &by);
+                }
+                SovereignCommand::UpdateLegislation(change) => {
+                    if let Ok(mut w) = shared_state.write() {
+                        change.apply(&mut w.legislation);
+                    }
+                }
+                SovereignCommand::RollbackThreshold(id) => {
+                    if let Err(e) = audit_loop.rollback(id, &mut wave_legislator) {
+                        tracing::warn!("📐 THRESHOLD ROLLBACK refused: {}", e);
+                    }
+                }
                 _ => {
-                    // Pause, Resume, Sentiment changes handled by AuthorityBridge state
+                    // Pause, Resume, per-symbol Pause, Reduce-Only, Sentiment changes handled by AuthorityBridge state
                 }
             }
         }
@@ -489,9 +686,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // --- Directive-72: Consume Account Updates ---
         if let Ok((usd, btc, equity, pnl, positions, orders)) = balance_rx.try_recv() {
              _ledger.sync(usd, btc, 0.0);
+             ledger_synced = true;
+             rebalancer.anchor(equity);
              last_equity = equity;
              last_pnl = pnl;
              last_positions = positions;
+             // Margin lots plus the spot BTC the pipeline buys (no venue cost basis: entered at the last mark)
+             let spot_entry = marks.get(&market::symbol::canonical("XBT/USD")).copied().unwrap_or(0.0);
+             shroud.sync_positions(
+                 last_positions
+                     .iter()
+                     .map(|p| (p.symbol.as_str(), p.net_size, p.avg_entry_price))
+                     .chain([("XBT/USD", btc, spot_entry)]),
+             );
+             for p in last_positions.iter().filter(|p| p.current_price > 0.0) {
+                 let key = market::symbol::canonical(&p.symbol);
+                 if key != pipeline_key {
+                     portfolio.update_price(&key, p.current_price);
+                 }
+                 marks.insert(key, p.current_price);
+             }
+             // Venue book: margin lots plus the spot BTC balance
+             portfolio.sync_positions(
+                 last_positions
+                     .iter()
+                     .map(|p| (market::symbol::canonical(&p.symbol), p.net_size))
+                     .chain([(market::symbol::canonical("XBT/USD"), btc)]),
+             );
              last_orders = orders;
              info!("🏦 Ledger Synced: USD=${:.2} BTC={:.8} Equity=${:.2}", usd, btc, equity);
         }
@@ -517,12 +738,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
              (100.0 + signal + noise + spike, 0.1, 1.0)
 >>>>>>> feb49d06 (pushing local changes.)
         } else {
-             match tokio::time::timeout(Duration::from_millis(100), ingest_rx.recv()).await {
-                 Ok(Some(tick)) => {
+             // Wake on arrival; fold whatever queued behind it into one observation
+             match tokio::time::timeout(idle_wake, ingest_rx.recv()).await {
+                 Ok(Some(first)) => {
+                     let mut tick = first;
+                     let mut coalesced = 0;
+                     while coalesced < MAX_COALESCE {
+                         match ingest_rx.try_recv() {
+                             Ok(next) => {
+                                 tick.absorb(next);
+                                 coalesced += 1;
+                             }
+                             Err(_) => break,
+                         }
+                     }
+                     if coalesced > 0 {
+                         metrics.ticks_coalesced.add(coalesced as u64, &kv);
+                     }
                      now_ms = tick.timestamp as f64;
 <<<<<<< HEAD
                      market.update_book(tick.bid, tick.ask);
-                     (tick.price, 0.0)
+                     (tick.price, tick.quantity)
 =======
                      let spread = if let (Some(bid), Some(ask)) = (tick.bid, tick.ask) {
                          ask - bid
@@ -537,7 +773,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                      break; 
                  },
                  Err(_) => {
-                     now_ms += 100.0;
+                     now_ms += idle_wake.as_secs_f64() * 1000.0;
 <<<<<<< HEAD
                      (market.price, 0.0)
 =======
@@ -546,11 +782,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                  }
              }
         };
+        let observe_start = Instant::now();
         
         metrics.heartbeat.add(1, &kv);
         metrics.market_price.record(price, &kv);
         
         market.update_price(price);
+        if price > 0.0 {
+            marks.insert(pipeline_key.clone(), price);
+            portfolio.update_price(&pipeline_key, price);
+        }
 
         // D-82: Zero-Copy Logging (Market Tick)
         // Explicitly recording the tick event to shared memory
@@ -586,14 +827,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ));
 
         // D-87: REGIME DETECTION
-        // Map Physics Efficiency -> Coherence
-        // Map Physics Entropy -> Entropy
-        let market_regime = regime_detector.update(state.efficiency_index, state.entropy);
-        let regime_id: u8 = match market_regime {
-            MarketRegime::Laminar => 1,
-            MarketRegime::Turbulent => 2,
-            MarketRegime::Decoherent => 3,
-        };
+        // Thresholds: Physics Efficiency -> Coherence, Physics Entropy -> Entropy
+        // HMM: efficiency, entropy and jerk (see regime_hmm::features)
+        let market_regime = regime_detector.observe(&state);
+
+        // Wave Legislator: nearest barrier ahead of the move -> tunneling verdict -> primed order
+        level_map.update(price, volume);
+        forecasts.observe_volume(&ooda.symbol, volume);
+        forecasts.refresh(&ooda.symbol, &feynman);
+        if let Some(barrier) = level_map.nearest_barrier(price, state.velocity) {
+            let potential = barrier.potential(level_map.config.horizon);
+            let verdict = wave_legislator.evaluate_tunneling(state.velocity, state.entropy, price, potential);
+            let was_primed = order_gateway.primed().is_some();
+            if !ooda.is_halted() {
+                order_gateway.prime_order(&verdict, &ooda.symbol, price);
+            }
+            if let (false, Some(order)) = (was_primed, order_gateway.primed()) {
+                info!("🌊 TUNNELING: {:?} through {:.2} {:?} -> primed {:?} @ {:?}", verdict, barrier.price, barrier.sources, order.side, order.price);
+            }
+        }
+        // The primed order rests at its target: our own orders must not cross it
+        ooda.pre_trade.remove_resting(reflex::gateway::order_manager::PRIMED_ORDER_ID);
+        if let Some(resting) = order_gateway.resting() {
+            ooda.pre_trade.add_resting(resting);
+        }
+        for fill in ooda.drain_fills() {
+            audit_loop.register_trade(fill);
+        }
+        audit_loop.recalibrate_legislator(&mut wave_legislator);
+        record_phase(&mut sentinel, LoopPhase::Observe, observe_start);
 
         // --- Directive-80: Sentinel Check (Moved Early for D-83) ---
         let vitality = sentinel.tick();
@@ -602,7 +864,63 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let market_active = true; // Assumed true if we are in this loop iteration (otherwise we break/timeout)
         ignition.update(&sentinel, market_active);
 
+        // D-83 Gate 3: Penny round trip through the venue, reconciled against fresh venue balances
+        if let Some(order) = ignition.penny_order(price) {
+            let (key, secret) = (config.kraken_api_key.as_str(), config.kraken_secret.as_str());
+            match ingest::kraken::fetch_position(key, secret, &ooda.symbol).await.map_err(|e| e.to_string()) {
+                Ok(position_before) => {
+                    let sent_at = clock.now();
+                    match execution_adapter.execute_round_trip(order.price, order.qty).await {
+                        Ok((bought, sold)) => {
+                            penny_latency_ms = clock.elapsed_since(sent_at).as_secs_f64() * 1000.0;
+                            match ingest::kraken::fetch_position(key, secret, &ooda.symbol).await.map_err(|e| e.to_string()) {
+                                Ok(position_after) => {
+                                    ignition.confirm_penny_trade(&reflex::governor::ignition::PennyFill {
+                                        id: order.id,
+                                        bought,
+                                        sold,
+                                        position_before,
+                                        position_after,
+                                    });
+                                }
+                                Err(e) => ignition.penny_failed(&format!("venue balance after round trip: {}", e)),
+                            }
+                        }
+                        Err(e) => ignition.penny_failed(&e),
+                    }
+                }
+                Err(e) => ignition.penny_failed(&format!("venue balance before round trip: {}", e)),
+            }
+        }
+
+        // D-83 Gate 4: Brain blessing, bounded by BLESSING_TIMEOUT
+        if ignition.blessing_due() {
+            match client_clone.as_mut() {
+                Some(brain) => {
+                    let request = client::brain::BlessingRequest {
+                        symbol: "XBT/USD".to_string(),
+                        price,
+                        velocity: state.velocity,
+                        entropy: state.entropy,
+                        regime: market_regime.id() as i32,
+                        penny_latency_ms,
+                        timestamp: clock.unix_millis(),
+                    };
+                    let timeout = reflex::governor::ignition::BLESSING_TIMEOUT;
+                    match tokio::time::timeout(timeout, brain.request_blessing(request)).await {
+                        Ok(Ok(verdict)) if verdict.blessed => ignition.confirm_gemma_blessing(),
+                        Ok(Ok(verdict)) => ignition.deny_gemma_blessing(&verdict.reason),
+                        Ok(Err(status)) => ignition.blessing_unavailable(status.message()),
+                        Err(_) => ignition.blessing_unavailable("timeout"),
+                    }
+                }
+                None => ignition.blessing_unavailable("Brain not connected"),
+            }
+        }
+        ignition.persist(&state_store).await;
+
         // D-107: Fetch Legislative State (Reader)
+        let orient_start = Instant::now();
         let legislation = if let Ok(r) = shared_state.read() {
             r.legislation.clone()
         } else {
@@ -610,17 +928,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         };
         
         let legislative_bias_str = format!("{:?}", legislation.bias).to_uppercase();
+        ooda.hurdle_rate = legislation.hurdle_rate;
+
+        // Trading schedule / Legislative hibernation (D-107): Closed = no new exposure
+        let mut session = trading_schedule.status(chrono::DateTime::from(clock.system_time()));
+        if legislation.hibernation && session.phase != SessionPhase::Closed {
+            session.phase = SessionPhase::Closed;
+            session.reason = "Hibernation (Legislature)".to_string();
+            session.closes_at_ms = None;
+            session.taper = 0.0;
+        }
+        if session.phase != last_session_phase {
+            match session.phase {
+                SessionPhase::Closed => warn!("💤 HIBERNATION: {} (no new exposure)", session.reason),
+                SessionPhase::Tapering => warn!("🌇 SESSION TAPER: {}", session.reason),
+                SessionPhase::Open => info!("🌅 SESSION OPEN"),
+            }
+            last_session_phase = session.phase;
+        }
+        ooda.session_taper = (session.phase == SessionPhase::Tapering).then_some(session.taper);
+
+        let brain_forecast = forecasts.get(&ooda.symbol);
+        ooda.brain_forecast = brain_forecast.map(|f| f.quantiles);
 
         // --- D-50: OODA Execution ---
         // Gated by Ignition State
-        let mut ooda_state = if ignition.state == reflex::governor::ignition::IgnitionState::Ignited {
-             ooda.orient(state.clone(), regime_id, client_clone.as_mut(), legislative_bias_str).await
-        } else {
-             if ignition.state == reflex::governor::ignition::IgnitionState::PennyTrade {
-                 ooda.orient(state.clone(), regime_id, client_clone.as_mut(), legislative_bias_str).await
-             } else {
-                 reflex::governor::ooda_loop::OODAState::default() 
+        // Orient is held to its phase budget: past it, this cycle flies on blind physics
+        let orienting = matches!(
+            ignition.state,
+            reflex::governor::ignition::IgnitionState::Ignited | reflex::governor::ignition::IgnitionState::PennyTrade
+        );
+        let mut ooda_state = if orienting {
+             let budget = sentinel.budgets().orient;
+             match tokio::time::timeout(budget, ooda.orient(state.clone(), market_regime, client_clone.as_mut(), legislative_bias_str)).await {
+                 Ok(oriented) => oriented,
+                 Err(_) => {
+                     warn!("⏱️ ORIENT over its {:?} budget - Blind Physics this cycle", budget);
+                     reflex::governor::ooda_loop::OODAState { physics: state.clone(), regime: market_regime, ..Default::default() }
+                 }
              }
+        } else {
+             reflex::governor::ooda_loop::OODAState::default()
         };
         
         // D-86: Sentiment Override
@@ -628,6 +976,55 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
              ooda_state.sentiment_score = Some(val);
              tracing::info!("🎚️ SENTIMENT OVERRIDE APPLIED: {:.2}", val);
         }
+        record_phase(&mut sentinel, LoopPhase::Orient, orient_start);
+
+        // Drawdown Breakers (Daily / Weekly / Peak) with timezone-aware SOD rollover
+        let decide_start = Instant::now();
+        let equity_now = if last_equity > 0.0 { last_equity } else { _ledger.total_equity(price) };
+        let breaker = if ledger_synced {
+            let status = drawdown.update(equity_now, chrono::DateTime::from(clock.system_time()));
+            if let Some(sod) = drawdown.start_of_day_equity() {
+                _ledger.set_start_of_day(sod);
+            }
+            drawdown.persist(&state_store).await;
+            status
+        } else {
+            reflex::governor::drawdown::BreakerStatus::idle()
+        };
+        ooda.ladder.persist(&state_store).await;
+        audit_loop.persist(&state_store).await;
+        if breaker.action != last_breaker_action {
+            warn!("🧯 DRAWDOWN BREAKER: {:?} -> {:?} {:?}", last_breaker_action, breaker.action, breaker.tripped);
+            last_breaker_action = breaker.action;
+        }
+        ooda.drawdown_action = breaker.action;
+        ooda.reduce_only = authority_bridge.is_reduce_only(); // D-86
+        // Pipeline holding: spot plus venue margin lots of this symbol (not the raw BTC balance)
+        let pipeline_position = _ledger.position_in(&ooda.symbol, last_positions.iter().map(|p| (p.symbol.as_str(), p.net_size)));
+        ooda.net_position = pipeline_position;
+        ooda.sizing.drawdown_scale = if breaker.action >= BreakerAction::ReduceSize {
+            drawdown.config.reduce_size_factor
+        } else {
+            1.0
+        };
+
+        // Capital Allocator: mark the active sleeve, refresh weights on schedule, cap this pipeline
+        let active_strategy = ooda.strategies.select(&ooda.symbol, market_regime).name().to_string();
+        allocator.update_equity(equity_now);
+        allocator.observe(&ooda.symbol, &active_strategy, pipeline_position, price);
+        if allocator.maybe_refresh() {
+            for sleeve in allocator.snapshot() {
+                tracing::debug!("💰 ALLOCATION: {} {} -> {:.1}% (${:.2})", sleeve.symbol, sleeve.strategy.as_deref().unwrap_or("*"), sleeve.weight * 100.0, sleeve.capital);
+            }
+        }
+        ooda.allocation = allocator.allocation(&ooda.symbol);
+        if ooda.allocation.is_none() {
+            tracing::debug!("💰 ALLOCATION: no sleeve for {} (uncapped)", ooda.symbol);
+        }
+
+        // Sizing Pipeline Inputs (Account, D-90 Fidelity)
+        ooda.sizing.equity = if last_equity > 0.0 { Some(last_equity) } else { None };
+        ooda.sizing.fidelity = rebalancer.fidelity;
 
         let decision = if ignition.state == reflex::governor::ignition::IgnitionState::Ignited {
              ooda.decide(&ooda_state, &legislation)
@@ -635,18 +1032,130 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
              reflex::governor::ooda_loop::Decision::default_hold() // Force Hold
         };
 
+        // Closed session: no new exposure. Halt, Reduce and orders that only shrink the position still go out
+        let decision = match decision.action {
+            reflex::governor::ooda_loop::Action::Buy(qty) | reflex::governor::ooda_loop::Action::Sell(qty)
+                if session.phase == SessionPhase::Closed =>
+            {
+                let signed = if matches!(decision.action, reflex::governor::ooda_loop::Action::Buy(_)) { qty } else { -qty };
+                let shrinks = pipeline_position * signed < 0.0 && qty <= pipeline_position.abs();
+                if shrinks {
+                    decision
+                } else {
+                    reflex::governor::ooda_loop::Decision {
+                        action: reflex::governor::ooda_loop::Action::Hold,
+                        reason: format!("Hibernation: {}", session.reason),
+                        confidence: 1.0,
+                    }
+                }
+            }
+            _ => decision,
+        };
+
+        // D-20: Risk Guardian on new exposure (forecast TTL, physics, Omega sieve, capital)
+        let decision = match decision.action {
+            reflex::governor::ooda_loop::Action::Buy(qty) | reflex::governor::ooda_loop::Action::Sell(qty) => {
+                let side = if matches!(decision.action, reflex::governor::ooda_loop::Action::Buy(_)) { ledger::Side::Buy } else { ledger::Side::Sell };
+                let proposal = taleb::TradeProposal { side, price, qty };
+                let verdict = match guardian.check_forecast(&state, &_ledger, &proposal, brain_forecast.as_ref(), legislation.hurdle_rate, Some(&ooda.tail)) {
+                    // Portfolio limits only once the single-trade gate passes (as in the sim)
+                    taleb::RiskVerdict::Allowed => match guardian.check_portfolio(&portfolio, &pipeline_key, &proposal, equity_now) {
+                        taleb::RiskVerdict::Veto(reason) => taleb::RiskVerdict::Veto(format!("Portfolio: {}", reason)),
+                        other => other,
+                    },
+                    other => other,
+                };
+                match verdict {
+                    taleb::RiskVerdict::Allowed => decision,
+                    taleb::RiskVerdict::Veto(reason) => {
+                        metrics.risk_vetos.add(1, &kv);
+                        reflex::governor::ooda_loop::Decision {
+                            action: reflex::governor::ooda_loop::Action::Hold,
+                            reason: format!("Risk Guardian: {}", reason),
+                            confidence: 1.0,
+                        }
+                    }
+                    taleb::RiskVerdict::Panic => reflex::governor::ooda_loop::Decision {
+                        action: reflex::governor::ooda_loop::Action::Halt,
+                        reason: "Risk Guardian: PANIC (Black Swan Jerk)".to_string(),
+                        confidence: 1.0,
+                    },
+                }
+            }
+            _ => decision,
+        };
+        record_phase(&mut sentinel, LoopPhase::Decide, decide_start);
+
+        // D-22: Shroud every open position; breach -> Nuclear Exit (IOC)
+        let act_start = Instant::now();
+        // Stops trail only a fresh Brain horizon forecast; without one they hold where they are
+        // (per-tick realized quantiles sit a few bp under the price and would ratchet into every dip)
+        if let Some(f) = ooda.brain_forecast {
+            shroud.update_quantiles(&ooda.symbol, f.p10, f.p50, f.p90);
+        }
+        let breaches: Vec<_> = shroud
+            .positions()
+            .filter_map(|p| {
+                let mark = *marks.get(&p.symbol)?; // Unmarked symbols wait for the next venue sync
+                match guardian.check_position_shroud(&shroud, &p.symbol, mark) {
+                    taleb::shroud::ShroudVerdict::NuclearExit(reason) => Some((p.clone(), mark, reason)),
+                    taleb::shroud::ShroudVerdict::Safe => None,
+                }
+            })
+            .collect();
+        for (pos, mark, reason) in breaches {
+            let exit = taleb::TradeProposal { side: pos.side.opposite(), price: mark, qty: pos.qty };
+            // The position stays shrouded (and the exit retried next cycle) until the IOC is confirmed
+            let budget = sentinel.budgets().act;
+            match tokio::time::timeout(budget, execution_adapter.execute_nuclear(&exit, &reason)).await {
+                Ok(Ok(())) => shroud.remove(&pos.symbol),
+                Ok(Err(e)) => tracing::error!("❌ SHROUD EXIT {} {:.6} failed: {}", pos.symbol, pos.qty, e),
+                Err(_) => tracing::error!("⏱️ SHROUD EXIT {} {:.6} unconfirmed after the {:?} act budget", pos.symbol, pos.qty, budget),
+            }
+        }
+
         // 4. ACT (Execution)
-        // D-86: Tactical Pause - Skip Gateway if paused
-        if !authority_bridge.is_paused() {
-            ooda.act(decision.clone(), price);
+        // D-86: Tactical Pause (global or this symbol) - Skip Gateway if paused (a Halt still goes through)
+        let is_halt = matches!(decision.action, reflex::governor::ooda_loop::Action::Halt);
+        let paused = authority_bridge.is_paused() || authority_bridge.is_symbol_paused(&ooda.symbol);
+        if !paused || is_halt {
+            // The venue call is held to the Act budget; an unconfirmed risk-off order is surfaced, not waited on
+            let budget = sentinel.budgets().act;
+            match ooda.act(decision.clone(), price) {
+                Some(reflex::governor::ooda_loop::RiskOffOrder::Trim(order)) => {
+                    if tokio::time::timeout(budget, execution_adapter.execute_sniper(&order)).await.is_err() {
+                        tracing::error!("⏱️ TRIM {} {:.6} unconfirmed after the {:?} act budget", order.side, order.qty, budget);
+                    }
+                }
+                Some(reflex::governor::ooda_loop::RiskOffOrder::Flatten(order, reason)) => {
+                    match tokio::time::timeout(budget, execution_adapter.execute_nuclear(&order, &reason)).await {
+                        Ok(Ok(())) => {}
+                        Ok(Err(e)) => tracing::error!("❌ FLATTEN {} {:.6} failed: {} ({})", order.side, order.qty, e, reason),
+                        Err(_) => tracing::error!("⏱️ FLATTEN {} {:.6} unconfirmed after the {:?} act budget ({})", order.side, order.qty, budget, reason),
+                    }
+                }
+                None => {}
+            }
+            // A latched Halt also drops the order primed at the barrier
+            if ooda.is_halted() && order_gateway.disarm() {
+                tracing::warn!("🛑 HALT: primed gateway order disarmed");
+            }
         } else {
              tracing::debug!("⏸️ Tactical Pause - Skipping Gateway Execution");
         }
+        record_phase(&mut sentinel, LoopPhase::Act, act_start);
 
         // Update Shared State (For API)
         if let Ok(mut w) = shared_state.write() {
             w.physics = state.clone(); 
             w.ooda = Some(ooda_state.clone());
+            w.halt_reason = ooda.halt_latch().map(|l| l.reason.clone());
+            w.ensemble = ooda.ensemble_manager.snapshot();
+            w.session = session.clone();
+            if let Some(brain) = client_clone.as_ref() {
+                w.brain = brain.health();
+                w.brain.schema_mismatch = ooda.brain_schema_mismatch();
+            }
             
             // Directive-72: Update Account Link
             // Directive-72: Update Account Link
@@ -682,9 +1191,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }     
             
             // Directive-64: Update Governance Link
-            w.governance.staircase_tier = staircase_governor.tier();
-            w.governance.staircase_progress = staircase_governor.progress();
+            w.governance.staircase_tier = ooda.ladder.effective_tier().index() as i32;
+            w.governance.staircase_progress = ooda.ladder.progress();
+            w.risk_ladder = ooda.ladder.snapshot();
             w.governance.audit_drift = audit_loop.drift_score;
+            w.audit = audit_loop.snapshot(&wave_legislator);
 
             // Check Veto (Directive-68 & 72)
             if w.veto_active {
@@ -698,12 +1209,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             w.vitality.latency_us = sentinel.current_latency_us;
             w.vitality.jitter_us = sentinel.current_jitter_us;
             w.vitality.status = format!("{:?}", sentinel.status);
+            w.vitality.phase_overruns = sentinel.total_overruns();
             
             // D-90: System Sanity & Omega Protocol
             w.governance.system_sanity_score = rebalancer.fidelity;
             
-            // Omega Kill-Switch
-            if rebalancer.check_omega(w.account.equity) {
+            // Omega Kill-Switch: peak-to-trough breaker from the persisted HWM
+            if breaker.action == BreakerAction::Halt {
                 // Trigger Kill
                 tracing::error!("💀 OMEGA PROTOCOL EXECUTED. DROPPING KEYS.");
                 w.veto_active = true;
@@ -751,8 +1263,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             // Emergency Cool-Down (D-80)
             if vitality == sentinel::VitalityStatus::Critical {
                 warn!("🔥 SENTINEL CRITICAL: Jitter High ({:.2}us). Forcing Cool-down.", sentinel.current_jitter_us);
-                // Force Staircase Demotion (if we had access to mutable staircase here, but we update status)
-                // For now, we rely on the status being broadcast to FE and Safety
+                ooda.ladder.demote_to_floor("Sentinel critical jitter");
             }
             
             // Directive-81: Simulated Reasoning Trace (for now, until Brain Bridge)
@@ -804,7 +1315,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
              println!("⚠️ REFLEX: High Velocity Event! V={:.2}", state.velocity);
         }
 
-        tokio::time::sleep(tick_rate).await;
+        if is_sim_mode_flag {
+            tokio::time::sleep(tick_rate).await;
+        }
         metrics.loop_duration.record(loop_start.elapsed().as_secs_f64() * 1000.0, &kv);
     }
     
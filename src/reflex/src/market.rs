@@ -4,6 +4,7 @@ use serde::Deserialize;
 // Sub-modules
 // ==============================================================================
 pub mod kraken;
+pub mod symbol; // Canonical instrument keys across feed, venue and pipelines
 
 // ==============================================================================
 // 1. Internal Generalized Tick
@@ -21,6 +22,18 @@ pub struct Tick {
     pub ask: Option<f64>,
 }
 
+impl Tick {
+    /// Fold a later tick of the same burst into this one: latest price, quote and
+    /// timestamp win, traded quantity accumulates.
+    pub fn absorb(&mut self, later: Tick) {
+        self.timestamp = later.timestamp;
+        self.price = later.price;
+        self.quantity += later.quantity;
+        self.bid = later.bid.or(self.bid);
+        self.ask = later.ask.or(self.ask);
+    }
+}
+
 // ==============================================================================
 // 2. Binance Incoming Message (JSON)
 // ==============================================================================
@@ -0,0 +1,66 @@
+// ==============================================================================
+// Canonical Symbols
+// ==============================================================================
+// One key per instrument across the feed, the venue account and the pipelines:
+// "XXBTZUSD" (Kraken REST), "XBT/USD" (Kraken WS) and "BTC-USDT" all map to "BTC/USD".
+// USD stablecoin quotes fold onto USD: risk is booked per base asset against the dollar.
+
+const QUOTES: [&str; 7] = ["ZUSD", "USDT", "USDC", "USD", "ZEUR", "EUR", "ZGBP"];
+
+/// Venue asset code -> canonical asset.
+fn asset(code: &str) -> String {
+    let code = code.to_ascii_uppercase();
+    match code.as_str() {
+        "XBT" | "XXBT" => "BTC".to_string(),
+        "XDG" | "XXDG" => "DOGE".to_string(),
+        "ZUSD" | "USDT" | "USDC" => "USD".to_string(),
+        "ZEUR" => "EUR".to_string(),
+        "ZGBP" => "GBP".to_string(),
+        // Kraken's legacy X/Z prefixed four-letter codes (XETH, XLTC, ...)
+        c if c.len() == 4 && c.starts_with('X') => c[1..].to_string(),
+        c => c.to_string(),
+    }
+}
+
+/// Canonical "BASE/QUOTE" key for any of the spellings above. Unrecognized input is
+/// upper-cased and returned as-is, so it still matches itself.
+pub fn canonical(symbol: &str) -> String {
+    let s = symbol.trim().to_ascii_uppercase();
+    if let Some((base, quote)) = s.split_once(['/', '-', '_']) {
+        return format!("{}/{}", asset(base), asset(quote));
+    }
+    // Kraken REST pairs: XXBTZUSD, XETHZEUR
+    if s.len() == 8 && s.starts_with('X') && s.as_bytes()[4] == b'Z' {
+        return format!("{}/{}", asset(&s[..4]), asset(&s[4..]));
+    }
+    QUOTES
+        .iter()
+        .find(|q| s.len() > q.len() && s.ends_with(*q))
+        .map(|q| format!("{}/{}", asset(&s[..s.len() - q.len()]), asset(q)))
+        .unwrap_or(s)
+}
+
+/// Base asset of a symbol ("BTC" for "XXBTZUSD").
+pub fn base(symbol: &str) -> String {
+    let key = canonical(symbol);
+    match key.split_once('/') {
+        Some((base, _)) => base.to_string(),
+        None => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spellings_share_one_key() {
+        for s in ["XXBTZUSD", "XBT/USD", "BTC-USDT", "btc/usd", "XBTUSD", "BTCUSDT"] {
+            assert_eq!(canonical(s), "BTC/USD", "{}", s);
+        }
+        assert_eq!(canonical("XETHZEUR"), "ETH/EUR");
+        assert_eq!(canonical("SOL/USD"), "SOL/USD");
+        assert_eq!(canonical("UNKNOWN"), "UNKNOWN");
+        assert_eq!(base("XXBTZUSD"), "BTC");
+    }
+}
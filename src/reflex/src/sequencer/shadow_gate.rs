@@ -1,8 +1,10 @@
 use std::collections::HashMap;
-use std::time::{Instant, Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Instant, Duration};
 use serde::{Serialize, Deserialize};
 use tracing::{info, warn};
+use crate::clock::{self, SharedClock};
 use crate::governor::ooda_loop::{Decision, Action};
+use crate::governor::audit_loop::{TradeResult, TradeSide};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShadowOrder {
@@ -26,6 +28,9 @@ pub struct ShadowGate {
     pub symbol: String,
     pub virtual_book: HashMap<String, ShadowOrder>,
     pub latency_simulation_ms: u64,
+    position: f64, // Net filled shadow quantity
+    cash: f64, // Cash flow of the shadow fills
+    clock: SharedClock,
     pub symbol: String, // D-110: Parameterized Symbol
 }
 
@@ -34,22 +39,31 @@ impl ShadowGate {
         Self {
             symbol,
             virtual_book: HashMap::new(),
+            position: 0.0,
+            cash: 0.0,
+            clock: clock::system(),
             latency_simulation_ms: 500, // D-54: Exchange Latency Sim
             symbol,
         }
     }
 
-    /// Submits a virtual order to the shadow book
-    pub fn submit_order(&mut self, decision: &Decision, price: f64) {
+    pub fn with_clock(symbol: String, clock: SharedClock) -> Self {
+        let mut gate = Self::new(symbol);
+        gate.clock = clock;
+        gate
+    }
+
+    /// Submits a virtual order to the shadow book. Returns its id while it works.
+    pub fn submit_order(&mut self, decision: &Decision, price: f64) -> Option<String> {
         let (side, qty, limit_price) = match decision.action {
             Action::Buy(q) => ("BUY", q, price), // Market/Limit at current price
             Action::Sell(q) => ("SELL", q, price),
-            _ => return, // Hold/Halt -> No Order
+            _ => return None, // Hold/Halt -> No Order
         };
 
-        if qty <= 0.0 { return; }
+        if qty <= 0.0 { return None; }
 
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let now = self.clock.unix_nanos();
         let id = format!("{}-{}", side, now);
         
         // For simplicity in Phase 7, we treat these as "Limit Orders at Signal Price"
@@ -69,13 +83,32 @@ impl ShadowGate {
         };
 
         tracing::info!("👻 SHADOW ORDER SUBMITTED: {} {} @ {:.2}", side, qty, limit_price);
-        self.virtual_book.insert(id, order);
+        self.virtual_book.insert(id.clone(), order);
+        Some(id)
+    }
+
+    /// TRUE while the order is still working (neither filled nor cancelled).
+    pub fn is_pending(&self, id: &str) -> bool {
+        self.virtual_book.get(id).is_some_and(|o| o.status == ShadowStatus::Pending)
     }
 
-    /// Checks for fills based on current market price and simulated latency
-    pub fn check_fills(&mut self, current_price: f64) {
-        let mut filled_ids = Vec::new();
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    /// Cancels every pending virtual order. Returns how many were cancelled.
+    pub fn cancel_pending(&mut self) -> usize {
+        let mut cancelled = 0;
+        for order in self.virtual_book.values_mut() {
+            if order.status == ShadowStatus::Pending {
+                order.status = ShadowStatus::Cancelled;
+                cancelled += 1;
+            }
+        }
+        cancelled
+    }
+
+    /// Checks for fills based on current market price and simulated latency.
+    /// Fills pending orders the price has reached; each comes back against the price it was sent at.
+    pub fn check_fills(&mut self, current_price: f64) -> Vec<TradeResult> {
+        let mut fills = Vec::new();
+        let now = self.clock.unix_nanos();
         let latency_ns = (self.latency_simulation_ms as u128) * 1_000_000;
 
         for (id, order) in self.virtual_book.iter_mut() {
@@ -100,7 +133,7 @@ impl ShadowGate {
             };
 
             if is_fill {
-                let fill_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+                let fill_ts = self.clock.unix_nanos();
                 
                 // Calculate Slippage (Alpha Decay)
                 // Slippage = |Fill Price - Intended Price|
@@ -108,23 +141,38 @@ impl ShadowGate {
                 tracing::info!("👻 SHADOW FILL: {} Filled @ {:.2} (Slippage: {:.2})", id, current_price, slippage);
                 
                 order.status = ShadowStatus::Filled(current_price, fill_ts);
-                filled_ids.push(id.clone());
+                let signed_qty = if order.side == "BUY" { order.qty } else { -order.qty };
+                self.position += signed_qty;
+                self.cash -= signed_qty * current_price;
+                fills.push(TradeResult {
+                    predicted_price: order.limit_price,
+                    filled_price: current_price,
+                    side: if order.side == "BUY" { TradeSide::Buy } else { TradeSide::Sell },
+                });
             }
         }
         
         // Cleanup or Archive? For now we keep them to avoid reprocessing, 
         // but in prod we'd move them to a 'filled_log'.
+        fills
+    }
+
+    /// Shadow PnL of everything filled so far, marked at `price`.
+    pub fn pnl(&self, price: f64) -> f64 {
+        self.cash + self.position * price
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::thread;
+    use crate::clock::ManualClock;
+    use std::sync::Arc;
 
     #[test]
     fn test_shadow_fill_mechanics() {
-        let mut gate = ShadowGate::new("BTC-USDT".to_string());
+        let clock = Arc::new(ManualClock::new());
+        let mut gate = ShadowGate::with_clock("BTC-USDT".to_string(), clock.clone());
         // Lower latency for test speed
         gate.latency_simulation_ms = 10;
         
@@ -145,16 +193,22 @@ mod tests {
         assert_eq!(order.status, ShadowStatus::Pending);
         
         // 3. Wait > latency
-        thread::sleep(Duration::from_millis(15));
+        clock.advance(Duration::from_millis(15));
         
         // 4. Check Fills - Price still favorable (49990.0 < 50000.0)
-        gate.check_fills(49990.0);
-        
+        let fills = gate.check_fills(49990.0);
+        assert_eq!(fills.len(), 1);
+        assert!((fills[0].slippage_bps() + 2.0).abs() < 1e-9); // 10 below the limit: price improvement
+
         let order = gate.virtual_book.values().next().unwrap();
         if let ShadowStatus::Filled(price, _) = order.status {
             assert_eq!(price, 49990.0);
         } else {
             panic!("Order should be filled! Status: {:?}", order.status);
         }
+
+        // 5. The fill is marked to market: 0.5 bought at 49990
+        assert_eq!(gate.pnl(49990.0), 0.0);
+        assert!((gate.pnl(50010.0) - 10.0).abs() < 1e-9);
     }
 }
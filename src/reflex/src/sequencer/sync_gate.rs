@@ -1,5 +1,7 @@
 use std::time::{Duration, Instant};
 use tracing::warn;
+use crate::clock::{self, SharedClock};
+use crate::governor::regime_detector::MarketRegime;
 
 #[derive(Debug, Clone)]
 pub enum SyncError {
@@ -13,21 +15,27 @@ pub struct SyncGate {
     max_drift_bps: f64,
     max_staleness_ticks_low_entropy: u64,
     max_staleness_ticks_high_entropy: u64,
+    clock: SharedClock,
 }
 
 impl SyncGate {
     pub fn new() -> Self {
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             max_latency_ms: 500.0, // D-91 Atomic Clock
             max_drift_bps: 10.0,   // 0.1% Drift Detector
             max_staleness_ticks_low_entropy: 20,
             max_staleness_ticks_high_entropy: 5,
+            clock,
         }
     }
 
     /// Atomic Clock Check: Validates inference latency
     pub fn measure_latency(&self, start_time: Instant) -> Result<(), SyncError> {
-        let duration = start_time.elapsed().as_secs_f64() * 1000.0;
+        let duration = self.clock.elapsed_since(start_time).as_secs_f64() * 1000.0;
         if duration > self.max_latency_ms {
             return Err(SyncError::LatencyExceeded {
                 duration_ms: duration,
@@ -60,7 +68,7 @@ impl SyncGate {
     }
 
     /// Staleness Threshold: Validates sequence lag based on Regime
-    pub fn check_staleness(&self, start_seq: u64, current_seq: u64, regime_id: u8) -> Result<(), SyncError> {
+    pub fn check_staleness(&self, start_seq: u64, current_seq: u64, regime: MarketRegime) -> Result<(), SyncError> {
         if current_seq < start_seq {
             // Should be impossible if GSID is monotonic, but safety check
             return Ok(()); 
@@ -68,9 +76,10 @@ impl SyncGate {
 
         let lag = current_seq - start_seq;
         
-        let limit = match regime_id {
-            2 | 3 => self.max_staleness_ticks_high_entropy, // Turbulent/Violent (5 ticks)
-            _ => self.max_staleness_ticks_low_entropy,      // Laminar/Unknown (20 ticks)
+        let limit = if regime.is_high_entropy() {
+            self.max_staleness_ticks_high_entropy // Turbulent/Decoherent (5 ticks)
+        } else {
+            self.max_staleness_ticks_low_entropy // Laminar/Unknown (20 ticks)
         };
 
         if lag > limit {
@@ -91,6 +100,13 @@ mod tests {
         let gate = SyncGate::new();
         let start = Instant::now();
         assert!(gate.measure_latency(start).is_ok());
+
+        use crate::clock::{Clock, ManualClock};
+        let clock = std::sync::Arc::new(ManualClock::new());
+        let gate = SyncGate::with_clock(clock.clone());
+        let start = clock.now();
+        clock.advance(Duration::from_millis(501));
+        assert!(matches!(gate.measure_latency(start), Err(SyncError::LatencyExceeded { .. })));
     }
 
     #[test]
@@ -113,12 +129,13 @@ mod tests {
         let gate = SyncGate::new();
         let start_seq = 100;
         
-        // Laminar (Regime 0) -> Limit 20
-        assert!(gate.check_staleness(start_seq, 110, 0).is_ok());
-        assert!(matches!(gate.check_staleness(start_seq, 125, 0), Err(SyncError::Staleness{..}))); // > 20
+        // Laminar (Regime 1) -> Limit 20
+        assert!(gate.check_staleness(start_seq, 110, MarketRegime::Laminar).is_ok());
+        assert!(matches!(gate.check_staleness(start_seq, 125, MarketRegime::Laminar), Err(SyncError::Staleness{..}))); // > 20
 
-        // Turbulent (Regime 2) -> Limit 5
-        assert!(gate.check_staleness(start_seq, 103, 2).is_ok());
-        assert!(matches!(gate.check_staleness(start_seq, 106, 2), Err(SyncError::Staleness{..}))); // > 5
+        // Turbulent (Regime 2) / Decoherent (Regime 3) -> Limit 5
+        assert!(gate.check_staleness(start_seq, 103, MarketRegime::Turbulent).is_ok());
+        assert!(matches!(gate.check_staleness(start_seq, 106, MarketRegime::Turbulent), Err(SyncError::Staleness{..}))); // > 5
+        assert!(matches!(gate.check_staleness(start_seq, 106, MarketRegime::Decoherent), Err(SyncError::Staleness{..})));
     }
 }
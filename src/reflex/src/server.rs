@@ -1,6 +1,6 @@
 use tonic::{Request, Response, Status};
 use warp::Filter;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use tokio::sync::broadcast;
 use futures_util::{StreamExt, SinkExt};
 use serde::Serialize;
@@ -13,17 +13,29 @@ use crate::reflex_proto::{
     PositionState, OrderState, // D-105
     TickHistoryRequest, // D-106
     ClosePositionRequest, // D-106 (Flatten)
+    ResumeRequest,
+    EnsembleResponse, AdapterReport, AdapterSwap, // D-95
+    RiskLadderResponse, RiskLadderEvent,
     CancelOrderRequest, // D-109
     LegislativeUpdate, // D-107
     SovereignCommandRequest,
     sovereign_command_request::CommandType,
+    CommandLogResponse, CommandRecord, // D-86
+    ScheduleResponse, TradingWindow, Blackout,
+    ThresholdChangesResponse, RollbackRequest, // D-66
 };
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use crate::feynman::PhysicsState;
 use crate::governor::ooda_loop::OODAState;
-use crate::governor::legislator::{LegislativeState, StrategicBias};
-use crate::governor::authority::SovereignCommand;
+use crate::governor::legislator::{LegislativeChange, LegislativeState};
+use crate::governor::authority::{AuthorizedCommand, SovereignCommand};
+use crate::governor::command_gate::{Authorization, CommandGate, CommandOutcome, GateError, SignedCommand};
+use crate::governor::ensemble_manager::EnsembleSnapshot;
+use crate::governor::audit_loop::{AuditSnapshot, ChangeKind};
+use crate::governor::risk_ladder::{LadderSnapshot, RiskTier};
+use crate::governor::schedule::{ScheduleConfig, SessionStatus};
+use crate::client::BrainHealth;
 
 
 
@@ -61,6 +73,7 @@ pub struct VitalityState {
     pub latency_us: f64,
     pub jitter_us: f64,
     pub status: String,
+    pub phase_overruns: u64, // OODA phase budget overruns since boot
 }
 
 #[derive(Debug, Clone)]
@@ -77,6 +90,13 @@ pub struct SharedState {
     pub ignition_status: String, // D-83
     pub ignition_request: bool, // D-83 Trigger
     pub legislation: LegislativeState, // D-107
+    pub halt_reason: Option<String>, // Latched Halt (None = running)
+    pub ensemble: EnsembleSnapshot, // D-95: Adapter performance & selection history
+    pub risk_ladder: LadderSnapshot, // Tier + transition log
+    pub schedule: ScheduleConfig, // Active trading-hours schedule
+    pub session: SessionStatus, // Where it stands now (Closed also covers legislative hibernation)
+    pub brain: BrainHealth, // Brain link: circuit breaker + heartbeat
+    pub audit: AuditSnapshot, // D-66: Tunneling threshold + recalibration log
 }
 
 impl Default for SharedState {
@@ -93,6 +113,13 @@ impl Default for SharedState {
             ignition_status: "HIBERNATION".to_string(), // Default
             ignition_request: false,
             legislation: LegislativeState::default(),
+            halt_reason: None,
+            ensemble: EnsembleSnapshot::default(),
+            risk_ladder: LadderSnapshot::default(),
+            schedule: ScheduleConfig::default(),
+            session: SessionStatus::default(),
+            brain: BrainHealth::default(),
+            audit: AuditSnapshot::default(),
         }
     }
 }
@@ -108,6 +135,7 @@ struct KineticHUD {
     jerk: f64,
     entropy: f64,
     decision: String,
+    halt_reason: Option<String>,
 }
 
 // --- gRPC Service ---
@@ -115,7 +143,43 @@ struct KineticHUD {
 pub struct ReflexServerImpl {
     pub state: SafeState,
     pub tx: broadcast::Sender<SharedState>,
-    pub authority_tx: mpsc::UnboundedSender<SovereignCommand>,
+    pub authority_tx: mpsc::UnboundedSender<AuthorizedCommand>,
+    pub command_gate: Arc<Mutex<CommandGate>>, // D-86: Only signed commands reach the bridge
+}
+
+impl ReflexServerImpl {
+    /// Verify a signed command and, once authorized, hand it to the bridge.
+    #[allow(clippy::result_large_err)] // tonic::Status, as every handler returns
+    fn submit(&self, signed: SignedCommand) -> Result<Response<Ack>, Status> {
+        let name = signed.command.name();
+        let authorization = self
+            .command_gate
+            .lock()
+            .map_err(|_| Status::internal("Lock poisoned"))?
+            .authorize(signed)
+            .map_err(|e| match e {
+                GateError::NoSuchApproval(_) | GateError::ApprovalMismatch { .. } => Status::failed_precondition(e.to_string()),
+                GateError::MissingReason | GateError::MalformedField(_) => Status::invalid_argument(e.to_string()),
+                _ => Status::permission_denied(e.to_string()),
+            })?;
+
+        match authorization {
+            Authorization::AwaitingApproval { approval_id, expires_at_ms } => Ok(Response::new(Ack {
+                success: true,
+                message: format!("{} awaiting second operator: approval_id={} expires_at_ms={}", name, approval_id, expires_at_ms),
+            })),
+            Authorization::Execute(cmd) => match self.authority_tx.send(cmd) {
+                Ok(_) => {
+                    tracing::info!("🎛️ SOVEREIGN COMMAND INJECTED: {}", name);
+                    Ok(Response::new(Ack { success: true, message: format!("{} Injected", name) }))
+                }
+                Err(e) => {
+                    tracing::error!("❌ FAILED TO INJECT COMMAND: {}", e);
+                    Err(Status::internal("Command Channel Closed"))
+                }
+            },
+        }
+    }
 }
 
 #[tonic::async_trait]
@@ -134,19 +198,53 @@ impl ReflexService for ReflexServerImpl {
              CommandType::CloseAll => SovereignCommand::CloseAll,
              CommandType::SetSentiment => SovereignCommand::SetSentimentOverride(req.sentiment_value),
              CommandType::ClearSentiment => SovereignCommand::ClearSentimentOverride,
+             CommandType::PauseSymbol => SovereignCommand::PauseSymbol(req.symbol.trim().to_string()),
+             CommandType::ResumeSymbol => SovereignCommand::ResumeSymbol(req.symbol.trim().to_string()),
+             CommandType::CapTier => SovereignCommand::CapTier {
+                 tier: req.tier.parse::<RiskTier>().map_err(Status::invalid_argument)?,
+                 duration_secs: req.duration_secs,
+             },
+             CommandType::ReduceOnly => SovereignCommand::ReduceOnly(req.enabled),
+             CommandType::CancelAll => SovereignCommand::CancelAll,
+             CommandType::ResumeFromHalt => SovereignCommand::ResumeFromHalt,
+             CommandType::DemoteLadder => SovereignCommand::DemoteLadder(req.tier.parse::<RiskTier>().map_err(Status::invalid_argument)?),
+             CommandType::UpdateLegislation => return Err(Status::invalid_argument("Use UpdateLegislation")),
+             CommandType::RollbackThreshold => return Err(Status::invalid_argument("Use RollbackThreshold")),
              CommandType::Unknown => return Err(Status::invalid_argument("Unknown Command Type")),
         };
 
-        match self.authority_tx.send(cmd) {
-            Ok(_) => {
-                tracing::info!("🎛️ SOVEREIGN COMMAND INJECTED: {:?}", cmd_type);
-                Ok(Response::new(Ack { success: true, message: "Command Injected".into() }))
-            },
-            Err(e) => {
-                tracing::error!("❌ FAILED TO INJECT COMMAND: {}", e);
-                Err(Status::internal("Command Channel Closed"))
-            }
-        }
+        self.submit(SignedCommand {
+            command: cmd,
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: req.approval_id,
+            signature: req.signature,
+        })
+    }
+
+    async fn get_command_log(&self, _request: Request<Empty>) -> Result<Response<CommandLogResponse>, Status> {
+        let gate = self.command_gate.lock().map_err(|_| Status::internal("Lock poisoned"))?;
+        Ok(Response::new(CommandLogResponse {
+            records: gate
+                .records()
+                .into_iter()
+                .map(|r| CommandRecord {
+                    timestamp_ms: r.at_ms,
+                    command: r.command,
+                    operator: r.operator,
+                    reason: r.reason,
+                    approval_id: r.approval_id,
+                    outcome: match r.outcome {
+                        CommandOutcome::Executed => "EXECUTED".to_string(),
+                        CommandOutcome::AwaitingApproval { .. } => "AWAITING_APPROVAL".to_string(),
+                        CommandOutcome::Rejected(why) => format!("REJECTED: {}", why),
+                    },
+                })
+                .collect(),
+            pending_approvals: gate.pending_approvals() as u32,
+        }))
     }
 
     async fn get_physics(&self, _request: Request<Empty>) -> Result<Response<PhysicsResponse>, Status> {
@@ -194,6 +292,13 @@ impl ReflexService for ReflexServerImpl {
             // D-105: Fiscal Control Deck
             positions: r.account.active_positions.clone(),
             orders: r.account.open_orders.clone(),
+
+            // Halt Latch
+            halted: r.halt_reason.is_some(),
+            halt_reason: r.halt_reason.clone().unwrap_or_default(),
+
+            // Canonical regime (brain.Regime names)
+            regime: r.ooda.as_ref().map(|o| o.regime).unwrap_or_default().to_string(),
         }))
     }
 
@@ -244,6 +349,13 @@ impl ReflexService for ReflexServerImpl {
                     // D-105: Fiscal Control Deck
                     positions: r.account.active_positions.clone(),
                     orders: r.account.open_orders.clone(),
+
+                    // Halt Latch
+                    halted: r.halt_reason.is_some(),
+                    halt_reason: r.halt_reason.clone().unwrap_or_default(),
+
+                    // Canonical regime (brain.Regime names)
+                    regime: r.ooda.as_ref().map(|o| o.regime).unwrap_or_default().to_string(),
                 }),
                 sentiment_score: ooda.sentiment_score,
                 nearest_regime: ooda.nearest_regime.as_ref().map(|s| s.clone()),
@@ -258,15 +370,139 @@ impl ReflexService for ReflexServerImpl {
     async fn trigger_veto(&self, request: Request<VetoRequest>) -> Result<Response<Ack>, Status> {
         let req = request.into_inner();
         tracing::warn!("☢️ MANUAL VETO REQUEST by {}: {}", req.operator, req.reason);
-        
-        match self.authority_tx.send(SovereignCommand::Veto) {
-            Ok(_) => Ok(Response::new(Ack { success: true, message: "Veto Triggered".into() })),
-            Err(_) => Err(Status::internal("Failed to send Veto Command")),
+
+        self.submit(SignedCommand {
+            command: SovereignCommand::Veto,
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: 0,
+            signature: req.signature,
+        })
+    }
+
+    async fn demote_provisional(&self, request: Request<DemoteRequest>) -> Result<Response<Ack>, Status> {
+        let req = request.into_inner();
+        let target: RiskTier = req.target_level.parse().map_err(Status::invalid_argument)?;
+        let tier = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?.risk_ladder.tier;
+        if target >= tier {
+            return Ok(Response::new(Ack { success: false, message: format!("Already at {}", tier) }));
         }
+        tracing::warn!("🪜 LADDER DEMOTION REQUESTED by {}: -> {} ({})", req.operator, target, req.reason);
+
+        self.submit(SignedCommand {
+            command: SovereignCommand::DemoteLadder(target),
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: req.approval_id,
+            signature: req.signature,
+        })
     }
 
-    async fn demote_provisional(&self, _req: Request<DemoteRequest>) -> Result<Response<Ack>, Status> {
-         Ok(Response::new(Ack { success: true, message: "Use Provisional API (Upcoming)".into() }))
+    async fn get_risk_ladder(&self, _request: Request<Empty>) -> Result<Response<RiskLadderResponse>, Status> {
+        let r = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?;
+        let ladder = &r.risk_ladder;
+        Ok(Response::new(RiskLadderResponse {
+            tier: ladder.tier.to_string(),
+            tier_index: ladder.tier.index() as i32,
+            position_size: ladder.position_size,
+            progress: ladder.progress,
+            cooldown_until_ms: ladder.cooldown_until_ms.unwrap_or(0),
+            events: ladder
+                .events
+                .iter()
+                .map(|e| RiskLadderEvent {
+                    seq: e.seq,
+                    timestamp_ms: e.at_ms,
+                    from_tier: e.from.to_string(),
+                    to_tier: e.to.to_string(),
+                    reason: e.reason.clone(),
+                    cooldown_until_ms: e.cooldown_until_ms.unwrap_or(0),
+                })
+                .collect(),
+            cap_tier: ladder.cap.map(|c| c.tier.to_string()).unwrap_or_default(),
+            cap_until_ms: ladder.cap.map_or(0, |c| c.until_ms),
+        }))
+    }
+
+    async fn get_threshold_changes(&self, _request: Request<Empty>) -> Result<Response<ThresholdChangesResponse>, Status> {
+        let r = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?;
+        let audit = &r.audit;
+        Ok(Response::new(ThresholdChangesResponse {
+            tunneling_threshold: audit.tunneling_threshold,
+            drift_score: audit.drift_score,
+            changes: audit
+                .changes
+                .iter()
+                .map(|c| crate::reflex_proto::ThresholdChange {
+                    id: c.id,
+                    timestamp_ms: c.at_ms,
+                    kind: match c.kind {
+                        ChangeKind::Tighten => "TIGHTEN",
+                        ChangeKind::Relax => "RELAX",
+                        ChangeKind::Rollback { .. } => "ROLLBACK",
+                    }
+                    .to_string(),
+                    rollback_of: match c.kind {
+                        ChangeKind::Rollback { of } => of,
+                        _ => 0,
+                    },
+                    old_value: c.old_value,
+                    new_value: c.new_value,
+                    drift_score: c.evidence.drift_score,
+                    trades: c.evidence.trades as u32,
+                    mean_slippage_bps: c.evidence.mean_slippage_bps,
+                    worst_slippage_bps: c.evidence.worst_slippage_bps,
+                })
+                .collect(),
+        }))
+    }
+
+    async fn rollback_threshold(&self, request: Request<RollbackRequest>) -> Result<Response<Ack>, Status> {
+        let req = request.into_inner();
+        let known = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?.audit.changes.iter().any(|c| c.id == req.change_id);
+        if !known {
+            return Ok(Response::new(Ack { success: false, message: format!("No threshold change #{}", req.change_id) }));
+        }
+        tracing::warn!("📐 THRESHOLD ROLLBACK REQUESTED by {}: #{} ({})", req.operator, req.change_id, req.reason);
+
+        self.submit(SignedCommand {
+            command: SovereignCommand::RollbackThreshold(req.change_id),
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: req.approval_id,
+            signature: req.signature,
+        })
+    }
+
+    async fn get_schedule(&self, _request: Request<Empty>) -> Result<Response<ScheduleResponse>, Status> {
+        let r = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?;
+        let (schedule, session) = (&r.schedule, &r.session);
+        Ok(Response::new(ScheduleResponse {
+            phase: format!("{:?}", session.phase).to_uppercase(),
+            reason: session.reason.clone(),
+            closes_at_ms: session.closes_at_ms.unwrap_or(0),
+            opens_at_ms: session.opens_at_ms.unwrap_or(0),
+            taper: session.taper,
+            timezone: schedule.timezone.clone(),
+            windows: schedule
+                .windows
+                .iter()
+                .map(|w| TradingWindow { days: w.days.clone(), start: w.start.clone(), end: w.end.clone() })
+                .collect(),
+            holidays: schedule.holidays.clone(),
+            blackouts: schedule
+                .blackouts
+                .iter()
+                .map(|b| Blackout { start: b.start.clone(), end: b.end.clone(), reason: b.reason.clone() })
+                .collect(),
+            taper_minutes: schedule.taper_minutes,
+        }))
     }
 
     type GetTickHistoryStream = ReceiverStream<Result<PhysicsResponse, Status>>;
@@ -299,6 +535,60 @@ impl ReflexService for ReflexServerImpl {
         Ok(Response::new(Ack { success: true, message: "Ignition Sequence Initiated".into() }))
     }
 
+    async fn resume_from_halt(&self, request: Request<ResumeRequest>) -> Result<Response<Ack>, Status> {
+        let req = request.into_inner();
+        let halted = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?.halt_reason.is_some();
+        if !halted {
+            return Ok(Response::new(Ack { success: false, message: "Not Halted".into() }));
+        }
+        tracing::warn!("▶️ HALT RESUME REQUESTED by {}: {}", req.operator, req.reason);
+
+        self.submit(SignedCommand {
+            command: SovereignCommand::ResumeFromHalt,
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: req.approval_id,
+            signature: req.signature,
+        })
+    }
+
+    // D-95: The Chameleon's scorecard
+    async fn get_ensemble(&self, _request: Request<Empty>) -> Result<Response<EnsembleResponse>, Status> {
+        let r = self.state.read().map_err(|_| Status::internal("Lock poisoned"))?;
+        let ensemble = &r.ensemble;
+        Ok(Response::new(EnsembleResponse {
+            active_adapter: ensemble.active_adapter.clone(),
+            adapters: ensemble
+                .adapters
+                .iter()
+                .map(|(adapter, perf, demoted)| AdapterReport {
+                    adapter: adapter.clone(),
+                    consultations: perf.consultations,
+                    nullifications: perf.nullifications,
+                    nullification_rate: perf.nullification_rate().unwrap_or(0.0),
+                    decisions: perf.decisions,
+                    graded: perf.graded,
+                    hits: perf.hits,
+                    hit_rate: perf.hit_rate().unwrap_or(0.0),
+                    markout_pnl: perf.markout_pnl,
+                    demoted: *demoted,
+                })
+                .collect(),
+            history: ensemble
+                .history
+                .iter()
+                .map(|s| AdapterSwap {
+                    timestamp: s.timestamp,
+                    regime: s.regime.to_string(),
+                    adapter: s.adapter.clone(),
+                    reason: s.reason.clone(),
+                })
+                .collect(),
+        }))
+    }
+
     // D-109: Cancel Order (Tactical)
     async fn cancel_order(&self, request: Request<CancelOrderRequest>) -> Result<Response<Ack>, Status> {
         let req = request.into_inner();
@@ -317,27 +607,26 @@ impl ReflexService for ReflexServerImpl {
     // D-107: Update Legislation
     async fn update_legislation(&self, request: Request<LegislativeUpdate>) -> Result<Response<Ack>, Status> {
         let req = request.into_inner();
-        let mut w = self.state.write().map_err(|_| Status::internal("Lock poisoned"))?;
-        
-        let bias = StrategicBias::from(req.bias.as_str());
-        w.legislation.bias = bias.clone();
-        w.legislation.aggression = req.aggression;
-        w.legislation.maker_only = req.maker_only;
-        w.legislation.hibernation = req.hibernation;
-
-        // D-107: Snap-to-Break-Even Command (One-shot)
-        if req.snap_to_breakeven {
-            tracing::info!("🛡️ SNAP-TO-BREAK-EVEN TRIGGERED");
-            // In a real implementation, this would trigger an async task to move stops.
-            // For now, we just log it.
-        }
-
-        tracing::info!(
-            "⚖️ LEGISLATION UPDATED: Bias={:?}, Aggression={:.2}, MakerOnly={}, Hibernation={}",
-            bias, req.aggression, req.maker_only, req.hibernation
-        );
-
-        Ok(Response::new(Ack { success: true, message: "Legislation Updated".into() }))
+        // D-107: Applied by the owner loop once the gate authorizes it
+        let change = LegislativeChange {
+            bias: req.bias.trim().to_ascii_uppercase(),
+            aggression: req.aggression,
+            maker_only: req.maker_only,
+            hibernation: req.hibernation,
+            hurdle_rate: req.hurdle_rate,
+            snap_to_breakeven: req.snap_to_breakeven,
+        };
+        tracing::info!("⚖️ LEGISLATION UPDATE REQUESTED by {}: {:?}", req.operator, change);
+
+        self.submit(SignedCommand {
+            command: SovereignCommand::UpdateLegislation(change),
+            operator: req.operator,
+            reason: req.reason,
+            timestamp_ms: req.timestamp_ms,
+            nonce: req.nonce,
+            approval_id: req.approval_id,
+            signature: req.signature,
+        })
     }
 
     async fn close_position(&self, request: Request<ClosePositionRequest>) -> Result<Response<Ack>, Status> {
@@ -359,37 +648,10 @@ impl ReflexService for ReflexServerImpl {
         let req = request.into_inner();
         tracing::info!("🔧 RATCHET TRIGGERED: Level {:?} | Reason: {}", req.level, req.reason);
 
-        match req.level {
-            0 => { // IDLE -> RESUME
-                if let Err(e) = self.authority_tx.send(SovereignCommand::Resume) {
-                    tracing::error!("Failed to send RESUME command: {}", e);
-                }
-            }
-            1 => { // TIGHTEN -> CLOSE ALL
-                if let Err(e) = self.authority_tx.send(SovereignCommand::CloseAll) {
-                    tracing::error!("Failed to send CLOSE_ALL command: {}", e);
-                }
-            }
-            2 => { // FREEZE -> PAUSE
-                if let Err(e) = self.authority_tx.send(SovereignCommand::Pause) {
-                    tracing::error!("Failed to send PAUSE command: {}", e);
-                }
-            }
-            3 => { 
-                // KILL SWITCH
-                tracing::error!("☢️ SYSTEM HALT COMMAND RECEIVED. INITIATING SHUTDOWN.");
-                // Also notify bridge if possible, but immediate exit takes precedence
-                let _ = self.authority_tx.send(SovereignCommand::Kill);
-
-                // We write to shared state so main loop can see it (if it checks)
-                // Or we just exit. For safety in Phase 5, let's force exit after a brief delay to allow Ack to send.
-                tokio::spawn(async move {
-                     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                     std::process::exit(0);
-                });
-                return Ok(Response::new(Ack { success: true, message: "SYSTEM HALTING NOW".into() }));
-            }
-            _ => {}
+        // D-86: Resume/CloseAll/Pause/Kill are sovereign commands and must be signed
+        if (0..=3).contains(&req.level) {
+            tracing::warn!("🔐 Unsigned ratchet level {} refused. Use InjectSovereignCommand.", req.level);
+            return Err(Status::permission_denied("Sovereign commands must be signed: use InjectSovereignCommand"));
         }
 
         Ok(Response::new(Ack { success: true, message: "Ratchet Updated".into() }))
@@ -398,18 +660,8 @@ impl ReflexService for ReflexServerImpl {
         let req = request.into_inner();
 
         if req.key == "sentiment_override" {
-            if req.value < 0.0 {
-                if let Err(e) = self.authority_tx.send(SovereignCommand::ClearSentimentOverride) {
-                    tracing::error!("Failed to send ClearSentimentOverride: {}", e);
-                    return Err(Status::internal("Bridge disconnected"));
-                }
-            } else {
-                if let Err(e) = self.authority_tx.send(SovereignCommand::SetSentimentOverride(req.value)) {
-                    tracing::error!("Failed to send SetSentimentOverride: {}", e);
-                    return Err(Status::internal("Bridge disconnected"));
-                }
-            }
-            return Ok(Response::new(Ack { success: true, message: "Sentiment Override Updated".into() }));
+            // D-86: Sentiment overrides are sovereign commands and must be signed
+            return Err(Status::permission_denied("Sovereign commands must be signed: use InjectSovereignCommand (SET_SENTIMENT / CLEAR_SENTIMENT)"));
         }
 
         Ok(Response::new(Ack { success: true, message: "Legacy stub".into() }))
@@ -491,6 +743,13 @@ impl ReflexService for ReflexServerImpl {
                             // D-105: Fiscal Control Deck
                             positions: state.account.active_positions.clone(),
                             orders: state.account.open_orders.clone(),
+
+                            // Halt Latch
+                            halted: state.halt_reason.is_some(),
+                            halt_reason: state.halt_reason.clone().unwrap_or_default(),
+
+                            // Canonical regime (brain.Regime names)
+                            regime: state.ooda.as_ref().map(|o| o.regime).unwrap_or_default().to_string(),
                         })
                     },
                     Err(_) => Err(Status::internal("Lagged")),
@@ -505,7 +764,8 @@ impl ReflexService for ReflexServerImpl {
 pub async fn run_server(
     state: SafeState, 
     tx: broadcast::Sender<SharedState>,
-    authority_tx: mpsc::UnboundedSender<SovereignCommand>,
+    authority_tx: mpsc::UnboundedSender<AuthorizedCommand>,
+    command_gate: CommandGate,
 ) {
     // 1. gRPC Server
     let grpc_state = state.clone();
@@ -519,6 +779,7 @@ pub async fn run_server(
         state: grpc_state,
         tx: grpc_tx,
         authority_tx,
+        command_gate: Arc::new(Mutex::new(command_gate)),
     });
 
     tracing::info!("🚀 API Surface (gRPC) listening on {}", grpc_addr);
@@ -558,7 +819,14 @@ async fn handle_socket(ws: warp::ws::WebSocket, mut rx: broadcast::Receiver<Shar
             velocity: state.physics.velocity,
             jerk: state.physics.jerk,
             entropy: state.physics.entropy,
-            decision: if state.veto_active { "VETO".into() } else { "ACTIVE".into() },
+            decision: if state.halt_reason.is_some() {
+                "HALTED".into()
+            } else if state.veto_active {
+                "VETO".into()
+            } else {
+                "ACTIVE".into()
+            },
+            halt_reason: state.halt_reason.clone(),
         };
 
         if let Ok(json) = serde_json::to_string(&hud) {
@@ -1,17 +1,21 @@
 use crate::feynman::PhysicsEngine;
-use crate::taleb::{RiskGuardian, TradeProposal, RiskVerdict};
+use crate::taleb::{RiskGuardian, TradeProposal, RiskVerdict, Side};
+use crate::taleb::portfolio::PortfolioRisk;
+use crate::taleb::tail::TailEstimator;
 use crate::ledger::AccountState;
 use crate::sim::ticker::SimTicker;
+use crate::clock::SimulatedClock;
 use opentelemetry::{global, KeyValue};
 use opentelemetry::metrics::{Counter, UpDownCounter};
 use futures_util::StreamExt;
+use std::sync::Arc;
 use std::time::Instant;
 use rand::Rng; // Added for Jitter
 
 // D-101: FIFO Queue State
 struct OrderState {
     id: String,
-    side: String,
+    side: Side,
     qty: f64,
     price: f64,
     queue_pos: f64, // Volume ahead of us
@@ -21,6 +25,9 @@ struct OrderState {
 pub struct SimulationEngine {
     physics: PhysicsEngine,
     guardian: RiskGuardian,
+    clock: Arc<SimulatedClock>, // Tick time: TTLs and cooldowns replay like live
+    portfolio: PortfolioRisk,
+    tail: TailEstimator,
     ledger: AccountState,
     ticker: SimTicker,
     auditor: crate::audit::QuestBridge,
@@ -41,10 +48,14 @@ impl SimulationEngine {
         let nav_gauge = meter.f64_up_down_counter("portfolio.nav").init();
 
         let ticker = SimTicker::new(db_url).await?; 
+        let clock = Arc::new(SimulatedClock::new(0));
 
         Ok(Self {
             physics: PhysicsEngine::new(2000), 
-            guardian: RiskGuardian::new(),
+            guardian: RiskGuardian::with_clock(clock.clone()),
+            clock,
+            portfolio: PortfolioRisk::default(),
+            tail: TailEstimator::default(),
             ledger: AccountState::new(100_000.0, 0.0), 
             ticker,
             auditor,
@@ -78,6 +89,7 @@ impl SimulationEngine {
                 Ok(tick) => {
                     count += 1;
                     _last_price = tick.price;
+                    self.clock.on_tick(tick.timestamp);
                     
                     // --- D-25B: Speed Control ---
                     if speed > 0.0 {
@@ -103,6 +115,9 @@ impl SimulationEngine {
                     let state = self.physics.update(tick.price, tick.timestamp, 0.1, tick.quantity, 0);
 >>>>>>> feb49d06 (pushing local changes.)
 
+                    self.portfolio.update_price("BTC-USDT", tick.price);
+                    self.tail.update_price(tick.price, tick.timestamp as i64);
+
                     // --- D-101: Pessimistic Fill Logic (FIFO Queue) ---
                     // Process Pending Orders BEFORE generating new ones
                     // --- D-101: Pessimistic Fill Logic (FIFO Queue) ---
@@ -116,7 +131,7 @@ impl SimulationEngine {
                             // Check latency condition (has order reached the "exchange"?)
                             if tick.timestamp >= order.placed_at_ts {
                                 // Check Price match
-                                let price_match = if order.side == "LONG" { tick.price <= order.price } else { tick.price >= order.price };
+                                let price_match = if order.side == Side::Buy { tick.price <= order.price } else { tick.price >= order.price };
                                 
                                 if price_match {
                                     // Decrement FIFO Queue
@@ -124,9 +139,10 @@ impl SimulationEngine {
                                     
                                     if order.queue_pos <= 0.0 {
                                         // FILL!
-                                        self.trade_counter.add(1, &[KeyValue::new("side", order.side.clone())]);
+                                        self.trade_counter.add(1, &[KeyValue::new("side", order.side.as_str())]);
                                         self.nav_gauge.add(order.qty * tick.price, &[KeyValue::new("type", "exposure_add")]);
-                                        self.ledger.update_fill(&order.side, tick.price, order.qty); 
+                                        self.ledger.update_fill(order.side, tick.price, order.qty); 
+                                        self.portfolio.apply_fill("BTC-USDT", order.side.sign() * order.qty);
                                         
                                         // Buffer Log
                                         use crate::audit::FrictionLog;
@@ -134,7 +150,7 @@ impl SimulationEngine {
                                             ts: Some((tick.timestamp as i64) * 1_000_000), 
                                             symbol: "BTC-USDT".to_string(),
                                             order_id: order.id.clone(),
-                                            side: order.side.clone(),
+                                            side: order.side.to_string(),
                                             intent_qty: order.qty,
                                             fill_price: tick.price, 
                                             slippage_bps: 5.0, 
@@ -165,7 +181,7 @@ impl SimulationEngine {
                     let action = if state.velocity > 0.0 { "LONG" } else { "HOLD" };
                     if action == "LONG" {
                          let intent = TradeProposal {
-                             side: "LONG".to_string(),
+                             side: Side::Buy,
                              price: tick.price,
                              qty: 0.1, 
                          };
@@ -178,12 +194,22 @@ impl SimulationEngine {
                          let p10 = tick.price * 0.99;
                          let now = tick.timestamp as i64; // ms
 
-                         let verdict = self.guardian.check(
+                         let verdict = self.guardian.check_with_tail(
                              &state, 
                              &self.ledger, 
                              &intent, 
-                             p10, p50, p90, now, 0.05
+                             p10, p50, p90, now, 0.05,
+                             Some(&self.tail),
                         );
+
+                        // Portfolio Limits: only consulted once the single-trade gate passes
+                        let verdict = match verdict {
+                            RiskVerdict::Allowed => {
+                                let equity = self.ledger.total_equity(tick.price);
+                                self.guardian.check_portfolio(&self.portfolio, "BTC-USDT", &intent, equity)
+                            },
+                            other => other,
+                        };
                         
                         match verdict {
                             RiskVerdict::Allowed => {
@@ -198,7 +224,7 @@ impl SimulationEngine {
 
                                     let order = OrderState {
                                         id: format!("SIM-{}", count),
-                                        side: intent.side.clone(),
+                                        side: intent.side,
                                         qty: intent.qty,
                                         price: tick.price, 
                                         queue_pos,
@@ -208,9 +234,10 @@ impl SimulationEngine {
 
                                 } else {
                                     // D-101 OPTIMISTIC: Instant Fill
-                                    self.trade_counter.add(1, &[KeyValue::new("side", "LONG")]);
+                                    self.trade_counter.add(1, &[KeyValue::new("side", intent.side.as_str())]);
                                     self.nav_gauge.add(intent.qty * tick.price, &[KeyValue::new("type", "exposure_add")]);
-                                    self.ledger.update_fill(&intent.side, tick.price, intent.qty);
+                                    self.ledger.update_fill(intent.side, tick.price, intent.qty);
+                                    self.portfolio.apply_fill("BTC-USDT", intent.side.sign() * intent.qty);
                                     
                                     // Inline Log (Optimistic)
                                     use crate::audit::FrictionLog;
@@ -218,7 +245,7 @@ impl SimulationEngine {
                                         ts: Some(now * 1_000_000), 
                                         symbol: "BTC-USDT".to_string(),
                                         order_id: format!("SIM-{}", count),
-                                        side: intent.side.clone(),
+                                        side: intent.side.to_string(),
                                         intent_qty: intent.qty,
                                         fill_price: tick.price, 
                                         slippage_bps: 0.0, // Optimistic = 0 slippage
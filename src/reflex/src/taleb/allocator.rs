@@ -0,0 +1,523 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{self, SharedClock};
+
+/// How the deployable equity is split across sleeves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AllocationMode {
+    RiskParity,  // Symbol weight ~ 1 / realized volatility, split evenly across its strategies
+    Performance, // Sleeve weight ~ realized Sharpe of the sleeve (losers fall to the floor)
+}
+
+/// One traded symbol and the strategies that get their own budget on it.
+/// No strategies = a single budget for the whole pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SleeveConfig {
+    pub symbol: String,
+    #[serde(default)]
+    pub strategies: Vec<String>,
+}
+
+/// Capital allocator settings. Loaded from JSON (ALLOCATOR_PATH); missing fields keep
+/// their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllocatorConfig {
+    pub mode: AllocationMode,
+    pub sleeves: Vec<SleeveConfig>,
+    pub refresh_secs: u64, // Weights are recomputed on this schedule, not every tick
+    pub sample_secs: u64,  // Returns are sampled on this wall-clock interval, not per tick
+    pub reserve: f64,      // Fraction of equity never handed out
+    pub min_weight: f64,   // Floor per sleeve (no sleeve is starved to zero)
+    pub max_weight: f64,   // Ceiling per sleeve; anything above it stays unallocated
+    pub window: usize,     // Returns kept per symbol / sleeve
+    pub min_samples: usize, // Below this a sleeve has no statistics and gets a neutral weight
+}
+
+impl Default for AllocatorConfig {
+    fn default() -> Self {
+        Self {
+            mode: AllocationMode::RiskParity,
+            sleeves: Vec::new(),
+            refresh_secs: 300,
+            sample_secs: 60,
+            reserve: 0.0,
+            min_weight: 0.05,
+            max_weight: 1.0,
+            window: 500,
+            min_samples: 30,
+        }
+    }
+}
+
+impl AllocatorConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&contents)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// A single whole-pipeline sleeve per symbol (the pre-allocator behaviour for one symbol).
+    pub fn for_symbols(symbols: &[&str]) -> Self {
+        Self {
+            sleeves: symbols
+                .iter()
+                .map(|s| SleeveConfig { symbol: s.to_string(), strategies: Vec::new() })
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(0.0..1.0).contains(&self.reserve) {
+            return Err(format!("reserve must be in [0, 1), got {}", self.reserve));
+        }
+        if !(0.0..=1.0).contains(&self.min_weight) || !(0.0..=1.0).contains(&self.max_weight) || self.min_weight > self.max_weight {
+            return Err(format!("weight bounds must satisfy 0 <= min <= max <= 1, got [{}, {}]", self.min_weight, self.max_weight));
+        }
+        if self.refresh_secs == 0 || self.sample_secs == 0 {
+            return Err("refresh_secs and sample_secs must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Capital handed to one sleeve at the last refresh.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SleeveAllocation {
+    pub symbol: String,
+    pub strategy: Option<String>, // None = whole pipeline
+    pub weight: f64,
+    pub capital: f64, // USD
+}
+
+/// What one OODA pipeline may deploy: the symbol total, and per-strategy budgets within it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PipelineAllocation {
+    pub total: f64,
+    pub by_strategy: HashMap<String, f64>,
+}
+
+impl PipelineAllocation {
+    /// Budget for the strategy the pipeline selected; the symbol total if it has no sleeve of its own.
+    pub fn cap_for(&self, strategy: &str) -> f64 {
+        self.by_strategy.get(strategy).copied().unwrap_or(self.total)
+    }
+}
+
+#[derive(Debug)]
+struct Sleeve {
+    symbol: String,
+    strategy: Option<String>,
+    returns: VecDeque<f64>, // PnL / sleeve capital per sample interval
+    pending_pnl: Option<f64>, // Accrued since the last sample; None if the sleeve was idle
+    weight: f64,
+}
+
+/// Cross-Symbol Capital Allocator
+/// Splits account equity across (symbol, strategy) sleeves by risk parity or realized
+/// performance. Weights refresh on a schedule; the dollar caps follow equity every tick.
+///
+/// Equity, prices and positions are pushed in by the owner loop.
+pub struct CapitalAllocator {
+    pub config: AllocatorConfig,
+    sleeves: Vec<Sleeve>,
+    symbol_returns: HashMap<String, VecDeque<f64>>, // Log returns per symbol (risk parity)
+    last_prices: HashMap<String, f64>,
+    samples: HashMap<String, (Instant, f64)>, // When and at what price each symbol was last sampled
+    equity: f64,
+    last_refresh: Option<Instant>,
+    clock: SharedClock,
+}
+
+impl CapitalAllocator {
+    pub fn new(config: AllocatorConfig) -> Self {
+        Self::with_clock(config, clock::system())
+    }
+
+    pub fn with_clock(config: AllocatorConfig, clock: SharedClock) -> Self {
+        let sleeves: Vec<Sleeve> = config
+            .sleeves
+            .iter()
+            .flat_map(|s| {
+                let strategies: Vec<Option<String>> = if s.strategies.is_empty() {
+                    vec![None]
+                } else {
+                    s.strategies.iter().cloned().map(Some).collect()
+                };
+                strategies.into_iter().map(move |strategy| Sleeve {
+                    symbol: s.symbol.clone(),
+                    strategy,
+                    returns: VecDeque::new(),
+                    pending_pnl: None,
+                    weight: 0.0,
+                })
+            })
+            .collect();
+        let mut allocator = Self {
+            config,
+            sleeves,
+            symbol_returns: HashMap::new(),
+            last_prices: HashMap::new(),
+            samples: HashMap::new(),
+            equity: 0.0,
+            last_refresh: None,
+            clock,
+        };
+        allocator.set_weights(vec![1.0; allocator.sleeves.len()]); // Equal until the first refresh
+        allocator
+    }
+
+    pub fn update_equity(&mut self, equity: f64) {
+        self.equity = equity.max(0.0);
+    }
+
+    fn deployable(&self) -> f64 {
+        self.equity * (1.0 - self.config.reserve)
+    }
+
+    /// Mark a symbol: accrues the PnL of `net_position` to the sleeve of the strategy
+    /// currently driving the pipeline. Returns are recorded once per `sample_secs` of
+    /// wall clock, so the statistics do not depend on the tick rate.
+    pub fn observe(&mut self, symbol: &str, strategy: &str, net_position: f64, price: f64) {
+        if price <= 0.0 {
+            return;
+        }
+        let last = self.last_prices.insert(symbol.to_string(), price);
+        if let Some(last) = last.filter(|p| *p > 0.0) {
+            if let Some(i) = self.sleeve_index(symbol, strategy) {
+                *self.sleeves[i].pending_pnl.get_or_insert(0.0) += net_position * (price - last);
+            }
+        }
+
+        let now = self.clock.now();
+        match self.samples.get(symbol).copied() {
+            Some((at, _)) if self.clock.elapsed_since(at) < Duration::from_secs(self.config.sample_secs) => {}
+            Some((_, sampled)) => {
+                self.sample(symbol, (price / sampled).ln());
+                self.samples.insert(symbol.to_string(), (now, price));
+            }
+            None => {
+                self.samples.insert(symbol.to_string(), (now, price));
+            }
+        }
+    }
+
+    fn sample(&mut self, symbol: &str, log_return: f64) {
+        let window = self.config.window;
+        push_bounded(self.symbol_returns.entry(symbol.to_string()).or_default(), log_return, window);
+
+        let deployable = self.deployable();
+        for sleeve in self.sleeves.iter_mut().filter(|s| s.symbol == symbol) {
+            let Some(pnl) = sleeve.pending_pnl.take() else { continue };
+            let capital = sleeve.weight * deployable;
+            if capital > 0.0 {
+                push_bounded(&mut sleeve.returns, pnl / capital, window);
+            }
+        }
+    }
+
+    /// Strategy sleeve if one exists for the symbol, else the symbol-wide sleeve.
+    fn sleeve_index(&self, symbol: &str, strategy: &str) -> Option<usize> {
+        self.sleeves
+            .iter()
+            .position(|s| s.symbol == symbol && s.strategy.as_deref() == Some(strategy))
+            .or_else(|| self.sleeves.iter().position(|s| s.symbol == symbol && s.strategy.is_none()))
+    }
+
+    pub fn refresh_due(&self) -> bool {
+        self.last_refresh
+            .is_none_or(|t| self.clock.elapsed_since(t) >= Duration::from_secs(self.config.refresh_secs))
+    }
+
+    /// Recompute weights if the schedule says so. Returns true when it did.
+    pub fn maybe_refresh(&mut self) -> bool {
+        if !self.refresh_due() {
+            return false;
+        }
+        self.refresh();
+        true
+    }
+
+    /// Recompute sleeve weights now.
+    pub fn refresh(&mut self) {
+        let raw = match self.config.mode {
+            AllocationMode::RiskParity => self.risk_parity_scores(),
+            AllocationMode::Performance => self.performance_scores(),
+        };
+        self.set_weights(raw);
+        self.last_refresh = Some(self.clock.now());
+    }
+
+    fn risk_parity_scores(&self) -> Vec<f64> {
+        let inverse_vol: HashMap<&str, Option<f64>> = self
+            .sleeves
+            .iter()
+            .map(|s| {
+                let vol = self
+                    .symbol_returns
+                    .get(&s.symbol)
+                    .filter(|r| r.len() >= self.config.min_samples)
+                    .and_then(std_dev)
+                    .filter(|v| *v > 0.0);
+                (s.symbol.as_str(), vol.map(|v| 1.0 / v))
+            })
+            .collect();
+        let symbol_scores = fill_missing(&inverse_vol.values().copied().collect::<Vec<_>>());
+        let by_symbol: HashMap<&str, f64> = inverse_vol.keys().copied().zip(symbol_scores).collect();
+
+        // Symbol budget is split evenly across that symbol's sleeves
+        self.sleeves
+            .iter()
+            .map(|s| {
+                let siblings = self.sleeves.iter().filter(|o| o.symbol == s.symbol).count() as f64;
+                by_symbol[s.symbol.as_str()] / siblings
+            })
+            .collect()
+    }
+
+    fn performance_scores(&self) -> Vec<f64> {
+        let sharpe: Vec<Option<f64>> = self
+            .sleeves
+            .iter()
+            .map(|s| {
+                if s.returns.len() < self.config.min_samples {
+                    return None;
+                }
+                let mean = s.returns.iter().sum::<f64>() / s.returns.len() as f64;
+                let sd = std_dev(&s.returns).filter(|v| *v > 0.0)?;
+                Some((mean / sd).max(0.0))
+            })
+            .collect();
+        fill_missing(&sharpe)
+    }
+
+    fn set_weights(&mut self, raw: Vec<f64>) {
+        let weights = bound_weights(&raw, self.config.min_weight, self.config.max_weight);
+        for (sleeve, w) in self.sleeves.iter_mut().zip(weights) {
+            sleeve.weight = w;
+        }
+    }
+
+    /// Caps for one OODA pipeline at the current equity. None if the symbol has no sleeve.
+    pub fn allocation(&self, symbol: &str) -> Option<PipelineAllocation> {
+        let deployable = self.deployable();
+        let mut allocation = PipelineAllocation::default();
+        let mut found = false;
+        for sleeve in self.sleeves.iter().filter(|s| s.symbol == symbol) {
+            found = true;
+            let capital = sleeve.weight * deployable;
+            allocation.total += capital;
+            if let Some(strategy) = &sleeve.strategy {
+                allocation.by_strategy.insert(strategy.clone(), capital);
+            }
+        }
+        found.then_some(allocation)
+    }
+
+    pub fn snapshot(&self) -> Vec<SleeveAllocation> {
+        let deployable = self.deployable();
+        self.sleeves
+            .iter()
+            .map(|s| SleeveAllocation {
+                symbol: s.symbol.clone(),
+                strategy: s.strategy.clone(),
+                weight: s.weight,
+                capital: s.weight * deployable,
+            })
+            .collect()
+    }
+}
+
+fn push_bounded(buf: &mut VecDeque<f64>, value: f64, cap: usize) {
+    if buf.len() >= cap.max(1) {
+        buf.pop_front();
+    }
+    buf.push_back(value);
+}
+
+fn std_dev(values: &VecDeque<f64>) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    Some((values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt())
+}
+
+/// Sleeves without statistics take the average known score; all unknown (or all zero) = equal.
+fn fill_missing(scores: &[Option<f64>]) -> Vec<f64> {
+    let known: Vec<f64> = scores.iter().flatten().copied().collect();
+    let neutral = if known.is_empty() { 1.0 } else { known.iter().sum::<f64>() / known.len() as f64 };
+    let filled: Vec<f64> = scores.iter().map(|s| s.unwrap_or(neutral)).collect();
+    if filled.iter().sum::<f64>() <= 0.0 {
+        return vec![1.0; scores.len()];
+    }
+    filled
+}
+
+/// Normalize to 1, then clamp into [min, max], handing the excess/shortfall to the
+/// unclamped sleeves. Whatever the ceiling leaves over stays unallocated.
+fn bound_weights(raw: &[f64], min: f64, max: f64) -> Vec<f64> {
+    let n = raw.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let min = min.min(1.0 / n as f64);
+    let mut weights = vec![0.0; n];
+    let mut fixed = vec![false; n];
+    for _ in 0..n {
+        let free_mass = 1.0 - weights.iter().zip(&fixed).filter(|(_, f)| **f).map(|(w, _)| w).sum::<f64>();
+        let free_raw: f64 = raw.iter().zip(&fixed).filter(|(_, f)| !**f).map(|(r, _)| r.max(0.0)).sum();
+        let free_count = fixed.iter().filter(|f| !**f).count();
+        if free_count == 0 {
+            break;
+        }
+        for i in (0..n).filter(|i| !fixed[*i]) {
+            weights[i] = if free_raw > 0.0 {
+                free_mass * raw[i].max(0.0) / free_raw
+            } else {
+                free_mass / free_count as f64
+            };
+        }
+        let mut changed = false;
+        for i in 0..n {
+            if fixed[i] {
+                continue;
+            }
+            if weights[i] < min {
+                weights[i] = min;
+                fixed[i] = true;
+                changed = true;
+            } else if weights[i] > max {
+                weights[i] = max;
+                fixed[i] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use std::sync::Arc;
+
+    fn config(mode: AllocationMode) -> AllocatorConfig {
+        AllocatorConfig {
+            mode,
+            sleeves: vec![
+                SleeveConfig { symbol: "XBT/USD".to_string(), strategies: Vec::new() },
+                SleeveConfig { symbol: "ETH/USD".to_string(), strategies: Vec::new() },
+            ],
+            min_weight: 0.0,
+            min_samples: 10,
+            ..AllocatorConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_risk_parity_favours_the_calmer_symbol() {
+        let clock = Arc::new(ManualClock::new());
+        let mut allocator = CapitalAllocator::with_clock(config(AllocationMode::RiskParity), clock.clone());
+        allocator.update_equity(10_000.0);
+
+        // Equal split before any statistics
+        assert_eq!(allocator.allocation("XBT/USD").unwrap().total, 5_000.0);
+
+        // ETH swings twice as hard as XBT
+        for i in 0..50 {
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            allocator.observe("XBT/USD", "Simons", 0.0, 100.0 * (1.0 + 0.01 * sign));
+            allocator.observe("ETH/USD", "Simons", 0.0, 100.0 * (1.0 + 0.02 * sign));
+            clock.advance(Duration::from_secs(allocator.config.sample_secs));
+        }
+        allocator.refresh();
+
+        let xbt = allocator.allocation("XBT/USD").unwrap().total;
+        let eth = allocator.allocation("ETH/USD").unwrap().total;
+        assert!((xbt + eth - 10_000.0).abs() < 1e-6);
+        assert!((xbt / eth - 2.0).abs() < 0.05, "xbt={} eth={}", xbt, eth);
+        assert!(allocator.allocation("SOL/USD").is_none());
+    }
+
+    #[test]
+    fn test_performance_weights_respect_bounds_and_strategy_sleeves() {
+        let mut cfg = config(AllocationMode::Performance);
+        cfg.sleeves[0].strategies = vec!["Simons".to_string(), "Kepler".to_string()];
+        cfg.min_weight = 0.1;
+        cfg.max_weight = 0.6;
+        cfg.reserve = 0.2;
+        let clock = Arc::new(ManualClock::new());
+        let mut allocator = CapitalAllocator::with_clock(cfg, clock.clone());
+        allocator.update_equity(10_000.0);
+
+        // Same rising market: Simons drives long and makes money, then Kepler fades it short
+        let mut price = 100.0;
+        for i in 0..120 {
+            price *= if i % 3 == 2 { 0.999 } else { 1.002 };
+            let (strategy, position) = if i < 60 { ("Simons", 1.0) } else { ("Kepler", -1.0) };
+            allocator.observe("XBT/USD", strategy, position, price);
+            clock.advance(Duration::from_secs(allocator.config.sample_secs));
+        }
+        allocator.refresh();
+
+        let xbt = allocator.allocation("XBT/USD").unwrap();
+        let simons = xbt.cap_for("Simons");
+        let kepler = xbt.cap_for("Kepler");
+        assert!(simons > kepler);
+        assert!((kepler - 0.1 * 8_000.0).abs() < 1e-6); // Loser held at the floor
+        assert!(simons <= 0.6 * 8_000.0 + 1e-6); // Winner held under the ceiling
+        assert_eq!(xbt.cap_for("Unknown"), xbt.total);
+
+        let total: f64 = allocator.snapshot().iter().map(|s| s.capital).sum();
+        assert!(total <= 8_000.0 + 1e-6); // Reserve never handed out
+    }
+
+    #[test]
+    fn test_returns_sampled_on_wall_clock() {
+        let clock = Arc::new(ManualClock::new());
+        let mut allocator = CapitalAllocator::with_clock(config(AllocationMode::Performance), clock.clone());
+        allocator.update_equity(10_000.0);
+
+        // A burst of ticks inside one interval is one return, carrying the whole interval's PnL
+        allocator.observe("XBT/USD", "Simons", 1.0, 100.0);
+        for price in [101.0, 102.0, 103.0] {
+            allocator.observe("XBT/USD", "Simons", 1.0, price);
+        }
+        assert!(!allocator.symbol_returns.contains_key("XBT/USD"));
+        clock.advance(Duration::from_secs(allocator.config.sample_secs));
+        allocator.observe("XBT/USD", "Simons", 1.0, 104.0);
+
+        assert_eq!(allocator.symbol_returns["XBT/USD"].len(), 1);
+        assert!((allocator.symbol_returns["XBT/USD"][0] - (1.04f64).ln()).abs() < 1e-12);
+        assert_eq!(allocator.sleeves[0].returns, VecDeque::from([4.0 / 5_000.0]));
+    }
+
+    #[test]
+    fn test_refresh_runs_on_schedule() {
+        let clock = Arc::new(ManualClock::new());
+        let mut cfg = config(AllocationMode::RiskParity);
+        cfg.refresh_secs = 60;
+        let mut allocator = CapitalAllocator::with_clock(cfg, clock.clone());
+
+        assert!(allocator.maybe_refresh());
+        assert!(!allocator.maybe_refresh());
+        clock.advance(Duration::from_secs(59));
+        assert!(!allocator.refresh_due());
+        clock.advance(Duration::from_secs(1));
+        assert!(allocator.maybe_refresh());
+    }
+}
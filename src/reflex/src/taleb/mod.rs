@@ -1,9 +1,14 @@
 pub mod omega;
 pub mod sizing;
 pub mod shroud; // D-22 Risk Shroud
+pub mod portfolio;
+pub mod allocator; // Cross-symbol capital budgets
+pub mod tail;
 
 use crate::feynman::PhysicsState;
 use crate::ledger::AccountState;
+pub use crate::ledger::Side;
+use crate::clock::{self, SharedClock};
 use tracing::warn;
 
 // Risk Constants
@@ -12,12 +17,15 @@ pub const MAX_ENTROPY: f64 = 0.90;
 pub const MAX_DRAWDOWN: f64 = 0.02; // 2%
 pub const BLACK_SWAN_JERK: f64 = 100.0;
 pub const OMEGA_THRESHOLD: f64 = 1.5;
+pub const TAIL_THIN_RATIO: f64 = 0.5; // Veto if Brain tail < 50% of the realized tail
+pub const TAIL_CONFIDENCE: f64 = 0.90; // Cross-check level: the Brain's p10 / p90
 
 use crate::client::brain::StrategyIntent as BrainIntent;
+use crate::brain::forecast::TimedForecast;
 
 #[derive(Debug, Clone)]
 pub struct TradeProposal {
-    pub side: String, // "BUY" or "SELL"
+    pub side: Side,
     pub price: f64,
     pub qty: f64,
 }
@@ -31,17 +39,22 @@ pub enum RiskVerdict {
 
 pub struct RiskGuardian {
     is_armed: bool,
+    clock: SharedClock, // Forecast TTL reference (tick time in sim)
 }
 
 impl Default for RiskGuardian {
     fn default() -> Self {
-        Self { is_armed: true }
+        Self::new()
     }
 }
 
 impl RiskGuardian {
     pub fn new() -> Self {
-        Self { is_armed: true }
+        Self::with_clock(clock::system())
+    }
+
+    pub fn with_clock(clock: SharedClock) -> Self {
+        Self { is_armed: true, clock }
     }
 
     /// Primary Gatekeeper Function
@@ -55,6 +68,44 @@ impl RiskGuardian {
         forecast_p90: f64,
         forecast_ts: i64, // Unix Millis
         hurdle_rate: f64, // Annualized Hurdle (e.g. 0.05)
+    ) -> RiskVerdict {
+        self.check_with_tail(
+            physics, account, intent, forecast_p10, forecast_p50, forecast_p90, forecast_ts, hurdle_rate, None,
+        )
+    }
+
+    /// Gatekeeper fed by the ForecastService. No forecast counts as stale (realized
+    /// quantiles if the tail has them, otherwise a veto).
+    pub fn check_forecast(
+        &self,
+        physics: &PhysicsState,
+        account: &AccountState,
+        intent: &TradeProposal,
+        forecast: Option<&TimedForecast>,
+        hurdle_rate: f64,
+        tail: Option<&tail::TailEstimator>,
+    ) -> RiskVerdict {
+        let (p10, p50, p90, ts) = forecast.map_or((0.0, 0.0, 0.0, 0), |f| {
+            (f.quantiles.p10, f.quantiles.p50, f.quantiles.p90, f.fetched_at_ms)
+        });
+        self.check_with_tail(physics, account, intent, p10, p50, p90, ts, hurdle_rate, tail)
+    }
+
+    /// Primary Gatekeeper with the realized tail estimator.
+    /// - Stale forecast: quantiles are rebuilt from realized returns instead of vetoing.
+    /// - Fresh forecast: vetoes when the Brain's VaR is much thinner than the realized VaR.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_with_tail(
+        &self,
+        physics: &PhysicsState,
+        account: &AccountState,
+        intent: &TradeProposal,
+        forecast_p10: f64,
+        forecast_p50: f64,
+        forecast_p90: f64,
+        forecast_ts: i64, // Unix Millis
+        hurdle_rate: f64, // Annualized Hurdle (e.g. 0.05)
+        tail: Option<&tail::TailEstimator>,
     ) -> RiskVerdict {
         if !self.is_armed {
             return RiskVerdict::Allowed;
@@ -62,16 +113,42 @@ impl RiskGuardian {
 
         // --- 0. Hardening: Quantile TTL (D-20 Hardening) ---
         // Ensure forecast is fresh (e.g., < 60 seconds old).
-        // Note: Using system time. Clock drift > 60s is unlikely but possible on docker.
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as i64;
+        // Note: Live uses system time. Clock drift > 60s is unlikely but possible on docker.
+        let now = self.clock.unix_millis();
         
         let age = now - forecast_ts;
-        if age > 60_000 { // 60 Seconds TTL
-             return RiskVerdict::Veto(format!("Forecast Stale: Age {}ms > 60000ms", age));
-        }
+        let (forecast_p10, forecast_p50, forecast_p90) = if age > 60_000 { // 60 Seconds TTL
+            // Fallback: rebuild the quantiles from the realized return distribution
+            let realized = tail.and_then(|t| Some((t.quantile(0.10)?, t.quantile(0.50)?, t.quantile(0.90)?)));
+            match realized {
+                Some((q10, q50, q90)) => {
+                    warn!("RISK: Forecast stale ({}ms). Using realized tail quantiles.", age);
+                    (intent.price * (1.0 + q10), intent.price * (1.0 + q50), intent.price * (1.0 + q90))
+                }
+                None => return RiskVerdict::Veto(format!("Forecast Stale: Age {}ms > 60000ms", age)),
+            }
+        } else {
+            // Cross-Check: the Brain's VaR on the risk side (p10 long, p90 short) vs the
+            // realized VaR / ES of the same side at the same confidence
+            if let Some(realized) = tail.and_then(|t| t.estimate_for(intent.side, TAIL_CONFIDENCE)) {
+                let brain_var = match intent.side {
+                    Side::Buy => (intent.price - forecast_p10) / intent.price,
+                    Side::Sell => (forecast_p90 - intent.price) / intent.price,
+                }
+                .max(0.0);
+                if realized.var > 0.0 && brain_var < realized.var * TAIL_THIN_RATIO {
+                    return RiskVerdict::Veto(format!(
+                        "Tail Cross-Check Veto: Brain VaR{:.0} {:.4}% < {:.0}% of realized VaR {:.4}% (ES {:.4}%)",
+                        TAIL_CONFIDENCE * 100.0,
+                        brain_var * 100.0,
+                        TAIL_THIN_RATIO * 100.0,
+                        realized.var * 100.0,
+                        realized.es * 100.0
+                    ));
+                }
+            }
+            (forecast_p10, forecast_p50, forecast_p90)
+        };
 
         // --- 1. Critical Physics Check (Kill Switch) ---
         if physics.jerk.abs() > BLACK_SWAN_JERK {
@@ -89,20 +166,28 @@ impl RiskGuardian {
 
         // --- 3. The Omega Sieve (Taleb Extension) ---
         // Verify that the Probability Distribution justifies the trade.
-        // MAR (Min Acceptable Return) = Price * (1 + Daily_Hurdle + Frictions).
+        // Long MAR  = Price * (1 + Daily_Hurdle + Frictions).
+        // Short MAR = Price * (1 - Daily_Hurdle - Frictions), scored on the mirrored
+        // distribution so the upside tail is the risk.
         
         // Annual Hurdle -> Daily Hurdle approx
         let daily_hurdle = hurdle_rate / 365.0;
         let friction_buffer = 0.001; // 10 bps buffer for verification
         
-        let mar_threshold = intent.price * (1.0 + daily_hurdle + friction_buffer);
-        
-        let omega = omega::OmegaScorer::calculate(
-            forecast_p10, 
-            forecast_p50, 
-            forecast_p90, 
-            mar_threshold
-        );
+        let omega = match intent.side {
+            Side::Buy => omega::OmegaScorer::calculate(
+                forecast_p10,
+                forecast_p50,
+                forecast_p90,
+                intent.price * (1.0 + daily_hurdle + friction_buffer),
+            ),
+            Side::Sell => omega::OmegaScorer::calculate_short(
+                forecast_p10,
+                forecast_p50,
+                forecast_p90,
+                intent.price * (1.0 - daily_hurdle - friction_buffer),
+            ),
+        };
 
         if omega < OMEGA_THRESHOLD {
             return RiskVerdict::Veto(format!("Omega Fragility Veto: {:.2} < 1.5", omega));
@@ -110,14 +195,32 @@ impl RiskGuardian {
 
         // --- 4. Capital Veto ---
         // a. Insolvency / Balance check
-        if intent.side == "BUY" {
-            let cost = intent.price * intent.qty;
-            if cost > account.available_balance() {
-                return RiskVerdict::Veto(format!(
-                    "Insufficient Funds: Cost {:.2} > Available {:.2}",
-                    cost,
-                    account.available_balance()
-                ));
+        match intent.side {
+            Side::Buy => {
+                let cost = intent.price * intent.qty;
+                if cost > account.available_balance() {
+                    return RiskVerdict::Veto(format!(
+                        "Insufficient Funds: Cost {:.2} > Available {:.2}",
+                        cost,
+                        account.available_balance()
+                    ));
+                }
+            }
+            Side::Sell => {
+                // Inventory check: the portion not covered by held BTC is a short
+                // and must be fully collateralised by free USDT.
+                let held = account.btc_position.max(0.0);
+                let uncovered = (intent.qty - held).max(0.0);
+                let collateral = uncovered * intent.price;
+                if collateral > account.available_balance() {
+                    return RiskVerdict::Veto(format!(
+                        "Insufficient Inventory: Selling {:.6} vs Held {:.6}, Short Collateral {:.2} > Available {:.2}",
+                        intent.qty,
+                        held,
+                        collateral,
+                        account.available_balance()
+                    ));
+                }
             }
         }
         
@@ -134,6 +237,21 @@ impl RiskGuardian {
         RiskVerdict::Allowed
     }
 
+    /// Portfolio Gatekeeper: Exposure, Concentration and VaR across all symbols.
+    /// Run after `check` so a trade that is fine in isolation cannot overload the book.
+    pub fn check_portfolio(
+        &self,
+        portfolio: &portfolio::PortfolioRisk,
+        symbol: &str,
+        intent: &TradeProposal,
+        equity: f64,
+    ) -> RiskVerdict {
+        if !self.is_armed {
+            return RiskVerdict::Allowed;
+        }
+        portfolio.check(symbol, intent, equity)
+    }
+
     /// Secondary Gatekeeper: The Risk Shroud (Exit Logic)
     pub fn check_shroud(
         &self,
@@ -146,6 +264,19 @@ impl RiskGuardian {
         }
         shroud::RiskShroud::new().check_shroud(current_price, intent, entropy)
     }
+
+    /// Position Shroud: checks a held position against its trailing BES stop.
+    pub fn check_position_shroud(
+        &self,
+        shroud: &shroud::RiskShroud,
+        symbol: &str,
+        current_price: f64,
+    ) -> shroud::ShroudVerdict {
+        if !self.is_armed {
+            return shroud::ShroudVerdict::Safe;
+        }
+        shroud.check_position(symbol, current_price)
+    }
 }
 
 #[cfg(test)]
@@ -157,7 +288,7 @@ mod tests {
         let guardian = RiskGuardian::new();
         let mut physics = PhysicsState::default();
         let account = AccountState::default();
-        let intent = TradeProposal { side: "BUY".to_string(), price: 100.0, qty: 1.0 };
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
 
         physics.jerk = 50.0; // > 25.0
 
@@ -167,12 +298,30 @@ mod tests {
         assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Max Jerk")));
     }
 
+    #[test]
+    fn test_forecast_ttl_follows_clock() {
+        // Replay: a historic forecast is fresh relative to tick time, not wall time
+        let clock = std::sync::Arc::new(crate::clock::SimulatedClock::new(1_600_000_000_000));
+        let guardian = RiskGuardian::with_clock(clock.clone());
+        let physics = PhysicsState::default();
+        let account = AccountState::default();
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
+        let forecast_ts = 1_600_000_000_000;
+
+        let verdict = guardian.check(&physics, &account, &intent, 90.0, 100.0, 110.0, forecast_ts, 0.05);
+        assert!(!matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Stale")));
+
+        clock.on_tick(1_600_000_061_000.0);
+        let verdict = guardian.check(&physics, &account, &intent, 90.0, 100.0, 110.0, forecast_ts, 0.05);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Stale")));
+    }
+
     #[test]
     fn test_black_swan_panic() {
         let guardian = RiskGuardian::new();
         let mut physics = PhysicsState::default();
         let account = AccountState::default();
-        let intent = TradeProposal { side: "BUY".to_string(), price: 100.0, qty: 1.0 };
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
 
         physics.jerk = 150.0; // > 100.0
 
@@ -186,7 +335,7 @@ mod tests {
         let guardian = RiskGuardian::new();
         let physics = PhysicsState::default();
         let account = AccountState::new(1000.0, 0.0);
-        let intent = TradeProposal { side: "BUY".to_string(), price: 100.0, qty: 1.0 };
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
 
         // Bearish Forecast: P90 is close to P50, but P10 is far below.
         // P50=100. P90=102. P10=80.
@@ -206,7 +355,7 @@ mod tests {
         let guardian = RiskGuardian::new();
         let physics = PhysicsState::default();
         let account = AccountState::default();
-        let intent = TradeProposal { side: "BUY".to_string(), price: 100.0, qty: 1.0 };
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
         
         // Stale Timestamp (Now - 70s)
         let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
@@ -221,7 +370,7 @@ mod tests {
         let guardian = RiskGuardian::new();
         let physics = PhysicsState::default();
         let account = AccountState::new(50.0, 0.0); // Only 50 USD
-        let intent = TradeProposal { side: "BUY".to_string(), price: 100.0, qty: 1.0 }; // Cost 100
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 }; // Cost 100
 
         // High Omega Forecast to bypass first gate
         // P50 > Threshold. Upside heavily favored.
@@ -229,4 +378,86 @@ mod tests {
         let verdict = guardian.check(&physics, &account, &intent, 99.0, 105.0, 110.0, now, 0.05);
         assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Insufficient Funds")));
     }
+
+    #[test]
+    fn test_tail_fallback_and_cross_check() {
+        let guardian = RiskGuardian::new();
+        let physics = PhysicsState::default();
+        let account = AccountState::new(1000.0, 0.0);
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
+
+        // Realized: upward drift with occasional 1% losses
+        let mut est = tail::TailEstimator::default();
+        for i in 0..100 {
+            est.push_return(0.02 + (i % 5) as f64 * 0.01 - 0.03);
+        }
+
+        // Stale forecast: falls back to realized quantiles (bullish) instead of vetoing
+        let stale = now - 70_000;
+        let verdict = guardian.check_with_tail(&physics, &account, &intent, 0.0, 0.0, 0.0, stale, 0.05, Some(&est));
+        assert_eq!(verdict, RiskVerdict::Allowed);
+
+        // Fresh forecast with a downside tail far thinner than observed
+        let verdict = guardian.check_with_tail(&physics, &account, &intent, 102.9, 103.0, 106.0, now, 0.05, Some(&est));
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Tail Cross-Check")));
+
+        // Without enough history the stale veto still applies
+        let empty = tail::TailEstimator::default();
+        let verdict = guardian.check_with_tail(&physics, &account, &intent, 0.0, 0.0, 0.0, stale, 0.05, Some(&empty));
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Forecast Stale")));
+    }
+
+    #[test]
+    fn test_check_forecast_uses_service_timestamp() {
+        use crate::governor::ooda_loop::{ForecastQuantiles, ForecastSource};
+        let guardian = RiskGuardian::new();
+        let physics = PhysicsState::default();
+        let account = AccountState::new(1000.0, 0.0);
+        let intent = TradeProposal { side: Side::Buy, price: 100.0, qty: 1.0 };
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
+
+        let quantiles = ForecastQuantiles::new(99.0, 105.0, 110.0, ForecastSource::Brain).unwrap();
+        let fresh = TimedForecast { quantiles, horizon: 60.0, fetched_at_ms: now };
+        assert_eq!(guardian.check_forecast(&physics, &account, &intent, Some(&fresh), 0.05, None), RiskVerdict::Allowed);
+
+        let stale = TimedForecast { fetched_at_ms: now - 70_000, ..fresh };
+        assert!(matches!(guardian.check_forecast(&physics, &account, &intent, Some(&stale), 0.05, None), RiskVerdict::Veto(ref r) if r.contains("Forecast Stale")));
+        assert!(matches!(guardian.check_forecast(&physics, &account, &intent, None, 0.05, None), RiskVerdict::Veto(ref r) if r.contains("Forecast Stale")));
+    }
+
+    #[test]
+    fn test_short_omega_symmetry() {
+        let guardian = RiskGuardian::new();
+        let physics = PhysicsState::default();
+        let account = AccountState::new(1000.0, 1.0);
+        let intent = TradeProposal { side: Side::Sell, price: 100.0, qty: 1.0 };
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
+
+        // Bearish forecast: fragile for a long, favourable for a short.
+        let verdict = guardian.check(&physics, &account, &intent, 90.0, 95.0, 101.0, now, 0.05);
+        assert_eq!(verdict, RiskVerdict::Allowed);
+
+        // Bullish forecast: the upside tail is the risk for a short.
+        let verdict = guardian.check(&physics, &account, &intent, 99.0, 105.0, 110.0, now, 0.05);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Omega Fragility")));
+    }
+
+    #[test]
+    fn test_sell_inventory_check() {
+        let guardian = RiskGuardian::new();
+        let physics = PhysicsState::default();
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
+        let intent = TradeProposal { side: Side::Sell, price: 100.0, qty: 1.0 };
+
+        // No BTC and only 50 USD of collateral for a 100 USD short.
+        let account = AccountState::new(50.0, 0.0);
+        let verdict = guardian.check(&physics, &account, &intent, 90.0, 95.0, 101.0, now, 0.05);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Insufficient Inventory")));
+
+        // Holding the inventory covers the sell regardless of USDT.
+        let account = AccountState::new(0.0, 1.0);
+        let verdict = guardian.check(&physics, &account, &intent, 90.0, 95.0, 101.0, now, 0.05);
+        assert_eq!(verdict, RiskVerdict::Allowed);
+    }
 }
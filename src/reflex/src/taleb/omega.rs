@@ -40,6 +40,13 @@ impl OmegaScorer {
         ups / downs
     }
 
+    /// Omega for a short position: the same triangle mirrored around zero,
+    /// so the upside tail (p90) becomes the loss side.
+    /// - threshold: MAR as an absolute price level the market must fall below.
+    pub fn calculate_short(p10: f64, p50: f64, p90: f64, threshold: f64) -> f64 {
+        Self::calculate(-p90, -p50, -p10, -threshold)
+    }
+
     /// Expected Gain: Integral of (x - t) * f(x) dx from t to b
     fn expected_gain(a: f64, c: f64, b: f64, h: f64, t: f64) -> f64 {
         if t >= b {
@@ -181,4 +188,17 @@ mod tests {
         println!("Bearish Omega: {}", omega);
         assert!(omega < 1.0);
     }
+
+    #[test]
+    fn test_omega_short_mirrors_long() {
+        // Bearish skew is good for a short: mirror of test_omega_bullish.
+        let long = OmegaScorer::calculate(95.0, 100.0, 120.0, 100.0);
+        let short = OmegaScorer::calculate_short(80.0, 100.0, 105.0, 100.0);
+        assert!(short > 1.0, "Short Omega should be > 1.0 for bearish skew");
+        assert!((long - short).abs() < 1e-9);
+
+        // Bullish skew is fragile for a short.
+        let short_bull = OmegaScorer::calculate_short(95.0, 100.0, 120.0, 100.0);
+        assert!(short_bull < 1.0);
+    }
 }
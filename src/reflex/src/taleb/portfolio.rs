@@ -0,0 +1,377 @@
+use std::collections::{HashMap, VecDeque};
+use statrs::distribution::{ContinuousCDF, Normal};
+use tracing::warn;
+
+use super::{RiskVerdict, TradeProposal};
+
+const RETURN_WINDOW: usize = 500; // Rolling returns kept per symbol
+const MIN_VAR_SAMPLES: usize = 30; // Below this, VaR is not trusted (limits skipped)
+
+/// Portfolio-wide limits. All values are fractions of account equity.
+#[derive(Debug, Clone)]
+pub struct PortfolioLimits {
+    pub max_gross_exposure: f64, // Sum |notional| / equity
+    pub max_net_exposure: f64,   // |Sum notional| / equity
+    pub max_concentration: f64,  // Largest single |notional| / gross
+    pub max_parametric_var: f64, // Variance-covariance VaR / equity
+    pub max_historical_var: f64, // Historical simulation VaR / equity
+    pub var_confidence: f64,     // e.g. 0.99
+}
+
+impl Default for PortfolioLimits {
+    fn default() -> Self {
+        Self {
+            max_gross_exposure: 2.0,
+            max_net_exposure: 1.0,
+            max_concentration: 0.60,
+            max_parametric_var: 0.03,
+            max_historical_var: 0.03,
+            var_confidence: 0.99,
+        }
+    }
+}
+
+/// Snapshot of the portfolio risk numbers (current or hypothetical post-trade).
+#[derive(Debug, Clone, Default)]
+pub struct PortfolioMetrics {
+    pub gross_exposure: f64,
+    pub net_exposure: f64,
+    pub max_concentration: f64,
+    pub parametric_var: Option<f64>,
+    pub historical_var: Option<f64>,
+}
+
+/// Portfolio Risk Book
+/// Tracks signed positions and recent returns for every traded symbol, and vetoes
+/// proposals that would push the whole book past its exposure or VaR limits.
+///
+/// Returns are aligned by update index, so callers should feed `update_price` once per
+/// symbol per bar (the OODA/sim loops already do this on each tick).
+pub struct PortfolioRisk {
+    pub limits: PortfolioLimits,
+    positions: HashMap<String, f64>, // Signed quantity (+long / -short)
+    last_prices: HashMap<String, f64>,
+    returns: HashMap<String, VecDeque<f64>>,
+}
+
+impl PortfolioRisk {
+    pub fn new(limits: PortfolioLimits) -> Self {
+        Self {
+            limits,
+            positions: HashMap::new(),
+            last_prices: HashMap::new(),
+            returns: HashMap::new(),
+        }
+    }
+
+    /// Records a new mark for `symbol` and appends the simple return to its history.
+    pub fn update_price(&mut self, symbol: &str, price: f64) {
+        if price <= 0.0 {
+            return;
+        }
+        if let Some(prev) = self.last_prices.get(symbol) {
+            let r = (price - prev) / prev;
+            let window = self
+                .returns
+                .entry(symbol.to_string())
+                .or_insert_with(|| VecDeque::with_capacity(RETURN_WINDOW));
+            if window.len() >= RETURN_WINDOW {
+                window.pop_front();
+            }
+            window.push_back(r);
+        }
+        self.last_prices.insert(symbol.to_string(), price);
+    }
+
+    /// Overwrites the held quantity (e.g. after an exchange sync).
+    pub fn set_position(&mut self, symbol: &str, qty: f64) {
+        self.positions.insert(symbol.to_string(), qty);
+    }
+
+    /// Replaces the whole book with an exchange snapshot; symbols not listed are flat,
+    /// repeated symbols (lots) add up.
+    pub fn sync_positions(&mut self, book: impl IntoIterator<Item = (String, f64)>) {
+        self.positions.clear();
+        for (symbol, qty) in book {
+            *self.positions.entry(symbol).or_insert(0.0) += qty;
+        }
+    }
+
+    /// Applies a signed fill to the held quantity.
+    pub fn apply_fill(&mut self, symbol: &str, signed_qty: f64) {
+        *self.positions.entry(symbol.to_string()).or_insert(0.0) += signed_qty;
+    }
+
+    pub fn position(&self, symbol: &str) -> f64 {
+        self.positions.get(symbol).copied().unwrap_or(0.0)
+    }
+
+    /// Risk numbers for the book as it stands.
+    pub fn metrics(&self) -> PortfolioMetrics {
+        self.compute_metrics(&self.notionals(None))
+    }
+
+    /// Pre-trade check: vetoes if the post-trade book breaches any portfolio limit.
+    /// Trades that strictly reduce gross exposure are always allowed (de-risking must never be blocked).
+    pub fn check(&self, symbol: &str, intent: &TradeProposal, equity: f64) -> RiskVerdict {
+        if equity <= f64::EPSILON {
+            return RiskVerdict::Veto("Portfolio Veto: Non-positive equity".to_string());
+        }
+
+        let signed_qty = intent.side.sign() * intent.qty;
+        let before = self.compute_metrics(&self.notionals(None));
+        let after = self.compute_metrics(&self.notionals(Some((symbol, signed_qty, intent.price))));
+
+        if after.gross_exposure < before.gross_exposure {
+            return RiskVerdict::Allowed;
+        }
+
+        let limits = &self.limits;
+        let gross_pct = after.gross_exposure / equity;
+        if gross_pct > limits.max_gross_exposure {
+            return RiskVerdict::Veto(format!(
+                "Gross Exposure Limit: {:.2}x > {:.2}x",
+                gross_pct, limits.max_gross_exposure
+            ));
+        }
+
+        let net_pct = after.net_exposure.abs() / equity;
+        if net_pct > limits.max_net_exposure {
+            return RiskVerdict::Veto(format!(
+                "Net Exposure Limit: {:.2}x > {:.2}x",
+                net_pct, limits.max_net_exposure
+            ));
+        }
+
+        // Concentration only means something once there is more than one line in the book.
+        let open_lines = self.notionals(Some((symbol, signed_qty, intent.price)))
+            .iter()
+            .filter(|(_, n)| n.abs() > f64::EPSILON)
+            .count();
+        if open_lines > 1 && after.max_concentration > limits.max_concentration {
+            return RiskVerdict::Veto(format!(
+                "Concentration Limit: {:.1}% > {:.1}%",
+                after.max_concentration * 100.0,
+                limits.max_concentration * 100.0
+            ));
+        }
+
+        if let Some(var) = after.parametric_var {
+            if var / equity > limits.max_parametric_var {
+                return RiskVerdict::Veto(format!(
+                    "Parametric VaR Limit: {:.2}% > {:.2}%",
+                    var / equity * 100.0,
+                    limits.max_parametric_var * 100.0
+                ));
+            }
+        }
+
+        if let Some(var) = after.historical_var {
+            if var / equity > limits.max_historical_var {
+                return RiskVerdict::Veto(format!(
+                    "Historical VaR Limit: {:.2}% > {:.2}%",
+                    var / equity * 100.0,
+                    limits.max_historical_var * 100.0
+                ));
+            }
+        }
+
+        RiskVerdict::Allowed
+    }
+
+    /// Signed notional per symbol, optionally with a hypothetical fill applied.
+    fn notionals(&self, hypothetical: Option<(&str, f64, f64)>) -> Vec<(String, f64)> {
+        let mut book: HashMap<String, f64> = self.positions.clone();
+        if let Some((sym, qty, _)) = hypothetical {
+            *book.entry(sym.to_string()).or_insert(0.0) += qty;
+        }
+
+        let mut out: Vec<(String, f64)> = book
+            .into_iter()
+            .map(|(sym, qty)| {
+                let px = match hypothetical {
+                    Some((h_sym, _, h_px)) if h_sym == sym && !self.last_prices.contains_key(&sym) => h_px,
+                    _ => self.last_prices.get(&sym).copied().unwrap_or(0.0),
+                };
+                (sym, qty * px)
+            })
+            .collect();
+        out.sort_by(|a, b| a.0.cmp(&b.0)); // Deterministic ordering for the covariance matrix
+        out
+    }
+
+    fn compute_metrics(&self, notionals: &[(String, f64)]) -> PortfolioMetrics {
+        let gross: f64 = notionals.iter().map(|(_, n)| n.abs()).sum();
+        let net: f64 = notionals.iter().map(|(_, n)| n).sum();
+        let largest = notionals.iter().map(|(_, n)| n.abs()).fold(0.0, f64::max);
+
+        PortfolioMetrics {
+            gross_exposure: gross,
+            net_exposure: net,
+            max_concentration: if gross > f64::EPSILON { largest / gross } else { 0.0 },
+            parametric_var: self.parametric_var(notionals),
+            historical_var: self.historical_var(notionals),
+        }
+    }
+
+    /// Tail-aligned return matrix for the symbols with non-zero notional.
+    /// Returns None if any held symbol lacks enough history.
+    fn aligned_returns(&self, notionals: &[(String, f64)]) -> Option<(Vec<f64>, Vec<Vec<f64>>)> {
+        let held: Vec<&(String, f64)> = notionals.iter().filter(|(_, n)| n.abs() > f64::EPSILON).collect();
+        if held.is_empty() {
+            return None;
+        }
+
+        let len = held
+            .iter()
+            .map(|(sym, _)| self.returns.get(sym).map(|r| r.len()).unwrap_or(0))
+            .min()
+            .unwrap_or(0);
+        if len < MIN_VAR_SAMPLES {
+            return None;
+        }
+
+        let weights = held.iter().map(|(_, n)| *n).collect();
+        let series = held
+            .iter()
+            .map(|(sym, _)| {
+                let r = &self.returns[sym];
+                r.iter().skip(r.len() - len).copied().collect()
+            })
+            .collect();
+        Some((weights, series))
+    }
+
+    /// Variance-covariance VaR (dollar loss) at the configured confidence.
+    /// VaR = z * sqrt(wᵀ Σ w), with w the signed notionals.
+    fn parametric_var(&self, notionals: &[(String, f64)]) -> Option<f64> {
+        let (w, series) = self.aligned_returns(notionals)?;
+        let n = series[0].len() as f64;
+        let means: Vec<f64> = series.iter().map(|s| s.iter().sum::<f64>() / n).collect();
+
+        let mut variance = 0.0;
+        for i in 0..series.len() {
+            for j in 0..series.len() {
+                let cov = series[i]
+                    .iter()
+                    .zip(series[j].iter())
+                    .map(|(a, b)| (a - means[i]) * (b - means[j]))
+                    .sum::<f64>()
+                    / (n - 1.0);
+                variance += w[i] * w[j] * cov;
+            }
+        }
+
+        let z = Normal::new(0.0, 1.0).ok()?.inverse_cdf(self.limits.var_confidence);
+        Some(z * variance.max(0.0).sqrt())
+    }
+
+    /// Historical-simulation VaR (dollar loss): replays recent joint returns on today's book.
+    fn historical_var(&self, notionals: &[(String, f64)]) -> Option<f64> {
+        let (w, series) = self.aligned_returns(notionals)?;
+        let mut pnl: Vec<f64> = (0..series[0].len())
+            .map(|t| w.iter().zip(series.iter()).map(|(wi, s)| wi * s[t]).sum())
+            .collect();
+        pnl.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let idx = (((1.0 - self.limits.var_confidence) * pnl.len() as f64).floor() as usize).min(pnl.len() - 1);
+        let var = -pnl[idx];
+        if var.is_nan() {
+            warn!("PORTFOLIO: Historical VaR produced NaN. Check return feed.");
+            return None;
+        }
+        Some(var.max(0.0))
+    }
+}
+
+impl Default for PortfolioRisk {
+    fn default() -> Self {
+        Self::new(PortfolioLimits::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::taleb::Side;
+
+    fn buy(price: f64, qty: f64) -> TradeProposal {
+        TradeProposal { side: Side::Buy, price, qty }
+    }
+
+    fn feed(book: &mut PortfolioRisk, symbol: &str, start: f64, moves: &[f64]) {
+        let mut p = start;
+        book.update_price(symbol, p);
+        for m in moves {
+            p *= 1.0 + m;
+            book.update_price(symbol, p);
+        }
+    }
+
+    #[test]
+    fn test_gross_exposure_veto() {
+        let mut book = PortfolioRisk::default();
+        book.update_price("BTC-USDT", 100.0);
+
+        // 1,000 equity, 2x gross cap -> 25 units @ 100 = 2,500 notional breaches
+        let verdict = book.check("BTC-USDT", &buy(100.0, 25.0), 1000.0);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Gross Exposure")));
+
+        let verdict = book.check("BTC-USDT", &buy(100.0, 5.0), 1000.0);
+        assert_eq!(verdict, RiskVerdict::Allowed);
+    }
+
+    #[test]
+    fn test_concentration_veto() {
+        let mut book = PortfolioRisk::default();
+        book.update_price("BTC-USDT", 100.0);
+        book.update_price("ETH-USDT", 10.0);
+        book.set_position("ETH-USDT", 10.0); // 100 notional
+
+        // Adding 900 BTC notional -> 90% of gross in one name
+        let verdict = book.check("BTC-USDT", &buy(100.0, 9.0), 10_000.0);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("Concentration")));
+    }
+
+    #[test]
+    fn test_reducing_trade_always_allowed() {
+        let mut book = PortfolioRisk::default();
+        book.update_price("BTC-USDT", 100.0);
+        book.set_position("BTC-USDT", 50.0); // Already 5x on 1,000 equity
+
+        let sell = TradeProposal { side: Side::Sell, price: 100.0, qty: 10.0 };
+        assert_eq!(book.check("BTC-USDT", &sell, 1000.0), RiskVerdict::Allowed);
+    }
+
+    #[test]
+    fn test_var_veto_on_volatile_history() {
+        let mut book = PortfolioRisk::default();
+        // Alternating +/-5% moves -> ~5% daily sigma
+        let moves: Vec<f64> = (0..100).map(|i| if i % 2 == 0 { 0.05 } else { -0.05 }).collect();
+        feed(&mut book, "BTC-USDT", 100.0, &moves);
+
+        let price = book.last_prices["BTC-USDT"];
+        // 50% of equity in a 5% sigma asset -> ~5.8% parametric VaR at 99%
+        let verdict = book.check("BTC-USDT", &buy(price, 500.0 / price), 1000.0);
+        assert!(matches!(verdict, RiskVerdict::Veto(ref r) if r.contains("VaR")));
+
+        let metrics = book.metrics();
+        assert!(metrics.parametric_var.is_none(), "Flat book has no VaR");
+    }
+
+    #[test]
+    fn test_hedged_book_has_lower_var() {
+        let mut book = PortfolioRisk::default();
+        let moves: Vec<f64> = (0..100).map(|i| ((i as f64) * 0.7).sin() * 0.02).collect();
+        feed(&mut book, "BTC-USDT", 100.0, &moves);
+        feed(&mut book, "BTC-PERP", 100.0, &moves); // Perfectly correlated
+
+        book.set_position("BTC-USDT", 5.0);
+        let outright = book.metrics().parametric_var.unwrap();
+
+        book.set_position("BTC-PERP", -5.0 * book.last_prices["BTC-USDT"] / book.last_prices["BTC-PERP"]);
+        let hedged = book.metrics().parametric_var.unwrap();
+
+        assert!(hedged < outright * 0.01, "Correlation not captured: {} vs {}", hedged, outright);
+    }
+}
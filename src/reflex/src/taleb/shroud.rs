@@ -1,4 +1,9 @@
+use std::collections::HashMap;
+use tracing::info;
+
 use crate::client::brain::StrategyIntent;
+use crate::ledger::Side;
+use crate::market::symbol;
 
 
 #[derive(Debug, PartialEq)]
@@ -7,11 +12,148 @@ pub enum ShroudVerdict {
     NuclearExit(String), // Reason
 }
 
-pub struct RiskShroud;
+/// A position we actually hold, with its trailing BES stop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtectedPosition {
+    pub symbol: String,
+    pub side: Side,
+    pub qty: f64, // Absolute size
+    pub entry_price: f64,
+    pub stop: Option<f64>, // None until the first valid forecast arrives
+}
+
+/// The Risk Shroud (D-22)
+/// Protects open positions (not the Brain's intent) with BES stops that trail
+/// only in our favour: up for longs, down for shorts.
+/// Keyed by canonical symbol, so venue pairs ("XXBTZUSD") and pipeline names ("BTC-USDT") meet.
+#[derive(Default)]
+pub struct RiskShroud {
+    positions: HashMap<String, ProtectedPosition>,
+}
 
 impl RiskShroud {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// BES stop level for a side from the forecast, or None if the forecast is unusable.
+    pub fn bes_stop(side: Side, intent: &StrategyIntent) -> Option<f64> {
+        if intent.model_used == "warming_up" || intent.model_used == "error_fallback" {
+            return None;
+        }
+        let stop = match side {
+            Side::Buy => (intent.forecast_p10 + intent.forecast_p20) / 2.0,
+            Side::Sell => (intent.forecast_p80 + intent.forecast_p90) / 2.0,
+        };
+        if stop.is_finite() && stop > 0.0 { Some(stop) } else { None }
+    }
+
+    /// Replace the tracked book with an exchange snapshot of (symbol, signed qty, entry).
+    /// Lots of one instrument net into one position (cost-weighted entry).
+    /// Positions that disappeared are dropped; a side flip resets the stop.
+    pub fn sync_positions<'a>(&mut self, snapshot: impl IntoIterator<Item = (&'a str, f64, f64)>) {
+        let mut book: HashMap<String, (f64, f64)> = HashMap::new(); // Key -> (signed qty, signed cost)
+        for (symbol, signed_qty, entry_price) in snapshot {
+            let lot = book.entry(symbol::canonical(symbol)).or_default();
+            lot.0 += signed_qty;
+            lot.1 += signed_qty * entry_price;
+        }
+        for (key, (qty, cost)) in &book {
+            let entry = if qty.abs() > f64::EPSILON { cost / qty } else { 0.0 };
+            self.sync_position(key, *qty, entry);
+        }
+        self.positions.retain(|s, _| book.contains_key(s));
+    }
+
+    pub fn sync_position(&mut self, symbol: &str, signed_qty: f64, entry_price: f64) {
+        let symbol = symbol::canonical(symbol);
+        if signed_qty.abs() <= f64::EPSILON {
+            self.positions.remove(&symbol);
+            return;
+        }
+        let side = if signed_qty > 0.0 { Side::Buy } else { Side::Sell };
+        match self.positions.get_mut(&symbol) {
+            Some(pos) if pos.side == side => {
+                pos.qty = signed_qty.abs();
+                pos.entry_price = entry_price;
+            }
+            _ => {
+                self.positions.insert(symbol.clone(), ProtectedPosition {
+                    symbol,
+                    side,
+                    qty: signed_qty.abs(),
+                    entry_price,
+                    stop: None,
+                });
+            }
+        }
+    }
+
+    pub fn remove(&mut self, symbol: &str) {
+        self.positions.remove(&symbol::canonical(symbol));
+    }
+
+    pub fn position(&self, symbol: &str) -> Option<&ProtectedPosition> {
+        self.positions.get(&symbol::canonical(symbol))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &ProtectedPosition> {
+        self.positions.values()
+    }
+
+    /// Ratchet the trailing stop from the latest forecast. Never loosens.
+    pub fn update_forecast(&mut self, symbol: &str, intent: &StrategyIntent) {
+        let Some(side) = self.position(symbol).map(|p| p.side) else { return };
+        if let Some(candidate) = Self::bes_stop(side, intent) {
+            self.ratchet(symbol, candidate);
+        }
+    }
+
+    /// Ratchet from a Brain Forecast (p10/p50/p90 only): p20/p80 are interpolated
+    /// linearly in probability, e.g. p20 = p10 + (p50 - p10) / 4.
+    pub fn update_quantiles(&mut self, symbol: &str, p10: f64, p50: f64, p90: f64) {
+        let Some(side) = self.position(symbol).map(|p| p.side) else { return };
+        let stop = match side {
+            Side::Buy => (p10 + (p10 + (p50 - p10) / 4.0)) / 2.0,
+            Side::Sell => ((p90 - (p90 - p50) / 4.0) + p90) / 2.0,
+        };
+        if stop.is_finite() && stop > 0.0 && p10 < p90 {
+            self.ratchet(symbol, stop);
+        }
+    }
+
+    fn ratchet(&mut self, symbol: &str, candidate: f64) {
+        let Some(pos) = self.positions.get_mut(&symbol::canonical(symbol)) else { return };
+
+        let next = match (pos.side, pos.stop) {
+            (_, None) => candidate,
+            (Side::Buy, Some(stop)) => stop.max(candidate),
+            (Side::Sell, Some(stop)) => stop.min(candidate),
+        };
+        if pos.stop != Some(next) {
+            info!("🛡️ SHROUD: {} {} stop -> {:.2}", pos.symbol, pos.side, next);
+            pos.stop = Some(next);
+        }
+    }
+
+    /// Evaluate a held position against its trailing stop.
+    pub fn check_position(&self, symbol: &str, current_price: f64) -> ShroudVerdict {
+        let Some(pos) = self.position(symbol) else { return ShroudVerdict::Safe };
+        let Some(stop) = pos.stop else { return ShroudVerdict::Safe };
+
+        let breached = match pos.side {
+            Side::Buy => current_price < stop,
+            Side::Sell => current_price > stop,
+        };
+        if !breached {
+            return ShroudVerdict::Safe;
+        }
+
+        let pnl_pct = pos.side.sign() * (current_price - pos.entry_price) / pos.entry_price * 100.0;
+        ShroudVerdict::NuclearExit(format!(
+            "Price ({:.2}) breached trailing BES Shroud on {} {} {:.6} (Entry: {:.2} | Stop: {:.2} | PnL: {:.2}%)",
+            current_price, pos.symbol, pos.side, pos.qty, pos.entry_price, stop, pnl_pct
+        ))
     }
 
     /// Calculates the Bayesian Expected Shortfall (BES) and checks if price breaches the shroud.
@@ -30,13 +172,11 @@ impl RiskShroud {
              return ShroudVerdict::Safe;
         }
 
-        // Determine direction from Intent (Boyd's current stance)
-        // Note: Ideally, Shroud protects the CURRENT POSITION. 
-        // But in this architecture, Intent reflects the target state.
-        // If Intent is LONG, we protect against downside.
-        let action = &intent.action;
+        // Stateless check in the direction of the Intent.
+        // Held positions go through `sync_positions` / `check_position` instead.
+        let action = Side::parse(&intent.action);
 
-        if action == "LONG" {
+        if action == Some(Side::Buy) {
              let p10 = intent.forecast_p10;
              let p20 = intent.forecast_p20;
              
@@ -50,7 +190,7 @@ impl RiskShroud {
                  ));
              }
 
-        } else if action == "SHORT" {
+        } else if action == Some(Side::Sell) {
              let p80 = intent.forecast_p80;
              let p90 = intent.forecast_p90;
              
@@ -74,6 +214,28 @@ mod tests {
     use super::*;
     use crate::client::brain::StrategyIntent;
 
+    #[test]
+    fn test_quantile_forecast_ratchets_stop() {
+        let mut shroud = RiskShroud::new();
+        shroud.sync_position("XBT/USD", 1.0, 100.0);
+        shroud.sync_position("ETH/USD", -2.0, 50.0);
+
+        // Long: BES between p10 and the interpolated p20 (92 + 8/4 = 94) -> 93
+        shroud.update_quantiles("XBT/USD", 92.0, 100.0, 108.0);
+        assert_eq!(shroud.position("XBT/USD").unwrap().stop, Some(93.0));
+        shroud.update_quantiles("XBT/USD", 90.0, 100.0, 110.0); // Lower: ignored
+        assert_eq!(shroud.position("XBT/USD").unwrap().stop, Some(93.0));
+
+        // Short: BES between the interpolated p80 (54 - 4/4 = 53) and p90 -> 53.5
+        shroud.update_quantiles("ETH/USD", 46.0, 50.0, 54.0);
+        assert_eq!(shroud.position("ETH/USD").unwrap().stop, Some(53.5));
+        assert!(matches!(shroud.check_position("ETH/USD", 54.0), ShroudVerdict::NuclearExit(_)));
+
+        // Inverted distribution is ignored
+        shroud.update_quantiles("ETH/USD", 54.0, 50.0, 46.0);
+        assert_eq!(shroud.position("ETH/USD").unwrap().stop, Some(53.5));
+    }
+
     #[test]
     fn test_bes_long_breach() {
         let shroud = RiskShroud::new();
@@ -118,4 +280,68 @@ mod tests {
             _ => panic!("Should have panicked"),
         }
     }
+
+    fn forecast(p10: f64, p20: f64, p80: f64, p90: f64) -> StrategyIntent {
+        StrategyIntent {
+            forecast_p10: p10,
+            forecast_p20: p20,
+            forecast_p50: (p20 + p80) / 2.0,
+            forecast_p80: p80,
+            forecast_p90: p90,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_trailing_stop_ratchets_in_our_favour() {
+        let mut shroud = RiskShroud::new();
+        shroud.sync_position("BTC-USDT", 1.0, 100.0);
+
+        // No forecast yet -> no stop
+        assert_eq!(shroud.check_position("BTC-USDT", 50.0), ShroudVerdict::Safe);
+
+        shroud.update_forecast("BTC-USDT", &forecast(94.0, 96.0, 104.0, 106.0)); // BES 95
+        assert_eq!(shroud.position("BTC-USDT").unwrap().stop, Some(95.0));
+
+        // Higher forecast ratchets up, lower one is ignored
+        shroud.update_forecast("BTC-USDT", &forecast(102.0, 104.0, 112.0, 114.0)); // BES 103
+        shroud.update_forecast("BTC-USDT", &forecast(90.0, 92.0, 100.0, 102.0)); // BES 91
+        assert_eq!(shroud.position("BTC-USDT").unwrap().stop, Some(103.0));
+
+        assert_eq!(shroud.check_position("BTC-USDT", 103.5), ShroudVerdict::Safe);
+        match shroud.check_position("BTC-USDT", 102.5) {
+            ShroudVerdict::NuclearExit(r) => assert!(r.contains("trailing BES Shroud") && r.contains("PnL: 2.50%")),
+            _ => panic!("Long stop should be breached"),
+        }
+    }
+
+    #[test]
+    fn test_short_position_and_sync() {
+        let mut shroud = RiskShroud::new();
+        shroud.sync_positions([("BTC-USDT", -2.0, 100.0), ("ETH-USDT", 1.0, 10.0)]);
+        shroud.update_forecast("BTC-USDT", &forecast(90.0, 92.0, 98.0, 100.0)); // BES 99
+        shroud.update_forecast("BTC-USDT", &forecast(95.0, 97.0, 103.0, 105.0)); // BES 104 (ignored)
+        assert_eq!(shroud.position("BTC-USDT").unwrap().stop, Some(99.0));
+        assert!(matches!(shroud.check_position("BTC-USDT", 99.5), ShroudVerdict::NuclearExit(_)));
+
+        // Flip to long resets the stop; closed ETH is dropped
+        shroud.sync_positions([("BTC-USDT", 1.0, 99.5)]);
+        let pos = shroud.position("BTC-USDT").unwrap();
+        assert_eq!((pos.side, pos.stop), (Side::Buy, None));
+        assert!(shroud.position("ETH-USDT").is_none());
+    }
+
+    #[test]
+    fn test_venue_lots_net_under_pipeline_symbol() {
+        let mut shroud = RiskShroud::new();
+        // Two Kraken lots of the pair the BTC-USDT pipeline trades
+        shroud.sync_positions([("XXBTZUSD", 1.0, 100.0), ("XXBTZUSD", 3.0, 104.0)]);
+        let pos = shroud.position("BTC-USDT").unwrap();
+        assert_eq!((pos.side, pos.qty, pos.entry_price), (Side::Buy, 4.0, 103.0));
+
+        // The pipeline's forecast reaches the venue position
+        shroud.update_quantiles("BTC-USDT", 92.0, 100.0, 108.0);
+        assert_eq!(shroud.position("XBT/USD").unwrap().stop, Some(93.0));
+        assert!(matches!(shroud.check_position("XXBTZUSD", 92.5), ShroudVerdict::NuclearExit(_)));
+    }
 }
@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+use crate::governor::rebalancer::Rebalancer;
+
 pub struct BESKelly;
 
 impl BESKelly {
@@ -83,10 +86,213 @@ impl BESKelly {
     }
 }
 
+/// One step of the sizing pipeline. `qty` is the size after the stage (base units),
+/// or `None` when the stage had no input to act on (e.g. Kelly without equity).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SizingStage {
+    pub stage: String,
+    pub qty: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SizingBreakdown {
+    pub stages: Vec<SizingStage>,
+    pub qty: f64,
+}
+
+/// Per-decision inputs to the sizing pipeline.
+#[derive(Debug, Clone)]
+pub struct SizingInputs {
+    pub price: f64,
+    pub confidence: f64, // Win probability for Kelly
+    pub conviction: f64, // Signal strength (0.0 - 1.0), scales the tier cap
+    pub tier_cap: f64,   // Risk Ladder max risk (lots)
+    pub aggression: f64, // Legislative multiplier
+    pub direction: f64,  // Order side: > 0 buys, < 0 sells
+    pub net_position: f64, // Signed base units already held by this pipeline
+}
+
+/// Unified Position Sizing
+/// The single path from a signal to an order quantity. Stages run in a fixed order:
+/// Kelly -> Tier Cap -> Fidelity -> Aggression -> Risk Limits.
+///
+/// Account/governor inputs (equity, fidelity) are pushed in by the owner loop.
+/// The capital cap bounds the pipeline's total exposure after the fill, not each order.
+#[derive(Debug, Clone)]
+pub struct SizingPipeline {
+    pub equity: Option<f64>,
+    pub fidelity: f64,               // Rebalancer fidelity (D-90)
+    pub take_profit_pct: f64,        // Kelly win distance
+    pub stop_loss_pct: f64,          // Kelly loss distance
+    pub max_qty: f64,
+    pub max_notional: f64,
+    pub drawdown_scale: f64,         // Drawdown breaker ReduceSize factor (1.0 = off)
+    pub capital_cap: Option<f64>,    // USD this pipeline may deploy (CapitalAllocator); None = whole account
+}
+
+impl Default for SizingPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SizingPipeline {
+    pub fn new() -> Self {
+        Self {
+            equity: None,
+            fidelity: 1.0,
+            take_profit_pct: 0.02,
+            stop_loss_pct: 0.01,
+            max_qty: f64::INFINITY,
+            max_notional: f64::INFINITY,
+            drawdown_scale: 1.0,
+            capital_cap: None,
+        }
+    }
+
+    pub fn size(&self, inputs: &SizingInputs) -> SizingBreakdown {
+        let mut stages = Vec::with_capacity(5);
+        let price = inputs.price;
+
+        // 1. Kelly Fraction (USD -> base units). The bracket is symmetric in percent,
+        // so the same payoff geometry applies to shorts. Kelly sees the pipeline's
+        // allocated capital, not the whole account.
+        let capital = match (self.equity, self.capital_cap) {
+            (Some(equity), Some(cap)) => Some(equity.min(cap)),
+            (equity, _) => equity,
+        };
+        let kelly = match capital {
+            Some(capital) if capital > 0.0 && price > 0.0 => {
+                let usd = BESKelly::allocate(
+                    capital,
+                    price,
+                    price * (1.0 + self.take_profit_pct),
+                    price * (1.0 - self.stop_loss_pct),
+                    inputs.confidence,
+                );
+                Some(usd / price)
+            }
+            _ => None,
+        };
+        stages.push(SizingStage { stage: "kelly".to_string(), qty: kelly });
+
+        // 2. Tier Cap (Risk Ladder), scaled by conviction
+        let cap = inputs.tier_cap * inputs.conviction.clamp(0.0, 1.0);
+        let mut qty = kelly.map_or(cap, |k| k.min(cap));
+        stages.push(SizingStage { stage: "tier_cap".to_string(), qty: Some(qty) });
+
+        // 3. Fidelity (Rebalancer)
+        qty = Rebalancer::scale_by_fidelity(self.fidelity, qty);
+        stages.push(SizingStage { stage: "fidelity".to_string(), qty: Some(qty) });
+
+        // 4. Aggression (Legislation)
+        qty *= inputs.aggression.clamp(0.1, 2.0);
+        stages.push(SizingStage { stage: "aggression".to_string(), qty: Some(qty) });
+
+        // 5. Risk Limits (drawdown breaker scale, then hard ceilings incl. the capital allocation)
+        qty *= self.drawdown_scale.clamp(0.0, 1.0);
+        qty = qty.min(self.max_qty);
+        if price > 0.0 {
+            qty = qty.min(self.max_notional / price);
+            if let Some(cap) = self.capital_cap {
+                // |net + qty| * price <= cap: adding orders only get the headroom left,
+                // reducing ones may close the position and open up to the cap beyond it
+                let held = inputs.net_position * inputs.direction.signum();
+                qty = qty.min(cap.max(0.0) / price - held);
+            }
+        }
+        qty = qty.max(0.0);
+        stages.push(SizingStage { stage: "risk_limits".to_string(), qty: Some(qty) });
+
+        SizingBreakdown { stages, qty }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn inputs() -> SizingInputs {
+        SizingInputs {
+            price: 100.0,
+            confidence: 0.6,
+            conviction: 1.0,
+            tier_cap: 0.5,
+            aggression: 1.0,
+            direction: 1.0,
+            net_position: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_pipeline_stage_order() {
+        let mut pipe = SizingPipeline::new();
+        pipe.equity = Some(500.0);
+        pipe.fidelity = 0.8;
+        pipe.max_qty = 0.3;
+
+        let mut i = inputs();
+        i.aggression = 2.0;
+        let out = pipe.size(&i);
+
+        let names: Vec<&str> = out.stages.iter().map(|s| s.stage.as_str()).collect();
+        assert_eq!(names, vec!["kelly", "tier_cap", "fidelity", "aggression", "risk_limits"]);
+
+        // Kelly binds below the 0.5 tier cap
+        let kelly = out.stages[0].qty.unwrap();
+        assert!(kelly > 0.0 && kelly < 0.5);
+        assert_eq!(out.stages[1].qty, Some(kelly));
+        assert!((out.stages[2].qty.unwrap() - kelly * 0.8).abs() < 1e-12);
+        assert!((out.stages[3].qty.unwrap() - kelly * 1.6).abs() < 1e-12);
+        assert_eq!(out.qty, (kelly * 1.6).min(0.3));
+    }
+
+    #[test]
+    fn test_pipeline_without_equity_uses_tier_cap() {
+        let mut pipe = SizingPipeline::new();
+        let mut i = inputs();
+        i.tier_cap = 0.05;
+        i.conviction = 0.5;
+
+        let out = pipe.size(&i);
+        assert_eq!(out.stages[0].qty, None);
+        assert!((out.qty - 0.025).abs() < 1e-12); // 0.05 * 0.5
+
+        // Fidelity lock zeroes the size
+        pipe.fidelity = 0.4;
+        assert_eq!(pipe.size(&i).qty, 0.0);
+    }
+
+    #[test]
+    fn test_pipeline_respects_capital_cap() {
+        let mut pipe = SizingPipeline::new();
+        pipe.equity = Some(10_000.0);
+        let mut i = inputs();
+        i.tier_cap = 100.0;
+        let uncapped = pipe.size(&i);
+
+        // Kelly sizes off the allocation, not the account
+        pipe.capital_cap = Some(1_000.0);
+        let capped = pipe.size(&i);
+        let ratio = capped.stages[0].qty.unwrap() / uncapped.stages[0].qty.unwrap();
+        assert!((ratio - 0.1).abs() < 1e-9);
+
+        // And the notional never exceeds it, even when only the tier cap sizes
+        pipe.equity = None;
+        i.aggression = 2.0;
+        pipe.capital_cap = Some(10.0);
+        assert!((pipe.size(&i).qty - 0.1).abs() < 1e-12); // $10 / $100
+
+        // The cap covers what is already held: $4 of $10 in -> $6 of room left
+        i.net_position = 0.04;
+        assert!((pipe.size(&i).qty - 0.06).abs() < 1e-12);
+        // Full -> nothing more on that side, while a sell may close and reverse up to the cap
+        i.net_position = 0.1;
+        assert_eq!(pipe.size(&i).qty, 0.0);
+        i.direction = -1.0;
+        assert!((pipe.size(&i).qty - 0.2).abs() < 1e-12);
+    }
+
     #[test]
     fn test_kelly_positive() {
         // High confidence, good RR.
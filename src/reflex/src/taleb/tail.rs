@@ -0,0 +1,314 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::time::Duration;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::ledger::Side;
+
+const RETURN_WINDOW: usize = 1000; // Rolling realized returns kept
+pub const MIN_TAIL_SAMPLES: usize = 30; // Below this, estimates are not trusted
+const CF_ES_STEPS: usize = 50; // Integration points for Cornish-Fisher ES
+pub const DEFAULT_HORIZON: Duration = Duration::from_secs(60); // Brain Forecast horizon
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailMethod {
+    Historical,    // Equal-weighted empirical quantile
+    Ewma,          // Exponentially weighted empirical quantile (recent returns count more)
+    CornishFisher, // Normal quantile adjusted for skew and excess kurtosis
+}
+
+impl FromStr for TailMethod {
+    type Err = String;
+
+    /// "historical", "ewma" or "cornish-fisher".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "historical" => Ok(TailMethod::Historical),
+            "ewma" => Ok(TailMethod::Ewma),
+            "cornish-fisher" => Ok(TailMethod::CornishFisher),
+            other => Err(format!("Unknown tail method: {}", other)),
+        }
+    }
+}
+
+/// VaR and Expected Shortfall as positive loss fractions (0.02 = 2% loss).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TailRisk {
+    pub var: f64,
+    pub es: f64,
+}
+
+/// Realized Tail Estimator
+/// Measures the tail from the engine's own return history, independent of the Brain.
+/// Used as a fallback when the forecast is stale and as a cross-check on its quantiles.
+///
+/// Sampling cadence defines the horizon: `update_price` keeps one return per `horizon`
+/// (the forecast horizon), so the cross-check compares like with like.
+pub struct TailEstimator {
+    pub method: TailMethod,
+    pub ewma_lambda: f64,
+    pub horizon: Duration,
+    last_price: Option<f64>,
+    last_sample_ms: Option<i64>,
+    returns: VecDeque<f64>,
+}
+
+impl Default for TailEstimator {
+    fn default() -> Self {
+        Self::new(TailMethod::Historical)
+    }
+}
+
+impl TailEstimator {
+    pub fn new(method: TailMethod) -> Self {
+        Self {
+            method,
+            ewma_lambda: 0.97,
+            horizon: DEFAULT_HORIZON,
+            last_price: None,
+            last_sample_ms: None,
+            returns: VecDeque::with_capacity(RETURN_WINDOW),
+        }
+    }
+
+    pub fn with_horizon(mut self, horizon: Duration) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    /// Feed every tick; a return is only taken once `horizon` has passed since the last sample.
+    pub fn update_price(&mut self, price: f64, now_ms: i64) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        if self.last_sample_ms.is_some_and(|at| now_ms - at < self.horizon.as_millis() as i64) {
+            return;
+        }
+        if let Some(prev) = self.last_price {
+            self.push_return(price / prev - 1.0);
+        }
+        self.last_price = Some(price);
+        self.last_sample_ms = Some(now_ms);
+    }
+
+    pub fn push_return(&mut self, r: f64) {
+        if !r.is_finite() {
+            return;
+        }
+        if self.returns.len() >= RETURN_WINDOW {
+            self.returns.pop_front();
+        }
+        self.returns.push_back(r);
+    }
+
+    pub fn len(&self) -> usize {
+        self.returns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.returns.is_empty()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.returns.len() >= MIN_TAIL_SAMPLES
+    }
+
+    /// Return-space quantile at probability `q` (e.g. 0.10 -> p10 return).
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        self.quantile_with(self.method, q)
+    }
+
+    pub fn quantile_with(&self, method: TailMethod, q: f64) -> Option<f64> {
+        if !self.is_ready() || !(0.0..1.0).contains(&q) || q <= 0.0 {
+            return None;
+        }
+        match method {
+            TailMethod::Historical => Some(self.weighted_lower(q, &self.equal_weights(), 1.0).0),
+            TailMethod::Ewma => Some(self.weighted_lower(q, &self.ewma_weights(), 1.0).0),
+            TailMethod::CornishFisher => self.cornish_fisher_quantile(q, 1.0),
+        }
+    }
+
+    /// Downside VaR / ES at `confidence` (e.g. 0.99) using the configured method.
+    pub fn estimate(&self, confidence: f64) -> Option<TailRisk> {
+        self.estimate_with(self.method, confidence)
+    }
+
+    pub fn estimate_with(&self, method: TailMethod, confidence: f64) -> Option<TailRisk> {
+        self.estimate_signed(method, confidence, 1.0)
+    }
+
+    /// VaR / ES of holding `side`: downside moves for a long, upside moves for a short.
+    pub fn estimate_for(&self, side: Side, confidence: f64) -> Option<TailRisk> {
+        self.estimate_signed(self.method, confidence, side.sign())
+    }
+
+    /// Tail of `sign * return` (sign -1 mirrors the distribution for a short).
+    fn estimate_signed(&self, method: TailMethod, confidence: f64, sign: f64) -> Option<TailRisk> {
+        let alpha = 1.0 - confidence;
+        if !self.is_ready() || alpha <= 0.0 || alpha >= 1.0 {
+            return None;
+        }
+        let (q, es) = match method {
+            TailMethod::Historical => self.weighted_lower(alpha, &self.equal_weights(), sign),
+            TailMethod::Ewma => self.weighted_lower(alpha, &self.ewma_weights(), sign),
+            TailMethod::CornishFisher => {
+                let q = self.cornish_fisher_quantile(alpha, sign)?;
+                // ES = mean of the quantile function over (0, alpha) (midpoint rule)
+                let step = alpha / CF_ES_STEPS as f64;
+                let mut sum = 0.0;
+                for i in 0..CF_ES_STEPS {
+                    sum += self.cornish_fisher_quantile((i as f64 + 0.5) * step, sign)?;
+                }
+                (q, sum / CF_ES_STEPS as f64)
+            }
+        };
+        Some(TailRisk { var: (-q).max(0.0), es: (-es).max(0.0) })
+    }
+
+    fn equal_weights(&self) -> Vec<f64> {
+        let n = self.returns.len();
+        vec![1.0 / n as f64; n]
+    }
+
+    fn ewma_weights(&self) -> Vec<f64> {
+        let n = self.returns.len();
+        let lambda = self.ewma_lambda.clamp(0.0, 0.9999);
+        // Oldest first: w_i ∝ λ^(n-1-i)
+        let raw: Vec<f64> = (0..n).map(|i| lambda.powi((n - 1 - i) as i32)).collect();
+        let total: f64 = raw.iter().sum();
+        raw.into_iter().map(|w| w / total).collect()
+    }
+
+    /// Weighted lower quantile of `sign * return` and the weighted mean at or below it.
+    fn weighted_lower(&self, q: f64, weights: &[f64], sign: f64) -> (f64, f64) {
+        let mut pairs: Vec<(f64, f64)> = self.returns.iter().map(|r| sign * r).zip(weights.iter().copied()).collect();
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut cum = 0.0;
+        let mut tail_sum = 0.0;
+        for (r, w) in &pairs {
+            cum += w;
+            tail_sum += r * w;
+            if cum >= q - 1e-12 {
+                return (*r, tail_sum / cum);
+            }
+        }
+        let last = pairs.last().map(|p| p.0).unwrap_or(0.0);
+        (last, tail_sum / cum.max(f64::EPSILON))
+    }
+
+    /// Mean, sd, skew and excess kurtosis of `sign * return`.
+    fn moments(&self, sign: f64) -> (f64, f64, f64, f64) {
+        let n = self.returns.len() as f64;
+        let mean = sign * self.returns.iter().sum::<f64>() / n;
+        let m2 = self.returns.iter().map(|r| (sign * r - mean).powi(2)).sum::<f64>() / n;
+        let m3 = self.returns.iter().map(|r| (sign * r - mean).powi(3)).sum::<f64>() / n;
+        let m4 = self.returns.iter().map(|r| (sign * r - mean).powi(4)).sum::<f64>() / n;
+        let sd = m2.sqrt();
+        if sd <= f64::EPSILON {
+            return (mean, 0.0, 0.0, 0.0);
+        }
+        (mean, sd, m3 / sd.powi(3), m4 / m2.powi(2) - 3.0)
+    }
+
+    fn cornish_fisher_quantile(&self, q: f64, sign: f64) -> Option<f64> {
+        let (mean, sd, skew, kurt) = self.moments(sign);
+        let z = Normal::new(0.0, 1.0).ok()?.inverse_cdf(q);
+        let z_cf = z
+            + (z.powi(2) - 1.0) * skew / 6.0
+            + (z.powi(3) - 3.0 * z) * kurt / 24.0
+            - (2.0 * z.powi(3) - 5.0 * z) * skew.powi(2) / 36.0;
+        Some(mean + sd * z_cf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimator_with(returns: &[f64], method: TailMethod) -> TailEstimator {
+        let mut e = TailEstimator::new(method);
+        for r in returns {
+            e.push_return(*r);
+        }
+        e
+    }
+
+    #[test]
+    fn test_not_ready_without_samples() {
+        let e = estimator_with(&[0.01; 10], TailMethod::Historical);
+        assert!(e.estimate(0.99).is_none());
+        assert!(e.quantile(0.10).is_none());
+    }
+
+    #[test]
+    fn test_samples_once_per_horizon() {
+        let mut e = TailEstimator::default().with_horizon(Duration::from_secs(60));
+        e.update_price(100.0, 0);
+        e.update_price(90.0, 30_000); // Inside the horizon: ignored
+        e.update_price(102.0, 60_000);
+        e.update_price(101.0, 61_000);
+        assert_eq!(e.len(), 1);
+        assert!((e.returns[0] - 0.02).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_short_side_tail_is_the_upside() {
+        // Mostly small losses, a few large gains: a short's tail is the gains
+        let mut rs = vec![0.05; 5];
+        rs.extend(std::iter::repeat_n(-0.001, 95));
+        let e = estimator_with(&rs, TailMethod::Historical);
+        assert_eq!(e.estimate_for(Side::Buy, 0.95).unwrap().var, 0.001);
+        let short = e.estimate_for(Side::Sell, 0.95).unwrap();
+        assert!((short.var - 0.05).abs() < 1e-12 && (short.es - 0.05).abs() < 1e-12);
+        assert_eq!("cornish_fisher".parse::<TailMethod>(), Ok(TailMethod::CornishFisher));
+    }
+
+    #[test]
+    fn test_historical_var_es() {
+        // 100 returns: -0.10, -0.09 ... -0.01 then 90 small gains
+        let mut rs: Vec<f64> = (1..=10).map(|i| -(i as f64) / 100.0).collect();
+        rs.extend(std::iter::repeat_n(0.001, 90));
+        let e = estimator_with(&rs, TailMethod::Historical);
+
+        let t = e.estimate(0.95).unwrap();
+        // 5% of 100 samples -> the 5th worst return (-0.06)
+        assert!((t.var - 0.06).abs() < 1e-9, "VaR {}", t.var);
+        // ES = mean of the 5 worst = 0.08
+        assert!((t.es - 0.08).abs() < 1e-9, "ES {}", t.es);
+        assert!(t.es >= t.var);
+    }
+
+    #[test]
+    fn test_ewma_weights_recent_losses() {
+        // Old losses, recent calm: EWMA sees a thinner tail than equal weighting
+        let mut rs: Vec<f64> = vec![-0.05; 20];
+        rs.extend(std::iter::repeat_n(0.001, 180));
+        let hist = estimator_with(&rs, TailMethod::Historical).estimate(0.95).unwrap();
+        let ewma = estimator_with(&rs, TailMethod::Ewma).estimate(0.95).unwrap();
+        assert!(ewma.var < hist.var, "EWMA {} vs Hist {}", ewma.var, hist.var);
+    }
+
+    #[test]
+    fn test_cornish_fisher_fat_left_tail() {
+        // Moderately negatively skewed sample (r = x - 10x², x ~ N(0, 1%)):
+        // CF VaR exceeds the plain Gaussian VaR. CF is only valid for moderate skew/kurtosis.
+        let n = 500;
+        let normal = Normal::new(0.0, 0.01).unwrap();
+        let rs: Vec<f64> = (0..n)
+            .map(|i| {
+                let x = normal.inverse_cdf((i as f64 + 0.5) / n as f64);
+                x - 10.0 * x * x
+            })
+            .collect();
+        let e = estimator_with(&rs, TailMethod::CornishFisher);
+        let cf = e.estimate(0.99).unwrap();
+
+        let (mean, sd, skew, _) = e.moments(1.0);
+        assert!(skew < 0.0);
+        let gaussian = -(mean + sd * Normal::new(0.0, 1.0).unwrap().inverse_cdf(0.01));
+        assert!(cf.var > gaussian, "CF {} vs Gaussian {}", cf.var, gaussian);
+        assert!(cf.es >= cf.var);
+    }
+}
@@ -3,6 +3,7 @@ use sha2::{Sha256, Digest};
 use tokio::sync::mpsc;
 use crate::feynman::PhysicsState;
 use crate::audit::{QuestBridge, ForensicLog};
+use crate::taleb::sizing::SizingStage;
 
 /// The immutable record of a decision event.
 /// Matches the schema required for "Combat Replay".
@@ -16,6 +17,10 @@ pub struct DecisionPacket {
     pub quantile_score: i32,  // 1-10 Stability Score
     pub decision: String,     // Action taken
     pub operator_hash: String, // Cryptographic seal
+    #[serde(default)]
+    pub sizing: Vec<SizingStage>, // Sizing pipeline trace (Kelly -> Risk Limits)
+    #[serde(default)]
+    pub omega: Option<f64>, // Forecast Omega at the legislative hurdle
 }
 
 impl DecisionPacket {
@@ -120,6 +125,8 @@ mod tests {
             quantile_score: 8,
             decision: "Hold".to_string(),
             operator_hash: String::new(),
+            sizing: Vec::new(),
+            omega: None,
         };
         packet.seal();
 
@@ -7,6 +7,9 @@ pub struct EngineMetrics {
     pub risk_vetos: Counter<u64>,
     pub market_price: Histogram<f64>, // Using Histogram for price distribution/logging
     pub market_velocity: Histogram<f64>,
+    pub phase_duration: Histogram<f64>, // Per OODA phase (attribute "phase")
+    pub budget_overruns: Counter<u64>,
+    pub ticks_coalesced: Counter<u64>,
 }
 
 impl EngineMetrics {
@@ -36,6 +39,18 @@ impl EngineMetrics {
                 .f64_histogram("reflex_market_velocity")
                 .with_description("Current Market Velocity")
                 .init(),
+            phase_duration: meter
+                .f64_histogram("reflex_phase_duration_ms")
+                .with_description("Duration of each OODA phase (observe/orient/decide/act)")
+                .init(),
+            budget_overruns: meter
+                .u64_counter("reflex_phase_budget_overruns")
+                .with_description("OODA phases that exceeded their time budget")
+                .init(),
+            ticks_coalesced: meter
+                .u64_counter("reflex_ticks_coalesced")
+                .with_description("Ticks folded into a later tick of the same burst")
+                .init(),
         }
     }
 }
@@ -35,6 +35,8 @@ async fn test_high_decay_trigger() {
             quantile_score: 1,
             decision: "BUY".to_string(),
             operator_hash: "test".to_string(),
+            sizing: Vec::new(),
+            omega: None,
         };
         decision_tx.send(decision).await.unwrap();
 
@@ -85,6 +87,8 @@ async fn test_jerk_filter() {
         quantile_score: 1,
         decision: "BUY".to_string(),
         operator_hash: "test".to_string(),
+        sizing: Vec::new(),
+        omega: None,
     };
     decision_tx.send(decision).await.unwrap();
 
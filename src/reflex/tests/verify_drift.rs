@@ -26,6 +26,8 @@ async fn test_mirror_latency_isolation() {
         quantile_score: 1,
         decision: "BUY".to_string(),
         operator_hash: "test".to_string(),
+        sizing: Vec::new(),
+        omega: None,
     };
 
     // Send 100 packets